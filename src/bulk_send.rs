@@ -0,0 +1,197 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Bulk send module.
+
+use crate::{
+    data_objects::{
+        Message, MessageObject, MessageProperty, Response, ResponseError, ResponseStatus,
+        SendEmailParams, SendEmailParamsBuilder, SendResponseObject, SendV3_1Response,
+    },
+    ClientError,
+};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Maximum amount of messages that the endpoint `/send` (v3.1) accepts in a single request.
+pub const MAX_MESSAGES_PER_REQUEST: usize = 50;
+
+/// Outcome of a message sent using [crate::MailjetClient::send_bulk].
+#[derive(Debug, Clone)]
+pub enum SendOutcome {
+    /// The message was accepted. It includes an object per recipient (`To`, `Cc` and `Bcc`) with the IDs assigned
+    /// by Mailjet.
+    Sent(Vec<MessageObject>),
+    /// The message was rejected by the external API. It includes the errors reported for it.
+    Rejected(Vec<ResponseError>),
+    /// The request that included the message failed, so the fate of the message is unknown. The same error is shared
+    /// by all the messages of that request.
+    Failed(Arc<ClientError>),
+}
+
+impl SendOutcome {
+    /// Whether the message was accepted by the external API.
+    pub fn is_sent(&self) -> bool {
+        matches!(self, SendOutcome::Sent(_))
+    }
+}
+
+impl From<SendResponseObject> for SendOutcome {
+    fn from(value: SendResponseObject) -> Self {
+        match value.status {
+            ResponseStatus::Success => SendOutcome::Sent(
+                [value.to, value.cc, value.bcc]
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .collect(),
+            ),
+            ResponseStatus::Error => SendOutcome::Rejected(value.errors.unwrap_or_default()),
+        }
+    }
+}
+
+/// Split a list of messages into requests accepted by the endpoint `/send` (v3.1).
+pub(crate) fn split_messages(
+    messages: Vec<Message>,
+    globals: Option<MessageProperty>,
+) -> Vec<SendEmailParams> {
+    let mut requests = Vec::new();
+    let mut messages = messages.into_iter().peekable();
+
+    while messages.peek().is_some() {
+        let request = SendEmailParamsBuilder::default()
+            .with_globals(globals.clone())
+            .with_messages(messages.by_ref().take(MAX_MESSAGES_PER_REQUEST))
+            .build();
+
+        requests.push(request);
+    }
+
+    requests
+}
+
+/// Build the outcome of each message of a request, keeping the order of the messages.
+///
+/// # Description
+///
+/// When the external API rejects a request (status code 400), the body of the response still includes the result of
+/// each message, so the outcome of each message is recovered from it.
+pub(crate) fn outcomes(
+    result: Result<Response<SendResponseObject>, ClientError>,
+    messages: usize,
+) -> Vec<SendOutcome> {
+    let results = match result {
+        Ok(response) => response.payload.unwrap_or_default(),
+        Err(ClientError::BadRequest(details)) => {
+            match serde_json::from_str::<SendV3_1Response>(&details.payload) {
+                Ok(body) => body.messages,
+                Err(_) => return failed(ClientError::BadRequest(details), messages),
+            }
+        }
+        Err(e) => return failed(e, messages),
+    };
+
+    if results.len() != messages {
+        warn!(
+            "The external API returned {} results for {messages} messages",
+            results.len()
+        );
+    }
+
+    let missing = Arc::new(ClientError::UnknownError(
+        "The external API didn't return a result for the message".into(),
+    ));
+
+    results
+        .into_iter()
+        .map(SendOutcome::from)
+        .chain(std::iter::repeat_with(|| {
+            SendOutcome::Failed(missing.clone())
+        }))
+        .take(messages)
+        .collect()
+}
+
+fn failed(error: ClientError, messages: usize) -> Vec<SendOutcome> {
+    let error = Arc::new(error);
+
+    vec![SendOutcome::Failed(error); messages]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_objects::MessageBuilder;
+    use crate::ApiErrorDetails;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 1)]
+    #[case(50, 1)]
+    #[case(51, 2)]
+    #[case(120, 3)]
+    fn messages_are_split(#[case] messages: usize, #[case] requests: usize) {
+        let messages = (0..messages)
+            .map(|i| {
+                MessageBuilder::default()
+                    .with_from("pilot@mailjet.com", None)
+                    .with_subject(&i.to_string())
+                    .build()
+                    .expect("Failed to build the message")
+            })
+            .collect();
+
+        let chunks = split_messages(messages, None);
+
+        assert_eq!(chunks.len(), requests);
+        assert!(chunks
+            .iter()
+            .all(|c| c.messages.len() <= MAX_MESSAGES_PER_REQUEST));
+        // The order is kept.
+        let subjects: Vec<_> = chunks
+            .iter()
+            .flat_map(|c| c.messages.iter().map(|m| m.subject.clone().unwrap()))
+            .collect();
+        assert!(subjects
+            .iter()
+            .enumerate()
+            .all(|(i, s)| *s == i.to_string()));
+    }
+
+    #[rstest]
+    fn rejected_request_outcomes() {
+        let payload = r#"{"Messages":[
+            {"Status":"success","To":[{"Email":"a@mail.com","MessageID":1}]},
+            {"Status":"error","Errors":[{"ErrorIdentifier":"x","ErrorCode":"send-0003",
+             "StatusCode":400,"ErrorMessage":"At least \"HTMLPart\", \"TextPart\" or \"TemplateID\" must be provided.",
+             "ErrorRelatedTo":["HTMLPart","TextPart"]}]}
+        ]}"#;
+        let error = ClientError::BadRequest(Box::new(ApiErrorDetails {
+            status_code: 400,
+            payload: payload.into(),
+            ..Default::default()
+        }));
+
+        let outcomes = outcomes(Err(error), 3);
+
+        assert_eq!(outcomes.len(), 3);
+        assert!(matches!(&outcomes[0], SendOutcome::Sent(to) if to[0].message_id == Some(1)));
+        assert!(matches!(&outcomes[1], SendOutcome::Rejected(e) if e[0].error_code == "send-0003"));
+        assert!(matches!(&outcomes[2], SendOutcome::Failed(_)));
+    }
+
+    #[rstest]
+    fn failed_request_outcomes() {
+        let outcomes = outcomes(Err(ClientError::MissingApiKey), 2);
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(
+            |o| matches!(o, SendOutcome::Failed(e) if matches!(**e, ClientError::MissingApiKey))
+        ));
+    }
+}
@@ -0,0 +1,505 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Typed access to the properties of the contacts.
+//!
+//! # Description
+//!
+//! Contacts carry custom properties (e.g. the first name, or the date of the last purchase) that are used to
+//! personalize the messages. Each property is declared once per account using `/contactmetadata`, and its values are
+//! read and updated using `/contactdata`. The external API returns all the values as strings, so this module
+//! converts them into typed values.
+//!
+//! Implement [ContactProperties] for a `struct` that groups a set of properties: the fields of the `struct` (as named
+//! by `serde`) are the names of the properties, and [ContactProperties::properties] declares their data types. The
+//! values are checked against the declared types before sending them, and [check_metadata] verifies that the
+//! declarations match the metadata of the account.
+//! See [Contact properties](https://dev.mailjet.com/email/reference/contacts/contact-properties/).
+//!
+//! ## Example
+//!
+//! ```
+//! use mailjet_client::{
+//!     contact_properties::{ContactProperties, PropertyDefinition},
+//!     data_objects::{ContactData, ContactProperty, PropertyDataType},
+//! };
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Passenger {
+//!     first_name: String,
+//!     miles: i64,
+//!     frequent_flyer: Option<bool>,
+//! }
+//!
+//! impl ContactProperties for Passenger {
+//!     fn properties() -> Vec<PropertyDefinition> {
+//!         vec![
+//!             PropertyDefinition::new("first_name", PropertyDataType::Str),
+//!             PropertyDefinition::new("miles", PropertyDataType::Int),
+//!             PropertyDefinition::new("frequent_flyer", PropertyDataType::Bool),
+//!         ]
+//!     }
+//! }
+//!
+//! let data = ContactData {
+//!     data: vec![
+//!         ContactProperty { name: "first_name".into(), value: "Amelia".into() },
+//!         ContactProperty { name: "miles".into(), value: "1200".into() },
+//!         ContactProperty { name: "frequent_flyer".into(), value: "".into() },
+//!     ],
+//!     ..Default::default()
+//! };
+//!
+//! let passenger = Passenger::from_contact_data(&data).expect("Failed to parse the properties");
+//!
+//! assert_eq!(passenger.miles, 1200);
+//! assert_eq!(passenger.frequent_flyer, None);
+//! ```
+
+use crate::{
+    data_objects::{
+        ContactData, ContactDataUpdate, ContactMetadata, ContactMetadataQuery, ContactProperty,
+        PropertyDataType, PropertyNamespace, ValidationErrors, Violation,
+    },
+    ClientError,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value};
+
+/// Declaration of a contact property: its name, data type and namespace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyDefinition {
+    pub name: String,
+    pub datatype: PropertyDataType,
+    pub namespace: PropertyNamespace,
+}
+
+impl PropertyDefinition {
+    /// Constructor of a `static` property.
+    pub fn new(name: &str, datatype: PropertyDataType) -> Self {
+        PropertyDefinition {
+            name: name.into(),
+            datatype,
+            namespace: PropertyNamespace::Static,
+        }
+    }
+
+    /// Keep the values of past updates of the property.
+    pub fn historic(mut self) -> Self {
+        self.namespace = PropertyNamespace::Historic;
+
+        self
+    }
+}
+
+impl From<&PropertyDefinition> for ContactMetadataQuery {
+    fn from(value: &PropertyDefinition) -> Self {
+        ContactMetadataQuery {
+            datatype: value.datatype,
+            name: value.name.clone(),
+            namespace: value.namespace,
+        }
+    }
+}
+
+/// A set of contact properties mapped to a Rust type.
+///
+/// # Description
+///
+/// The type is serialized into a JSON object whose keys are the names of the properties. Fields whose value is
+/// `null` (e.g. an `Option` set to `None`) are not sent, so they don't modify the stored value. When reading the
+/// properties of a contact, properties without a value are left out of the object, so declare as `Option` the fields
+/// that might be empty.
+pub trait ContactProperties: Serialize + DeserializeOwned {
+    /// Declarations of the properties of the set.
+    fn properties() -> Vec<PropertyDefinition>;
+
+    /// Build the request to update the properties of a contact, checking the values against the declared types.
+    fn to_contact_data(&self) -> Result<ContactDataUpdate, ClientError> {
+        to_contact_data(self, &Self::properties())
+    }
+
+    /// Parse the properties of a contact, converting the values into the declared types.
+    fn from_contact_data(data: &ContactData) -> Result<Self, ClientError> {
+        from_contact_data(data, &Self::properties())
+    }
+}
+
+/// Check that the properties are declared in the account, using the same data type and namespace.
+///
+/// # Description
+///
+/// `metadata` is the list of properties of the account, as returned by `/contactmetadata`. All the mismatches are
+/// reported, using the name of the property as field of each [Violation].
+pub fn check_metadata(
+    properties: &[PropertyDefinition],
+    metadata: &[ContactMetadata],
+) -> Result<(), ValidationErrors> {
+    let mut violations = Vec::new();
+
+    for property in properties {
+        let message = match metadata.iter().find(|m| m.name == property.name) {
+            None => "The property is not declared in the account".to_string(),
+            Some(m) if m.datatype != property.datatype => format!(
+                "The property is declared as {:?}, but {:?} is expected",
+                m.datatype, property.datatype
+            ),
+            Some(m) if m.namespace != property.namespace => format!(
+                "The property is declared in the namespace {:?}, but {:?} is expected",
+                m.namespace, property.namespace
+            ),
+            Some(_) => continue,
+        };
+
+        violations.push(Violation {
+            field: property.name.clone(),
+            message,
+        });
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationErrors { violations })
+    }
+}
+
+fn to_contact_data<T: Serialize>(
+    value: &T,
+    properties: &[PropertyDefinition],
+) -> Result<ContactDataUpdate, ClientError> {
+    let Value::Object(fields) =
+        serde_json::to_value(value).map_err(|e| ClientError::ParseError(e.to_string()))?
+    else {
+        return Err(ClientError::ParseError(
+            "The contact properties must serialize into an object".into(),
+        ));
+    };
+
+    let mut violations = Vec::new();
+    let mut data = Vec::new();
+
+    for (name, value) in fields {
+        if value.is_null() {
+            continue;
+        }
+
+        match properties.iter().find(|p| p.name == name) {
+            None => violations.push(Violation {
+                field: name,
+                message: "The property is not declared".into(),
+            }),
+            Some(property) if !accepts(property.datatype, &value) => violations.push(Violation {
+                field: name,
+                message: format!("{value} is not a valid {:?} value", property.datatype),
+            }),
+            Some(_) => data.push(ContactProperty { name, value }),
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(ContactDataUpdate { data })
+    } else {
+        Err(ClientError::Validation(ValidationErrors { violations }))
+    }
+}
+
+fn from_contact_data<T: DeserializeOwned>(
+    data: &ContactData,
+    properties: &[PropertyDefinition],
+) -> Result<T, ClientError> {
+    let mut fields = Map::new();
+
+    for property in properties {
+        let Some(value) = data.get(&property.name) else {
+            continue;
+        };
+
+        if let Some(value) = typed_value(property.datatype, value).map_err(|e| {
+            ClientError::ParseError(format!(
+                "Wrong value of the property {}: {e}",
+                property.name
+            ))
+        })? {
+            fields.insert(property.name.clone(), value);
+        }
+    }
+
+    serde_json::from_value(Value::Object(fields))
+        .map_err(|e| ClientError::ParseError(e.to_string()))
+}
+
+/// Convert a value returned by the external API into the given data type. Empty values are returned as `None`.
+fn typed_value(datatype: PropertyDataType, value: &Value) -> Result<Option<Value>, String> {
+    let raw = match value {
+        Value::Null => return Ok(None),
+        Value::String(text) if text.is_empty() => return Ok(None),
+        Value::String(text) => text,
+        value if accepts(datatype, value) => return Ok(Some(value.clone())),
+        value => return Err(format!("{value} is not a valid {datatype:?} value")),
+    };
+    // The blank space around a string is kept, it's only ignored when the string is parsed into another type.
+    let text = raw.trim();
+    let error = || format!("'{text}' is not a valid {datatype:?} value");
+
+    let value = match datatype {
+        PropertyDataType::Str => Value::String(raw.clone()),
+        PropertyDataType::Int => Value::Number(text.parse::<i64>().map_err(|_| error())?.into()),
+        PropertyDataType::Float => text
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(error)?,
+        PropertyDataType::Bool => match text.to_ascii_lowercase().as_str() {
+            "true" | "1" => Value::Bool(true),
+            "false" | "0" => Value::Bool(false),
+            _ => return Err(error()),
+        },
+        PropertyDataType::Datetime if is_datetime(text) => Value::String(text.into()),
+        PropertyDataType::Datetime => return Err(error()),
+    };
+
+    Ok(Some(value))
+}
+
+/// Whether a JSON value is valid for the given data type.
+fn accepts(datatype: PropertyDataType, value: &Value) -> bool {
+    match datatype {
+        PropertyDataType::Str => value.is_string(),
+        PropertyDataType::Int => value.is_i64() || value.is_u64(),
+        PropertyDataType::Float => value.is_number(),
+        PropertyDataType::Bool => value.is_boolean(),
+        PropertyDataType::Datetime => value.as_str().is_some_and(is_datetime),
+    }
+}
+
+/// Whether a string is an ISO 8601 date (`YYYY-MM-DD`), optionally followed by a time (`THH:MM:SS`, plus optional
+/// fractions of a second and an optional time zone, `Z` or `±HH:MM`). Nothing else may follow.
+fn is_datetime(text: &str) -> bool {
+    /// Take the prefix of `text` that matches `pattern` (`D` stands for a digit).
+    fn take<'t>(text: &'t [u8], pattern: &[u8]) -> Option<&'t [u8]> {
+        let matches = text.len() >= pattern.len()
+            && pattern.iter().zip(text).all(|(p, b)| match p {
+                b'D' => b.is_ascii_digit(),
+                p => p == b,
+            });
+
+        matches.then(|| &text[pattern.len()..])
+    }
+
+    let Some(rest) = take(text.as_bytes(), b"DDDD-DD-DD") else {
+        return false;
+    };
+    if rest.is_empty() {
+        return true;
+    }
+    let Some(mut rest) = take(rest, b"TDD:DD:DD") else {
+        return false;
+    };
+
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return false;
+        }
+        rest = &fraction[digits..];
+    }
+
+    match rest {
+        [] | [b'Z'] => true,
+        [b'+' | b'-', zone @ ..] => take(zone, b"DD:DD").is_some_and(<[u8]>::is_empty),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Passenger {
+        first_name: String,
+        miles: i64,
+        rating: Option<f64>,
+        frequent_flyer: Option<bool>,
+        last_flight: Option<String>,
+    }
+
+    impl ContactProperties for Passenger {
+        fn properties() -> Vec<PropertyDefinition> {
+            vec![
+                PropertyDefinition::new("first_name", PropertyDataType::Str),
+                PropertyDefinition::new("miles", PropertyDataType::Int).historic(),
+                PropertyDefinition::new("rating", PropertyDataType::Float),
+                PropertyDefinition::new("frequent_flyer", PropertyDataType::Bool),
+                PropertyDefinition::new("last_flight", PropertyDataType::Datetime),
+            ]
+        }
+    }
+
+    fn property(name: &str, value: &str) -> ContactProperty {
+        ContactProperty {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    #[rstest]
+    fn properties_from_contact_data() {
+        let data = ContactData {
+            data: vec![
+                property("first_name", "Amelia"),
+                property("miles", "1200"),
+                property("rating", "4.5"),
+                property("frequent_flyer", "true"),
+                property("last_flight", ""),
+                property("unknown", "ignored"),
+            ],
+            ..Default::default()
+        };
+
+        let passenger =
+            Passenger::from_contact_data(&data).expect("Failed to parse the properties");
+
+        assert_eq!(
+            passenger,
+            Passenger {
+                first_name: "Amelia".into(),
+                miles: 1200,
+                rating: Some(4.5),
+                frequent_flyer: Some(true),
+                last_flight: None,
+            }
+        );
+    }
+
+    #[rstest]
+    fn wrong_value_from_contact_data() {
+        let data = ContactData {
+            data: vec![property("first_name", "Amelia"), property("miles", "many")],
+            ..Default::default()
+        };
+
+        let result = Passenger::from_contact_data(&data);
+
+        assert!(matches!(result, Err(ClientError::ParseError(e)) if e.contains("miles")));
+    }
+
+    #[rstest]
+    fn properties_to_contact_data() {
+        let passenger = Passenger {
+            first_name: "Amelia".into(),
+            miles: 1200,
+            rating: None,
+            frequent_flyer: Some(false),
+            last_flight: Some("2024-09-20T10:00:00Z".into()),
+        };
+
+        let update = passenger
+            .to_contact_data()
+            .expect("Failed to build the update");
+
+        let values: HashMap<_, _> = update.data.into_iter().map(|p| (p.name, p.value)).collect();
+        assert_eq!(
+            values,
+            HashMap::from([
+                ("first_name".to_string(), json!("Amelia")),
+                ("miles".to_string(), json!(1200)),
+                ("frequent_flyer".to_string(), json!(false)),
+                ("last_flight".to_string(), json!("2024-09-20T10:00:00Z")),
+            ])
+        );
+    }
+
+    #[rstest]
+    fn wrong_value_to_contact_data() {
+        let passenger = Passenger {
+            first_name: "Amelia".into(),
+            miles: 1200,
+            rating: None,
+            frequent_flyer: None,
+            last_flight: Some("yesterday".into()),
+        };
+
+        let Err(ClientError::Validation(errors)) = passenger.to_contact_data() else {
+            panic!("The datetime value should be rejected");
+        };
+
+        assert!(errors.contains("last_flight"));
+        assert_eq!(errors.violations.len(), 1);
+    }
+
+    #[rstest]
+    fn only_parsed_values_are_trimmed() {
+        assert_eq!(
+            typed_value(PropertyDataType::Str, &json!(" Amelia ")),
+            Ok(Some(json!(" Amelia ")))
+        );
+        assert_eq!(
+            typed_value(PropertyDataType::Int, &json!(" 1200 ")),
+            Ok(Some(json!(1200)))
+        );
+        assert_eq!(
+            typed_value(PropertyDataType::Datetime, &json!(" 2024-09-20 ")),
+            Ok(Some(json!("2024-09-20")))
+        );
+    }
+
+    #[rstest]
+    #[case("2024-09-20", true)]
+    #[case("2024-09-20T10:00:00", true)]
+    #[case("2024-09-20T10:00:00.123+02:00", true)]
+    #[case("2024-09-2", false)]
+    #[case("20/09/2024", false)]
+    #[case("2024-09-20 10:00", false)]
+    #[case("2024-09-20T10:00:00Z", true)]
+    #[case("2024-09-20T10:00:00-05:30", true)]
+    #[case("2024-09-20T10:00:00xyz", false)]
+    #[case("2024-09-20T10:00:00.", false)]
+    #[case("2024-09-20T10:00:00.5Zulu", false)]
+    #[case("2024-09-20T10:00:00+02", false)]
+    #[case("2024-09-20T10:00:00+02:00:00", false)]
+    #[case("2024-09-20x", false)]
+    fn datetime_values(#[case] text: &str, #[case] expected: bool) {
+        assert_eq!(is_datetime(text), expected);
+    }
+
+    #[rstest]
+    fn metadata_mismatches() {
+        let metadata = vec![
+            ContactMetadata {
+                id: 1,
+                datatype: PropertyDataType::Str,
+                name: "first_name".into(),
+                namespace: PropertyNamespace::Static,
+            },
+            ContactMetadata {
+                id: 2,
+                datatype: PropertyDataType::Str,
+                name: "miles".into(),
+                namespace: PropertyNamespace::Historic,
+            },
+            ContactMetadata {
+                id: 3,
+                datatype: PropertyDataType::Float,
+                name: "rating".into(),
+                namespace: PropertyNamespace::Historic,
+            },
+        ];
+
+        let errors = check_metadata(&Passenger::properties(), &metadata)
+            .expect_err("The metadata doesn't match");
+
+        let fields: Vec<_> = errors.violations.iter().map(|v| v.field.as_str()).collect();
+        assert_eq!(fields, ["miles", "rating", "frequent_flyer", "last_flight"]);
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Module that defines the attachments of a message.
+
+use crate::ClientError;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Maximum size (in bytes) of the attachments of a message accepted by the external API.
+///
+/// # Description
+///
+/// Mailjet rejects messages bigger than 15 MB. The size is measured over the base64 encoded content of the
+/// attachments and inline attachments of a message, which is what is sent to the external API.
+pub const MAX_MESSAGE_SIZE: usize = 15 * 1024 * 1024;
+
+/// Content type used when it can't be inferred from the content nor from the file name.
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Attachment object.
+///
+/// # Description
+///
+/// The content of the file is encoded using base64. Rather than filling the fields by hand, use any of the
+/// constructors: [Attachment::from_bytes], [Attachment::from_path] or [Attachment::from_reader]. These encode the
+/// content, and infer the content type from the content itself (magic bytes) or, when not possible, from the
+/// extension of the file name.
+///
+/// Inline attachments (images referenced from the HTML part using `cid:`) need a `ContentID`. Set it using
+/// [Attachment::with_content_id].
+///
+/// ## Example
+///
+/// ```
+/// use mailjet_client::data_objects::Attachment;
+///
+/// let logo = Attachment::from_bytes("logo.png", b"\x89PNG\r\n\x1a\n").with_content_id("logo");
+///
+/// assert_eq!(logo.content_type, "image/png");
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attachment {
+    pub filename: String,
+    pub content_type: String,
+    pub base_64_content: String,
+    #[serde(rename = "ContentID", skip_serializing_if = "Option::is_none", default)]
+    pub content_id: Option<String>,
+}
+
+impl Attachment {
+    /// Build an attachment from the raw content of a file.
+    pub fn from_bytes(filename: &str, content: impl AsRef<[u8]>) -> Self {
+        let content = content.as_ref();
+
+        Attachment {
+            filename: filename.into(),
+            content_type: infer_content_type(filename, content),
+            base_64_content: STANDARD.encode(content),
+            content_id: None,
+        }
+    }
+
+    /// Build an attachment from a [Bytes] buffer.
+    pub fn from_buffer(filename: &str, content: Bytes) -> Self {
+        Attachment::from_bytes(filename, content)
+    }
+
+    /// Build an attachment from a file. The name of the attachment is taken from the path.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, ClientError> {
+        let path = path.as_ref();
+        let filename = path.file_name().and_then(|f| f.to_str()).ok_or_else(|| {
+            ClientError::ParseError(format!("Invalid file name: {}", path.display()))
+        })?;
+        let content = tokio::fs::read(path).await.map_err(ClientError::Io)?;
+
+        Ok(Attachment::from_bytes(filename, content))
+    }
+
+    /// Build an attachment reading the content from an asynchronous reader until EOF.
+    pub async fn from_reader<R: AsyncRead + Unpin>(
+        filename: &str,
+        mut reader: R,
+    ) -> Result<Self, ClientError> {
+        let mut content = Vec::new();
+        reader
+            .read_to_end(&mut content)
+            .await
+            .map_err(ClientError::Io)?;
+
+        Ok(Attachment::from_bytes(filename, content))
+    }
+
+    /// Override the inferred content type.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = content_type.into();
+
+        self
+    }
+
+    /// Set the `ContentID` of an inline attachment, used to reference it from the HTML part (`cid:<id>`).
+    pub fn with_content_id(mut self, content_id: &str) -> Self {
+        self.content_id = Some(content_id.into());
+
+        self
+    }
+
+    /// Decode the content of the attachment.
+    pub fn content(&self) -> Result<Vec<u8>, ClientError> {
+        STANDARD
+            .decode(&self.base_64_content)
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// Size (in bytes) of the encoded content.
+    pub fn size(&self) -> usize {
+        self.base_64_content.len()
+    }
+}
+
+/// Check that the size of the given attachments doesn't exceed [MAX_MESSAGE_SIZE].
+pub(crate) fn check_attachments_size<'a>(
+    attachments: impl IntoIterator<Item = &'a Option<Vec<Attachment>>>,
+) -> Result<(), ClientError> {
+    let size = attachments
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(Attachment::size)
+        .sum();
+
+    if size > MAX_MESSAGE_SIZE {
+        Err(ClientError::MessageTooLarge {
+            size,
+            limit: MAX_MESSAGE_SIZE,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn infer_content_type(filename: &str, content: &[u8]) -> String {
+    match infer::get(content) {
+        Some(kind) => kind.mime_type().into(),
+        None => mime_guess::from_path(filename)
+            .first_raw()
+            .unwrap_or(DEFAULT_CONTENT_TYPE)
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    #[case("logo.png", b"\x89PNG\r\n\x1a\n".as_slice(), "image/png")]
+    // Magic bytes take precedence over the extension.
+    #[case("logo.txt", b"%PDF-1.7".as_slice(), "application/pdf")]
+    #[case("notes.txt", b"Hello".as_slice(), "text/plain")]
+    #[case("report.csv", b"a,b".as_slice(), "text/csv")]
+    #[case("unknown", b"Hello".as_slice(), DEFAULT_CONTENT_TYPE)]
+    fn content_type_is_inferred(
+        #[case] filename: &str,
+        #[case] content: &[u8],
+        #[case] content_type: &str,
+    ) {
+        assert_eq!(
+            Attachment::from_bytes(filename, content).content_type,
+            content_type
+        );
+    }
+
+    #[rstest]
+    fn attachment_from_bytes() {
+        let attachment = Attachment::from_buffer("test.txt", Bytes::from_static(b"Hello world"));
+
+        assert_eq!(attachment.filename, "test.txt");
+        assert_eq!(attachment.base_64_content, "SGVsbG8gd29ybGQ=");
+        assert_eq!(attachment.content_id, None);
+    }
+
+    #[rstest]
+    async fn attachment_from_reader() {
+        let attachment = Attachment::from_reader("test.txt", b"Hello world".as_slice())
+            .await
+            .expect("Failed to read the content");
+
+        assert_eq!(attachment.base_64_content, "SGVsbG8gd29ybGQ=");
+    }
+
+    #[rstest]
+    async fn attachment_from_path() {
+        let attachment = Attachment::from_path("Cargo.toml")
+            .await
+            .expect("Failed to read the file");
+
+        assert_eq!(attachment.filename, "Cargo.toml");
+        assert!(Attachment::from_path("missing.txt").await.is_err());
+    }
+
+    #[rstest]
+    fn content_id_is_serialized() {
+        let attachment = Attachment::from_bytes("logo.png", b"").with_content_id("logo");
+        let json = serde_json::to_value(&attachment).unwrap();
+
+        assert_eq!(json["ContentID"], "logo");
+        assert_eq!(
+            serde_json::to_value(Attachment::from_bytes("a.txt", b""))
+                .unwrap()
+                .get("ContentID"),
+            None
+        );
+    }
+
+    #[rstest]
+    fn size_limit() {
+        let half = Attachment {
+            filename: "half.bin".into(),
+            content_type: DEFAULT_CONTENT_TYPE.into(),
+            base_64_content: "A".repeat(MAX_MESSAGE_SIZE / 2),
+            content_id: None,
+        };
+
+        assert!(
+            check_attachments_size([&Some(vec![half.clone()]), &Some(vec![half.clone()])]).is_ok()
+        );
+        assert!(matches!(
+            check_attachments_size([&Some(vec![half.clone(), half.clone()]), &Some(vec![half])]),
+            Err(ClientError::MessageTooLarge { .. })
+        ));
+        assert!(check_attachments_size([&None, &None]).is_ok());
+    }
+}
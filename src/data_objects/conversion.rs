@@ -0,0 +1,479 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Conversions between the data objects of the API v3 and v3.1 to send emails.
+
+use crate::data_objects::{Message, NameAndEmail, SimpleMessage, Variables};
+use serde_json::Value;
+
+/// Result of converting a message between the models of the API v3 and v3.1.
+///
+/// # Description
+///
+/// Both models don't match exactly, so some fields of the source message might not be represented in the target.
+/// These are listed in `lost_fields`, using the names of the external API (source model).
+#[derive(Debug, Clone)]
+pub struct Conversion<T> {
+    pub value: T,
+    pub lost_fields: Vec<&'static str>,
+}
+
+impl<T> Conversion<T> {
+    /// Whether all the fields of the source message were represented in the target.
+    pub fn is_lossless(&self) -> bool {
+        self.lost_fields.is_empty()
+    }
+}
+
+/// A message of any of the models supported by the endpoint `/send`.
+///
+/// # Description
+///
+/// Use this object with [crate::MailjetClient::send_message] to send a message using the API version configured in
+/// the client, regardless of the model used to build it.
+#[derive(Debug, Clone)]
+pub enum EmailMessage {
+    /// Message of the API v3.
+    Simple(Box<SimpleMessage>),
+    /// Message of the API v3.1.
+    Message(Box<Message>),
+}
+
+impl From<SimpleMessage> for EmailMessage {
+    fn from(value: SimpleMessage) -> Self {
+        EmailMessage::Simple(Box::new(value))
+    }
+}
+
+impl From<Message> for EmailMessage {
+    fn from(value: Message) -> Self {
+        EmailMessage::Message(Box::new(value))
+    }
+}
+
+impl EmailMessage {
+    /// Get the message using the model of the API v3.
+    pub fn into_simple_message(self) -> Conversion<SimpleMessage> {
+        match self {
+            EmailMessage::Simple(value) => Conversion {
+                value: *value,
+                lost_fields: Vec::new(),
+            },
+            EmailMessage::Message(message) => message.into_simple_message(),
+        }
+    }
+
+    /// Get the message using the model of the API v3.1.
+    pub fn into_message(self) -> Conversion<Message> {
+        match self {
+            EmailMessage::Simple(message) => message.into_message(),
+            EmailMessage::Message(value) => Conversion {
+                value: *value,
+                lost_fields: Vec::new(),
+            },
+        }
+    }
+}
+
+impl SimpleMessage {
+    /// Convert the message to the model of the API v3.1.
+    ///
+    /// # Description
+    ///
+    /// `Recipients` and the recipients listed in the headers `To`, `Cc` and `Bcc` (using the format
+    /// `"Name" <email>, email`) are kept. The variables of the message (`Vars`) must be a JSON object to be kept.
+    pub fn into_message(self) -> Conversion<Message> {
+        let mut lost_fields = Vec::new();
+
+        if self.sender.is_some() {
+            lost_fields.push("Sender");
+        }
+        if self.recipients.iter().any(|r| r.vars.is_some()) {
+            lost_fields.push("Recipients.Vars");
+        }
+
+        let variables = match self.vars.as_deref().map(parse_vars) {
+            Some(Some(vars)) => Some(vars),
+            Some(None) => {
+                lost_fields.push("Vars");
+                None
+            }
+            None => None,
+        };
+
+        let mut to: Vec<_> = self
+            .recipients
+            .into_iter()
+            .map(|r| NameAndEmail { vars: None, ..r })
+            .collect();
+        to.extend(self.to.as_deref().map(parse_addresses).unwrap_or_default());
+
+        let value = Message {
+            from: NameAndEmail::new(
+                &self.from_email,
+                Some(self.from_name.as_str()).filter(|n| !n.is_empty()),
+            ),
+            sender: None,
+            to: Some(to).filter(|to| !to.is_empty()),
+            cc: self.cc.as_deref().map(parse_addresses),
+            bcc: self.bcc.as_deref().map(parse_addresses),
+            reply_to: None,
+            subject: self.subject,
+            text_part: self.text_part,
+            html_part: self.html_part,
+            template_id: None,
+            template_language: None,
+            template_error_reporting: None,
+            template_error_deliver: None,
+            attachments: self.attachments,
+            inline_attachments: self.inline_attachments,
+            priority: None,
+            custom_campaign: None,
+            deduplicate_campaign: None,
+            track_opens: None,
+            track_clicks: None,
+            custom_id: None,
+            event_payload: self.event_payload,
+            url_tags: None,
+            headers: None,
+            variables,
+        };
+
+        Conversion { value, lost_fields }
+    }
+}
+
+impl Message {
+    /// Convert the message to the model of the API v3.
+    ///
+    /// # Description
+    ///
+    /// When the message has a single recipient, it is listed as `Recipients`. Otherwise, the recipients are listed
+    /// using the headers `To`, `Cc` and `Bcc`: the API v3 sends a separate email to each of the `Recipients`, while
+    /// the API v3.1 sends a single email to all the `To` recipients, and both lists can't be mixed.
+    pub fn into_simple_message(self) -> Conversion<SimpleMessage> {
+        let mut lost_fields = Vec::new();
+        let mut lose = |present: bool, field: &'static str| {
+            if present {
+                lost_fields.push(field);
+            }
+        };
+
+        lose(self.sender.is_some(), "Sender");
+        lose(self.reply_to.is_some(), "ReplyTo");
+        lose(self.template_id.is_some(), "TemplateID");
+        lose(self.template_language.is_some(), "TemplateLanguage");
+        lose(
+            self.template_error_reporting.is_some(),
+            "TemplateErrorReporting",
+        );
+        lose(
+            self.template_error_deliver.is_some(),
+            "TemplateErrorDeliver",
+        );
+        lose(self.priority.is_some(), "Priority");
+        lose(self.custom_campaign.is_some(), "CustomCampaign");
+        lose(self.deduplicate_campaign.is_some(), "DeduplicateCampaign");
+        lose(self.track_opens.is_some(), "TrackOpens");
+        lose(self.track_clicks.is_some(), "TrackClicks");
+        lose(self.custom_id.is_some(), "CustomID");
+        lose(self.url_tags.is_some(), "URLTags");
+        lose(self.headers.is_some(), "Headers");
+
+        let headers = self.cc.is_some()
+            || self.bcc.is_some()
+            || self.to.as_ref().is_some_and(|to| to.len() > 1);
+        if headers {
+            // The headers can't carry the variables of each recipient.
+            for (field, recipients) in [
+                ("To.Vars", &self.to),
+                ("Cc.Vars", &self.cc),
+                ("Bcc.Vars", &self.bcc),
+            ] {
+                lose(recipients.iter().flatten().any(|r| r.vars.is_some()), field);
+            }
+        }
+
+        let (recipients, to) = match (headers, self.to) {
+            (false, to) => (to.unwrap_or_default(), None),
+            (true, to) => (Vec::new(), to.map(|to| format_addresses(&to))),
+        };
+
+        let value = SimpleMessage {
+            from_email: self.from.email,
+            from_name: self.from.name.unwrap_or_default(),
+            sender: None,
+            recipients,
+            to,
+            cc: self.cc.map(|cc| format_addresses(&cc)),
+            bcc: self.bcc.map(|bcc| format_addresses(&bcc)),
+            subject: self.subject,
+            text_part: self.text_part,
+            html_part: self.html_part,
+            attachments: self.attachments,
+            inline_attachments: self.inline_attachments,
+            event_payload: self.event_payload,
+            vars: match self.variables.map(|v| serde_json::to_string(&v)) {
+                Some(Ok(vars)) => Some(vars),
+                Some(Err(_)) => {
+                    lost_fields.push("Variables");
+                    None
+                }
+                None => None,
+            },
+        };
+
+        Conversion { value, lost_fields }
+    }
+}
+
+/// Parse the variables of a message of the API v3 (a JSON object).
+fn parse_vars(vars: &str) -> Option<Variables> {
+    match serde_json::from_str(vars) {
+        Ok(Value::Object(vars)) => Some(vars.into_iter().collect()),
+        _ => None,
+    }
+}
+
+/// Parse a list of addresses using the format `"Name" <email>, email`.
+///
+/// # Description
+///
+/// Commas within quoted names (`"Doe, Jane" <jane@mail.com>`) or angle brackets don't separate addresses, and the
+/// escaped characters of quoted names (`\"` and `\\`) are unescaped.
+fn parse_addresses(addresses: &str) -> Vec<NameAndEmail> {
+    split_addresses(addresses)
+        .into_iter()
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .map(|address| match address.rsplit_once('<') {
+            Some((name, email)) => {
+                let name = unquote(name.trim());
+                NameAndEmail::new(
+                    email.trim_end_matches('>').trim(),
+                    Some(name.as_str()).filter(|n| !n.is_empty()),
+                )
+            }
+            None => NameAndEmail::new(address, None),
+        })
+        .collect()
+}
+
+/// Split a list of addresses by the commas that are out of quoted strings and angle brackets.
+fn split_addresses(addresses: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut quoted, mut escaped, mut bracketed) = (false, false, false);
+    let mut start = 0;
+
+    for (i, c) in addresses.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '<' if !quoted => bracketed = true,
+            '>' if !quoted => bracketed = false,
+            ',' if !quoted && !bracketed => {
+                parts.push(&addresses[start..i]);
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    parts.push(&addresses[start..]);
+
+    parts
+}
+
+/// Remove the quotes of a display name, unescaping its content.
+fn unquote(name: &str) -> String {
+    let Some(inner) = name.strip_prefix('"').and_then(|n| n.strip_suffix('"')) else {
+        return name.to_string();
+    };
+
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.extend(chars.next()),
+            c => unquoted.push(c),
+        }
+    }
+
+    unquoted.trim().to_string()
+}
+
+/// Format a list of addresses using the format `"Name" <email>, email`. Names are quoted, escaping `"` and `\\`.
+fn format_addresses(addresses: &[NameAndEmail]) -> String {
+    addresses
+        .iter()
+        .map(|a| match &a.name {
+            Some(name) => format!(
+                "\"{}\" <{}>",
+                name.replace('\\', "\\\\").replace('"', "\\\""),
+                a.email
+            ),
+            None => a.email.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_objects::{MessageBuilder, Track};
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[fixture]
+    fn simple_message() -> SimpleMessage {
+        SimpleMessage {
+            from_email: "pilot@mailjet.com".into(),
+            from_name: "Mailjet Pilot".into(),
+            recipients: vec![NameAndEmail::new("passenger@mailjet.com", None)],
+            subject: Some("Your email flight plan!".into()),
+            text_part: Some("Dear passenger, welcome to Mailjet!".into()),
+            vars: Some(r#"{"day": "Monday", "seat": 12}"#.into()),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case("a@mail.com", vec![("a@mail.com", None)])]
+    #[case(
+        "\"Jane Doe\" <jane@mail.com>, john@mail.com",
+        vec![("jane@mail.com", Some("Jane Doe")), ("john@mail.com", None)]
+    )]
+    #[case("John <john@mail.com>,", vec![("john@mail.com", Some("John"))])]
+    #[case(
+        "\"Doe, Jane\" <jane@mail.com>, john@mail.com",
+        vec![("jane@mail.com", Some("Doe, Jane")), ("john@mail.com", None)]
+    )]
+    #[case(
+        r#""The \"Ace\", Jr." <ace@mail.com>"#,
+        vec![("ace@mail.com", Some("The \"Ace\", Jr."))]
+    )]
+    fn addresses_are_parsed(#[case] addresses: &str, #[case] expected: Vec<(&str, Option<&str>)>) {
+        let parsed: Vec<_> = parse_addresses(addresses)
+            .into_iter()
+            .map(|a| (a.email, a.name))
+            .collect();
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(e, n)| (e.to_string(), n.map(String::from)))
+            .collect();
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[rstest]
+    #[case(vec![NameAndEmail::new("jane@mail.com", Some("Doe, Jane"))])]
+    #[case(vec![
+        NameAndEmail::new("ace@mail.com", Some(r#"The "Ace", Jr."#)),
+        NameAndEmail::new("back@mail.com", Some(r"Back\slash")),
+        NameAndEmail::new("john@mail.com", None),
+    ])]
+    fn addresses_round_trip(#[case] addresses: Vec<NameAndEmail>) {
+        let formatted = format_addresses(&addresses);
+        let parsed = parse_addresses(&formatted);
+
+        let pairs = |list: &[NameAndEmail]| -> Vec<_> {
+            list.iter()
+                .map(|a| (a.email.clone(), a.name.clone()))
+                .collect()
+        };
+        assert_eq!(pairs(&parsed), pairs(&addresses), "{formatted}");
+    }
+
+    #[rstest]
+    fn simple_message_to_message(simple_message: SimpleMessage) {
+        let conversion = simple_message.into_message();
+
+        assert!(conversion.is_lossless());
+        let message = conversion.value;
+        assert_eq!(message.from.email, "pilot@mailjet.com");
+        assert_eq!(message.from.name.as_deref(), Some("Mailjet Pilot"));
+        assert_eq!(message.to.unwrap()[0].email, "passenger@mailjet.com");
+        let variables = message.variables.unwrap();
+        assert_eq!(variables["day"], "Monday");
+        assert_eq!(variables["seat"], 12);
+    }
+
+    #[rstest]
+    fn lost_fields_of_simple_message(mut simple_message: SimpleMessage) {
+        simple_message.sender = Some(true);
+        simple_message.vars = Some("[1, 2]".into());
+
+        assert_eq!(
+            simple_message.into_message().lost_fields,
+            vec!["Sender", "Vars"]
+        );
+    }
+
+    #[rstest]
+    fn round_trip(simple_message: SimpleMessage) {
+        let conversion = simple_message
+            .clone()
+            .into_message()
+            .value
+            .into_simple_message();
+
+        assert!(conversion.is_lossless());
+        let message = conversion.value;
+        assert_eq!(message.from_email, simple_message.from_email);
+        assert_eq!(message.from_name, simple_message.from_name);
+        assert_eq!(message.recipients[0].email, "passenger@mailjet.com");
+        assert_eq!(message.text_part, simple_message.text_part);
+    }
+
+    #[rstest]
+    fn many_recipients_share_the_email() {
+        let message = MessageBuilder::default()
+            .with_from("pilot@mailjet.com", None)
+            .add_to("passenger@mailjet.com", None)
+            .add_to("copilot@mailjet.com", None)
+            .build()
+            .expect("Failed to build the message");
+
+        let conversion = message.into_simple_message();
+
+        assert!(conversion.is_lossless());
+        let message = conversion.value;
+        // `Recipients` would send a separate email to each of them.
+        assert!(message.recipients.is_empty());
+        assert_eq!(
+            message.to.as_deref(),
+            Some("passenger@mailjet.com, copilot@mailjet.com")
+        );
+    }
+
+    #[rstest]
+    fn message_to_simple_message() {
+        let mut message = MessageBuilder::default()
+            .with_from("pilot@mailjet.com", None)
+            .with_to("passenger@mailjet.com", Some("Passenger"))
+            .with_text_body("Dear passenger, welcome to Mailjet!")
+            .build()
+            .expect("Failed to build the message");
+        message.cc = Some(vec![NameAndEmail::new("copilot@mailjet.com", None)]);
+        message.template_error_reporting = Some(NameAndEmail::new("air-traffic@mailjet.com", None));
+        message.track_opens = Some(Track::Enabled);
+        message.to.as_mut().unwrap()[0].vars = Some(Default::default());
+
+        let conversion = message.into_simple_message();
+
+        assert_eq!(
+            conversion.lost_fields,
+            vec!["TemplateErrorReporting", "TrackOpens", "To.Vars"]
+        );
+        let message = conversion.value;
+        assert!(message.recipients.is_empty());
+        assert!(message
+            .to
+            .unwrap()
+            .starts_with("\"Passenger\" <passenger@mailjet.com>"));
+        assert_eq!(message.cc.as_deref(), Some("copilot@mailjet.com"));
+    }
+}
@@ -5,11 +5,57 @@
 
 //! Module that defines all the objects related to mails.
 
-use super::RequestObject;
+use super::{Attachment, RequestObject, ValidationErrors, Violation};
+use crate::ClientError;
 use names::Generator;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
+/// Variables of a message (or a recipient) used by the template language.
+///
+/// # Description
+///
+/// Mailjet templates support any JSON value as a variable: strings, numbers, booleans, arrays (for loops) and
+/// objects. Use [to_variables] to build the variables from a Rust object.
+pub type Variables = HashMap<String, Value>;
+
+/// Serialize a Rust object into [Variables].
+///
+/// # Description
+///
+/// [ClientError::ParseError] is returned when the object doesn't serialize to a JSON object, as the names of the
+/// variables are taken from its fields.
+///
+/// ## Example
+///
+/// ```
+/// use mailjet_client::data_objects::to_variables;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Flight {
+///     number: u32,
+///     stops: Vec<&'static str>,
+/// }
+///
+/// let variables = to_variables(&Flight { number: 42, stops: vec!["CPH", "MAD"] }).unwrap();
+///
+/// assert_eq!(variables["number"], 42);
+/// assert!(to_variables(&42).is_err());
+/// ```
+pub fn to_variables<T: Serialize>(value: &T) -> Result<Variables, ClientError> {
+    match serde_json::to_value(value) {
+        Ok(Value::Object(variables)) => Ok(variables.into_iter().collect()),
+        Ok(other) => Err(ClientError::ParseError(format!(
+            "Variables must serialize to a JSON object, found: {other}"
+        ))),
+        Err(e) => Err(ClientError::ParseError(format!(
+            "Failed to serialize the variables: {e}"
+        ))),
+    }
+}
+
 /// Object that represents the parameters needed to send a message using the API::v3.1.
 ///
 /// # Description
@@ -44,7 +90,7 @@ pub struct Message {
     #[serde(rename = "URLTags")]
     pub url_tags: Option<String>,
     pub headers: Option<HashMap<String, String>>,
-    pub variables: Option<HashMap<String, String>>,
+    pub variables: Option<Variables>,
 }
 
 /// Object that represents the parameters needed to send a message using the API::v3.0.
@@ -75,29 +121,35 @@ pub struct SimpleMessage {
     pub vars: Option<String>,
 }
 
-impl RequestObject for SimpleMessage {
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
-}
+impl RequestObject for SimpleMessage {}
 
 /// Object that implements a builder construction pattern for [Message].
 ///
 /// # Description
 ///
-/// This object populates the mandatory fields with the default value for the type in case no value was
-/// given. This might cause later issues when sending the request to the external API. The main use case
-/// is to build a [Message] with default values, and later on, populate only those required. This would
-/// be much quicker than calling new with a lot of `None` values.
+/// All the fields of a [Message] can be set using the fluent methods of this object. Recipients are appended, so
+/// call [MessageBuilder::add_to], [MessageBuilder::add_cc] or [MessageBuilder::add_bcc] once per recipient.
+///
+/// The sender address (`From`) is mandatory, so [MessageBuilder::build] fails when it wasn't given. The rest of the
+/// rules of the external API are not checked when building the message, see
+/// [Validate](crate::data_objects::Validate).
 ///
 /// ## Example
 ///
 /// ```
-/// use mailjet_client::data_objects::MessageBuilder;
+/// use mailjet_client::data_objects::{MessageBuilder, Track};
 ///
 /// let message = MessageBuilder::default()
 ///     .with_from("john_doe@mail.com", Some("John Doe"))
-///     .build();
+///     .add_to("jane_doe@mail.com", Some("Jane Doe"))
+///     .add_cc("alice@mail.com", None)
+///     .with_template(4242)
+///     .with_variable("name", "Jane")
+///     .with_track_opens(Track::Enabled)
+///     .build()
+///     .expect("Missing sender");
+///
+/// assert_eq!(message.to.unwrap().len(), 1);
 /// ```
 #[derive(Debug, Default)]
 pub struct MessageBuilder {
@@ -125,7 +177,7 @@ pub struct MessageBuilder {
     pub event_payload: Option<String>,
     pub url_tags: Option<String>,
     pub headers: Option<HashMap<String, String>>,
-    pub variables: Option<HashMap<String, String>>,
+    pub variables: Option<Variables>,
 }
 
 impl MessageBuilder {
@@ -135,11 +187,46 @@ impl MessageBuilder {
         self
     }
 
-    pub fn with_to(mut self, email: &str, name: Option<&str>) -> Self {
-        self.to = Some(Vec::from([
-            NameAndEmail::new(email, name),
-            NameAndEmail::new(email, name),
-        ]));
+    pub fn with_sender(mut self, email: &str, name: Option<&str>) -> Self {
+        self.sender = Some(NameAndEmail::new(email, name));
+
+        self
+    }
+
+    /// Append a recipient to the list `To`.
+    pub fn add_to(mut self, email: &str, name: Option<&str>) -> Self {
+        self.to
+            .get_or_insert_with(Vec::new)
+            .push(NameAndEmail::new(email, name));
+
+        self
+    }
+
+    /// Append a recipient to the list `To`. Alias of [MessageBuilder::add_to].
+    pub fn with_to(self, email: &str, name: Option<&str>) -> Self {
+        self.add_to(email, name)
+    }
+
+    /// Append a recipient to the list `Cc`.
+    pub fn add_cc(mut self, email: &str, name: Option<&str>) -> Self {
+        self.cc
+            .get_or_insert_with(Vec::new)
+            .push(NameAndEmail::new(email, name));
+
+        self
+    }
+
+    /// Append a recipient to the list `Bcc`.
+    pub fn add_bcc(mut self, email: &str, name: Option<&str>) -> Self {
+        self.bcc
+            .get_or_insert_with(Vec::new)
+            .push(NameAndEmail::new(email, name));
+
+        self
+    }
+
+    pub fn with_reply_to(mut self, email: &str, name: Option<&str>) -> Self {
+        self.reply_to = Some(NameAndEmail::new(email, name));
 
         self
     }
@@ -162,9 +249,144 @@ impl MessageBuilder {
         self
     }
 
-    pub fn build(self) -> Message {
-        Message {
-            from: self.from.unwrap_or_default(),
+    /// Use a template stored in Mailjet. The template language is enabled, so the variables of the message are
+    /// replaced in the template.
+    pub fn with_template(mut self, template_id: u64) -> Self {
+        self.template_id = Some(template_id);
+        self.template_language = Some(true);
+
+        self
+    }
+
+    pub fn with_template_language(mut self, enabled: bool) -> Self {
+        self.template_language = Some(enabled);
+
+        self
+    }
+
+    /// Send a report of the errors found when rendering the template to the given address.
+    pub fn with_template_error_reporting(mut self, email: &str, name: Option<&str>) -> Self {
+        self.template_error_reporting = Some(NameAndEmail::new(email, name));
+
+        self
+    }
+
+    /// Deliver the message even when errors are found when rendering the template.
+    pub fn with_template_error_deliver(mut self, deliver: bool) -> Self {
+        self.template_error_deliver = Some(deliver);
+
+        self
+    }
+
+    /// Add a variable used by the template language.
+    pub fn with_variable(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.variables
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+
+        self
+    }
+
+    /// Add the fields of a Rust object as variables used by the template language. See [to_variables].
+    pub fn with_variables<T: Serialize>(mut self, variables: &T) -> Result<Self, ClientError> {
+        self.variables
+            .get_or_insert_with(HashMap::new)
+            .extend(to_variables(variables)?);
+
+        Ok(self)
+    }
+
+    pub fn with_attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments
+            .get_or_insert_with(Vec::new)
+            .push(attachment);
+
+        self
+    }
+
+    pub fn with_inline_attachment(mut self, attachment: Attachment) -> Self {
+        self.inline_attachments
+            .get_or_insert_with(Vec::new)
+            .push(attachment);
+
+        self
+    }
+
+    /// Set the priority of the message, within the range `[0, 4]`.
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = Some(priority);
+
+        self
+    }
+
+    pub fn with_custom_campaign(mut self, campaign: &str) -> Self {
+        self.custom_campaign = Some(campaign.into());
+
+        self
+    }
+
+    pub fn with_deduplicate_campaign(mut self, deduplicate: bool) -> Self {
+        self.deduplicate_campaign = Some(deduplicate);
+
+        self
+    }
+
+    pub fn with_track_opens(mut self, track: Track) -> Self {
+        self.track_opens = Some(track);
+
+        self
+    }
+
+    pub fn with_track_clicks(mut self, track: Track) -> Self {
+        self.track_clicks = Some(track);
+
+        self
+    }
+
+    pub fn with_custom_id(mut self, custom_id: &str) -> Self {
+        self.custom_id = Some(custom_id.into());
+
+        self
+    }
+
+    pub fn with_event_payload(mut self, payload: &str) -> Self {
+        self.event_payload = Some(payload.into());
+
+        self
+    }
+
+    pub fn with_url_tags(mut self, url_tags: &str) -> Self {
+        self.url_tags = Some(url_tags.into());
+
+        self
+    }
+
+    /// Add a custom header to the message.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+
+        self
+    }
+
+    /// Build the [Message].
+    ///
+    /// # Description
+    ///
+    /// [ClientError::Validation] is returned when the sender address (`From`) wasn't given.
+    pub fn build(self) -> Result<Message, ClientError> {
+        let from = self.from.ok_or_else(|| {
+            ClientError::Validation(ValidationErrors {
+                violations: vec![Violation {
+                    field: "From".into(),
+                    message: "the sender address is required".into(),
+                }],
+            })
+        })?;
+
+        Ok(Message {
+            from,
             sender: self.sender,
             to: self.to,
             cc: self.cc,
@@ -189,7 +411,7 @@ impl MessageBuilder {
             url_tags: self.url_tags,
             headers: self.headers,
             variables: self.variables,
-        }
+        })
     }
 }
 
@@ -199,7 +421,7 @@ impl MessageBuilder {
 ///
 /// The parameters to send a message using the external API::v3.1 include a field named `Globals`. It is
 /// an object that is translated by this struct.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MessageProperty {
     pub from: Option<NameAndEmail>,
@@ -227,7 +449,7 @@ pub struct MessageProperty {
     #[serde(rename = "URLTags")]
     pub url_tags: Option<String>,
     pub headers: Option<HashMap<String, String>>,
-    pub variables: Option<HashMap<String, String>>,
+    pub variables: Option<Variables>,
 }
 
 /// Simple object that includes an email and a linked name (optional).
@@ -236,7 +458,7 @@ pub struct MessageProperty {
 pub struct NameAndEmail {
     pub email: String,
     pub name: Option<String>,
-    pub vars: Option<String>,
+    pub vars: Option<Variables>,
 }
 
 impl Default for NameAndEmail {
@@ -261,15 +483,6 @@ impl NameAndEmail {
     }
 }
 
-/// Attachment object.
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-pub struct Attachment {
-    pub filename: String,
-    pub content_type: String,
-    pub base_64_content: String,
-}
-
 #[derive(Clone, Debug, Default, Serialize, PartialEq, Deserialize)]
 pub enum Track {
     #[default]
@@ -300,7 +513,8 @@ mod tests {
             .with_to(test_email, name)
             .with_text_body(body)
             .with_html_body(body)
-            .build();
+            .build()
+            .expect("Failed to build the message");
 
         assert_eq!(message.from.email, test_email);
         assert_eq!(message.from.name.as_deref(), name);
@@ -308,4 +522,115 @@ mod tests {
         assert_eq!(message.html_part.as_deref(), Some(body));
         assert_eq!(message.subject.as_deref(), Some(subject));
     }
+
+    #[rstest]
+    fn recipients_are_appended() {
+        let message = MessageBuilder::default()
+            .with_from("pilot@mailjet.com", None)
+            .add_to("passenger1@mailjet.com", None)
+            .with_to("passenger2@mailjet.com", Some("Passenger 2"))
+            .add_cc("copilot@mailjet.com", None)
+            .add_bcc("air-traffic@mailjet.com", None)
+            .add_bcc("tower@mailjet.com", None)
+            .build()
+            .expect("Failed to build the message");
+
+        let emails = |r: Option<Vec<NameAndEmail>>| -> Vec<String> {
+            r.unwrap_or_default().into_iter().map(|r| r.email).collect()
+        };
+        assert_eq!(
+            emails(message.to),
+            ["passenger1@mailjet.com", "passenger2@mailjet.com"]
+        );
+        assert_eq!(emails(message.cc), ["copilot@mailjet.com"]);
+        assert_eq!(
+            emails(message.bcc),
+            ["air-traffic@mailjet.com", "tower@mailjet.com"]
+        );
+    }
+
+    #[rstest]
+    fn all_fields_are_set() {
+        let message = MessageBuilder::default()
+            .with_from("pilot@mailjet.com", None)
+            .with_sender("ops@mailjet.com", None)
+            .with_reply_to("support@mailjet.com", None)
+            .with_template(4242)
+            .with_template_error_reporting("dev@mailjet.com", None)
+            .with_template_error_deliver(false)
+            .with_variable("day", "Monday")
+            .with_priority(2)
+            .with_custom_campaign("flights")
+            .with_deduplicate_campaign(true)
+            .with_track_opens(Track::Enabled)
+            .with_track_clicks(Track::Disabled)
+            .with_custom_id("flight-42")
+            .with_event_payload("payload")
+            .with_url_tags("utm_source=mailjet")
+            .with_header("X-Flight", "42")
+            .build()
+            .expect("Failed to build the message");
+
+        assert_eq!(message.sender.unwrap().email, "ops@mailjet.com");
+        assert_eq!(message.reply_to.unwrap().email, "support@mailjet.com");
+        assert_eq!(message.template_id, Some(4242));
+        assert_eq!(message.template_language, Some(true));
+        assert_eq!(
+            message.template_error_reporting.unwrap().email,
+            "dev@mailjet.com"
+        );
+        assert_eq!(message.template_error_deliver, Some(false));
+        assert_eq!(message.variables.unwrap()["day"], "Monday");
+        assert_eq!(message.priority, Some(2));
+        assert_eq!(message.custom_campaign.as_deref(), Some("flights"));
+        assert_eq!(message.deduplicate_campaign, Some(true));
+        assert_eq!(message.track_opens, Some(Track::Enabled));
+        assert_eq!(message.track_clicks, Some(Track::Disabled));
+        assert_eq!(message.custom_id.as_deref(), Some("flight-42"));
+        assert_eq!(message.event_payload.as_deref(), Some("payload"));
+        assert_eq!(message.url_tags.as_deref(), Some("utm_source=mailjet"));
+        assert_eq!(message.headers.unwrap()["X-Flight"], "42");
+    }
+
+    #[rstest]
+    fn missing_sender() {
+        let result = MessageBuilder::default()
+            .add_to("passenger@mailjet.com", None)
+            .build();
+
+        assert!(matches!(result, Err(ClientError::Validation(e)) if e.contains("From")));
+    }
+
+    #[rstest]
+    fn typed_variables() {
+        #[derive(Serialize)]
+        struct Booking {
+            seat: u8,
+            vip: bool,
+            stops: Vec<&'static str>,
+        }
+
+        let message = MessageBuilder::default()
+            .with_from("pilot@mailjet.com", None)
+            .with_variable("day", "Monday")
+            .with_variables(&Booking {
+                seat: 12,
+                vip: true,
+                stops: vec!["CPH", "MAD"],
+            })
+            .expect("Failed to serialize the variables")
+            .build()
+            .expect("Failed to build the message");
+
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(
+            json["Variables"],
+            serde_json::json!({"day": "Monday", "seat": 12, "vip": true, "stops": ["CPH", "MAD"]})
+        );
+
+        assert!(matches!(
+            MessageBuilder::default().with_variables(&vec![1, 2]),
+            Err(ClientError::ParseError(_))
+        ));
+    }
 }
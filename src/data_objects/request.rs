@@ -5,9 +5,38 @@
 
 //! Translations of data objects use as params for the endpoints of Mailjet's API.
 
-use crate::data_objects::{Message, MessageProperty, RequestObject};
-use serde::{Deserialize, Serialize};
-use std::any::Any;
+use crate::data_objects::{
+    CallbackStatus, ContactProperty, EmailType, EventType, Message, MessageProperty,
+    PaginatedRequest, PropertyDataType, PropertyNamespace, RequestObject, TemplateOwnerType,
+    TemplatePurpose, Variables,
+};
+use core::fmt;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+
+/// Implement [PaginatedRequest] for a request object that includes the fields `limit`, `offset` and `count_only`.
+macro_rules! impl_paginated_request {
+    ($request:ty) => {
+        impl PaginatedRequest for $request {
+            fn offset(&self) -> u32 {
+                self.offset.unwrap_or_default()
+            }
+
+            fn set_limit(&mut self, limit: u16) {
+                self.limit = Some(limit);
+            }
+
+            fn set_offset(&mut self, offset: u32) {
+                self.offset = Some(offset);
+            }
+
+            fn set_count_only(&mut self, count_only: bool) {
+                self.count_only = Some(count_only);
+            }
+        }
+    };
+}
 
 /// Object that translates the parameters allowed when sending a POST to the `/send` endpoint (v3.1).
 ///
@@ -24,11 +53,176 @@ pub struct SendEmailParams {
 }
 
 impl RequestObject for SendEmailParams {
-    fn as_any(&self) -> &dyn Any {
+    /// Mailjet deduplicates messages using their `CustomID`, so the request is safe to be sent again only when all
+    /// its messages include one, either their own or the one of the `Globals`.
+    fn is_deduplicable(&self) -> bool {
+        let global_id = self.globals.as_ref().is_some_and(|g| g.custom_id.is_some());
+
+        !self.messages.is_empty()
+            && (global_id || self.messages.iter().all(|m| m.custom_id.is_some()))
+    }
+}
+
+impl SendEmailParams {
+    /// Get the messages that the external API sends, once the `Globals` are merged into each message.
+    ///
+    /// # Description
+    ///
+    /// Mailjet applies the following rules when merging the `Globals` into a message:
+    /// - The properties defined in the message override those defined in the `Globals`.
+    /// - The `Headers` and `Variables` are merged key-wise, and the values of the message take precedence.
+    /// - The `From` is mandatory in a message, and it is always sent, so the one in the `Globals` is never used.
+    ///
+    /// This is a local preview, no request is sent to the external API.
+    pub fn effective_messages(&self) -> Vec<Message> {
+        match &self.globals {
+            Some(globals) => self
+                .messages
+                .iter()
+                .map(|m| merge_globals(m.clone(), globals))
+                .collect(),
+            None => self.messages.clone(),
+        }
+    }
+}
+
+/// Object that implements a builder construction pattern for [SendEmailParams].
+///
+/// ## Example
+///
+/// ```
+/// use mailjet_client::data_objects::{MessageBuilder, MessageProperty, SendEmailParamsBuilder};
+///
+/// let request = SendEmailParamsBuilder::default()
+///     .with_sandbox_mode(true)
+///     .with_globals(MessageProperty {
+///         subject: Some("Your email flight plan!".into()),
+///         ..Default::default()
+///     })
+///     .add_message(
+///         MessageBuilder::default()
+///             .with_from("pilot@mailjet.com", None)
+///             .add_to("passenger@mailjet.com", None)
+///             .build()
+///             .expect("Missing sender"),
+///     )
+///     .build();
+///
+/// assert_eq!(
+///     request.effective_messages()[0].subject.as_deref(),
+///     Some("Your email flight plan!")
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct SendEmailParamsBuilder {
+    pub sandbox_mode: Option<bool>,
+    pub advance_error_handling: Option<bool>,
+    pub globals: Option<MessageProperty>,
+    pub messages: Vec<Message>,
+}
+
+impl SendEmailParamsBuilder {
+    pub fn with_sandbox_mode(mut self, sandbox_mode: bool) -> Self {
+        self.sandbox_mode = Some(sandbox_mode);
+
         self
     }
+
+    pub fn with_advance_error_handling(mut self, advance_error_handling: bool) -> Self {
+        self.advance_error_handling = Some(advance_error_handling);
+
+        self
+    }
+
+    /// Set the properties shared by all the messages. `None` removes them.
+    pub fn with_globals(mut self, globals: impl Into<Option<MessageProperty>>) -> Self {
+        self.globals = globals.into();
+
+        self
+    }
+
+    /// Append a message to the request.
+    pub fn add_message(mut self, message: Message) -> Self {
+        self.messages.push(message);
+
+        self
+    }
+
+    /// Append several messages to the request.
+    pub fn with_messages(mut self, messages: impl IntoIterator<Item = Message>) -> Self {
+        self.messages.extend(messages);
+
+        self
+    }
+
+    pub fn build(self) -> SendEmailParams {
+        SendEmailParams {
+            sandbox_mode: self.sandbox_mode,
+            advance_error_handling: self.advance_error_handling,
+            globals: self.globals,
+            messages: self.messages,
+        }
+    }
 }
 
+/// Merge the `Globals` of a request into a message. See [SendEmailParams::effective_messages].
+fn merge_globals(message: Message, globals: &MessageProperty) -> Message {
+    let globals = globals.clone();
+
+    fn merge_maps<V>(
+        globals: Option<HashMap<String, V>>,
+        message: Option<HashMap<String, V>>,
+    ) -> Option<HashMap<String, V>> {
+        match (globals, message) {
+            (Some(mut globals), Some(message)) => {
+                globals.extend(message);
+                Some(globals)
+            }
+            (globals, message) => message.or(globals),
+        }
+    }
+
+    Message {
+        // The sender is mandatory in a message, so the one in the globals is never used.
+        from: message.from,
+        sender: message.sender.or(globals.sender),
+        to: message.to,
+        cc: message.cc.or(globals.cc),
+        bcc: message.bcc.or(globals.bcc),
+        reply_to: message.reply_to.or(globals.reply_to),
+        subject: message.subject.or(globals.subject),
+        text_part: message.text_part.or(globals.text_part),
+        html_part: message.html_part.or(globals.html_part),
+        template_id: message.template_id.or(globals.template_id),
+        template_language: message.template_language.or(globals.template_language),
+        template_error_reporting: message
+            .template_error_reporting
+            .or(globals.template_error_reporting),
+        template_error_deliver: message
+            .template_error_deliver
+            .or(globals.template_error_deliver),
+        attachments: message.attachments.or(globals.attachments),
+        inline_attachments: message.inline_attachments.or(globals.inline_attachments),
+        priority: message.priority.or(globals.priority),
+        custom_campaign: message.custom_campaign.or(globals.custom_campaign),
+        deduplicate_campaign: message
+            .deduplicate_campaign
+            .or(globals.deduplicate_campaign),
+        track_opens: message.track_opens.or(globals.track_opens),
+        track_clicks: message.track_clicks.or(globals.track_clicks),
+        custom_id: message.custom_id.or(globals.custom_id),
+        event_payload: message.event_payload.or(globals.event_payload),
+        url_tags: message.url_tags.or(globals.url_tags),
+        headers: merge_maps(globals.headers, message.headers),
+        variables: merge_maps(globals.variables, message.variables),
+    }
+}
+
+/// Object that represents the allowed filters to use the endpoint `Sender` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/sender`](https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender#v3_get_sender).
 #[derive(Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct SenderQuery {
@@ -41,11 +235,31 @@ pub struct SenderQuery {
     pub show_deleted: Option<bool>,
     pub status: Option<String>,
     pub limit: Option<u16>,
-    pub offset: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
     pub count_only: Option<bool>,
     pub sort: Option<String>,
 }
 
+impl RequestObject for SenderQuery {}
+
+impl_paginated_request!(SenderQuery);
+
+/// Object that represents the allowed parameters to use the endpoint `Sender` (POST).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct SenderCreate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_type: Option<EmailType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_default_sender: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub email: String,
+}
+
+impl RequestObject for SenderCreate {}
+
 /// Object that represents the allowed parameters to use the endpoint  `Contact` (POST).
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -54,3 +268,778 @@ pub struct ContactQuery {
     pub name: Option<String>,
     pub email: String,
 }
+
+impl RequestObject for ContactQuery {}
+
+/// Empty object for requests that don't need any parameter.
+#[derive(Serialize, Debug, Clone, Copy, Default)]
+pub struct NoParams {}
+
+impl RequestObject for NoParams {}
+
+/// Characters of an email address (or a name) that are escaped when it is used as a segment of a path. `|` is kept,
+/// as it separates the owner and the name of a template (`apikey|name`).
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'@')
+    .remove(b'|');
+
+/// Identifier of a resource: endpoints such as `/contact` or `/sender` accept either the numeric ID or the email
+/// address.
+///
+/// # Description
+///
+/// The [fmt::Display] implementation renders the identifier as a segment of a path, so the reserved characters of an
+/// email address, such as `+`, are percent-encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceId {
+    Id(i64),
+    Email(String),
+}
+
+impl fmt::Display for ResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceId::Id(id) => write!(f, "{id}"),
+            ResourceId::Email(email) => write!(f, "{}", utf8_percent_encode(email, PATH_SEGMENT)),
+        }
+    }
+}
+
+impl From<i64> for ResourceId {
+    fn from(value: i64) -> Self {
+        ResourceId::Id(value)
+    }
+}
+
+impl From<&str> for ResourceId {
+    fn from(value: &str) -> Self {
+        ResourceId::Email(value.to_string())
+    }
+}
+
+impl From<String> for ResourceId {
+    fn from(value: String) -> Self {
+        ResourceId::Email(value)
+    }
+}
+
+/// Object that represents the allowed filters to use the endpoint `Contact` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/contact`](https://dev.mailjet.com/email/reference/contacts/contact#v3_get_contact).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactFilters {
+    pub campaign: Option<i64>,
+    pub contacts_list: Option<i64>,
+    pub is_excluded_from_campaigns: Option<bool>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for ContactFilters {}
+
+impl_paginated_request!(ContactFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `Contact` (PUT).
+///
+/// # Description
+///
+/// Attributes set to `None` are not sent, so their current value is kept by the external API.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_excluded_from_campaigns: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl RequestObject for ContactUpdate {}
+
+/// Object that represents the allowed filters to use the endpoint `ContactsList` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/contactslist`](https://dev.mailjet.com/email/reference/contacts/contact-list#v3_get_contactslist).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactsListFilters {
+    pub address: Option<String>,
+    #[serde(rename = "ExcludeID")]
+    pub exclude_id: Option<i64>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub is_deleted: Option<bool>,
+    pub name: Option<String>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for ContactsListFilters {}
+
+impl_paginated_request!(ContactsListFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `ContactsList` (POST and PUT).
+///
+/// # Description
+///
+/// The `Name` is mandatory when creating a list. Setting `IsDeleted` to `true` moves the list to the trash, and
+/// setting it back to `false` restores it. See [`/contactslist`](https://dev.mailjet.com/email/reference/contacts/contact-list#v3_post_contactslist).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactsListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_deleted: Option<bool>,
+}
+
+impl RequestObject for ContactsListQuery {}
+
+/// Object that represents the allowed filters to use the endpoint `ListRecipient` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/listrecipient`](https://dev.mailjet.com/email/reference/contacts/subscriptions#v3_get_listrecipient).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListRecipientFilters {
+    pub contact: Option<i64>,
+    pub contact_email: Option<String>,
+    pub contacts_list: Option<i64>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub ignore_deleted: Option<bool>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub is_unsubscribed: Option<bool>,
+    pub list_name: Option<String>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for ListRecipientFilters {}
+
+impl_paginated_request!(ListRecipientFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `ListRecipient` (POST).
+///
+/// # Description
+///
+/// The contact is given either by its ID (`ContactID`) or its email address (`ContactAlt`), and the list by its ID
+/// (`ListID`) or its address (`ListAlt`). See [`/listrecipient`](https://dev.mailjet.com/email/reference/contacts/subscriptions#v3_post_listrecipient).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListRecipientQuery {
+    #[serde(rename = "ContactID", skip_serializing_if = "Option::is_none")]
+    pub contact_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_alt: Option<String>,
+    #[serde(rename = "ListID", skip_serializing_if = "Option::is_none")]
+    pub list_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_unsubscribed: Option<bool>,
+}
+
+impl RequestObject for ListRecipientQuery {}
+
+/// Object that represents the allowed parameters to use the endpoint `ListRecipient` (PUT).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListRecipientUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_unsubscribed: Option<bool>,
+}
+
+impl RequestObject for ListRecipientUpdate {}
+
+/// Action applied to the contacts by the endpoints `managemanycontacts`.
+///
+/// # Description
+///
+/// - `AddForce`: add the contacts to the list, subscribing again those that unsubscribed.
+/// - `AddNoForce`: add the contacts to the list, keeping the status of those that unsubscribed.
+/// - `Remove`: remove the contacts from the list.
+/// - `Unsub`: unsubscribe the contacts from the list.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContactAction {
+    AddForce,
+    AddNoForce,
+    Remove,
+    Unsub,
+}
+
+/// A contact managed by the endpoints `managemanycontacts`.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ManagedContact {
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_excluded_from_campaigns: Option<bool>,
+    /// Values of the contact properties (see `/contactmetadata`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Variables>,
+}
+
+impl ManagedContact {
+    pub fn new(email: &str) -> Self {
+        ManagedContact {
+            email: email.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Action applied to a list by the endpoint `/contact/managemanycontacts`.
+#[derive(Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactsListAction {
+    #[serde(rename = "ListID")]
+    pub list_id: i64,
+    pub action: ContactAction,
+}
+
+/// Object that represents the allowed parameters to use the endpoint `/contact/managemanycontacts` (POST).
+///
+/// # Description
+///
+/// The contacts are created when they don't exist, and the actions are applied to each of the given lists.
+/// See [`/contact/managemanycontacts`](https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_post_contact_managemanycontacts).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ManageContactsQuery {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub contacts_lists: Vec<ContactsListAction>,
+    pub contacts: Vec<ManagedContact>,
+}
+
+impl RequestObject for ManageContactsQuery {}
+
+/// Object that represents the allowed parameters to use the endpoint `/contactslist/{list_ID}/managemanycontacts`
+/// (POST). See [`/contactslist/{list_ID}/managemanycontacts`](https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_post_contactslist_list_ID_managemanycontacts).
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ManageListContactsQuery {
+    pub action: ContactAction,
+    pub contacts: Vec<ManagedContact>,
+}
+
+impl RequestObject for ManageListContactsQuery {}
+
+/// Options of a CSV import.
+///
+/// # Description
+///
+/// `FieldNames` maps the columns of the CSV file to the contact fields: `email`, `name` or the name of a contact
+/// property. Use `-` to ignore a column. `TimeZone` is the offset (hours) applied to the dates of the file.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ImportOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_time_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<i32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub field_names: Vec<String>,
+}
+
+/// Object that represents the allowed parameters to use the endpoint `CsvImport` (POST).
+///
+/// # Description
+///
+/// `DataID` is the ID returned when uploading the CSV file (see [crate::MailjetClient::upload_contacts_csv]).
+/// See [`/csvimport`](https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_post_csvimport).
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CsvImportQuery {
+    #[serde(rename = "ContactsListID")]
+    pub contacts_list_id: i64,
+    #[serde(rename = "DataID")]
+    pub data_id: i64,
+    pub method: ContactAction,
+    /// The external API expects the options as a JSON string.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_as_json_string"
+    )]
+    pub import_options: Option<ImportOptions>,
+}
+
+impl RequestObject for CsvImportQuery {}
+
+fn serialize_as_json_string<S: Serializer>(
+    value: &Option<ImportOptions>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(options) => serializer
+            .serialize_str(&serde_json::to_string(options).map_err(serde::ser::Error::custom)?),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Object that represents the allowed filters to use the endpoint `Template` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/template`](https://dev.mailjet.com/email/reference/templates#v3_get_template).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateFilters {
+    #[serde(rename = "AuthorID")]
+    pub author_id: Option<i64>,
+    pub categories: Option<String>,
+    /// `1`: drag and drop builder, `2`: HTML builder, `3`: saved section, `4`: MJML.
+    pub edit_mode: Option<u8>,
+    pub name: Option<String>,
+    pub owner_type: Option<TemplateOwnerType>,
+    pub purposes: Option<TemplatePurpose>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for TemplateFilters {}
+
+impl_paginated_request!(TemplateFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `Template` (POST and PUT).
+///
+/// # Description
+///
+/// The `Name` is mandatory when creating a template. Attributes set to `None` are not sent, so their current value
+/// is kept by the external API when updating a template.
+/// See [`/template`](https://dev.mailjet.com/email/reference/templates#v3_post_template).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edit_mode: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_starred: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_text_part_generation_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_type: Option<TemplateOwnerType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purposes: Option<Vec<TemplatePurpose>>,
+}
+
+impl RequestObject for TemplateQuery {}
+
+/// Object that represents the allowed filters to use the endpoints `Message` and `MessageInformation` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. `FromTS` and `ToTS` accept timestamps in RFC 3339 format or Unix timestamps.
+/// See [`/message`](https://dev.mailjet.com/email/reference/messages/message#v3_get_message).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageFilters {
+    #[serde(rename = "CampaignID")]
+    pub campaign_id: Option<i64>,
+    pub contact: Option<i64>,
+    #[serde(rename = "CustomID")]
+    pub custom_id: Option<String>,
+    pub destination: Option<i64>,
+    #[serde(rename = "FromTS")]
+    pub from_ts: Option<String>,
+    #[serde(rename = "ToTS")]
+    pub to_ts: Option<String>,
+    #[serde(rename = "SenderID")]
+    pub sender_id: Option<i64>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub show_contact_alt: Option<bool>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub show_subject: Option<bool>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for MessageFilters {}
+
+impl_paginated_request!(MessageFilters);
+
+/// Object that represents the allowed filters to use the endpoint `EventCallbackUrl` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/eventcallbackurl`](https://dev.mailjet.com/email/reference/webhook#v3_get_eventcallbackurl).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventCallbackUrlFilters {
+    #[serde(serialize_with = "serialize_flag")]
+    pub backup: Option<bool>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for EventCallbackUrlFilters {}
+
+impl_paginated_request!(EventCallbackUrlFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `EventCallbackUrl` (POST and PUT).
+///
+/// # Description
+///
+/// The `EventType` and the `Url` are mandatory when creating a webhook. Attributes set to `None` are not sent.
+/// See [`/eventcallbackurl`](https://dev.mailjet.com/email/reference/webhook#v3_post_eventcallbackurl).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventCallbackUrlQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<EventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CallbackStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_backup: Option<bool>,
+}
+
+impl RequestObject for EventCallbackUrlQuery {}
+
+/// Object that represents the allowed filters to use the endpoint `ContactFilter` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/contactfilter`](https://dev.mailjet.com/email/reference/segmentation#v3_get_contactfilter).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactFilterFilters {
+    /// Include the filters that were deleted.
+    #[serde(serialize_with = "serialize_flag")]
+    pub show_deleted: Option<bool>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for ContactFilterFilters {}
+
+impl_paginated_request!(ContactFilterFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `ContactFilter` (POST, PUT).
+///
+/// # Description
+///
+/// The syntax of the `Expression` is checked locally by the client before sending the request (see
+/// [crate::segmentation::validate_expression]). See [`/contactfilter`](https://dev.mailjet.com/email/reference/segmentation#v3_post_contactfilter).
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactFilterQuery {
+    pub name: String,
+    pub expression: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl ContactFilterQuery {
+    /// Build a new filter, e.g. from a [crate::segmentation::Expression].
+    pub fn new(name: &str, expression: impl ToString) -> Self {
+        ContactFilterQuery {
+            name: name.into(),
+            expression: expression.to_string(),
+            description: None,
+        }
+    }
+}
+
+impl RequestObject for ContactFilterQuery {}
+
+/// Object that represents the allowed filters to use the endpoint `ContactMetadata` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/contactmetadata`](https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactmetadata).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactMetadataFilters {
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for ContactMetadataFilters {}
+
+impl_paginated_request!(ContactMetadataFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `ContactMetadata` (POST, PUT).
+///
+/// # Description
+///
+/// The name of a property must be unique within the account. See [`/contactmetadata`](https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_post_contactmetadata).
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactMetadataQuery {
+    pub datatype: PropertyDataType,
+    pub name: String,
+    #[serde(rename = "NameSpace")]
+    pub namespace: PropertyNamespace,
+}
+
+impl RequestObject for ContactMetadataQuery {}
+
+/// Object that represents the allowed filters to use the endpoint `ContactData` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/contactdata`](https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactdata).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactDataFilters {
+    /// Retrieve only the contacts of the given list.
+    pub contacts_list: Option<i64>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for ContactDataFilters {}
+
+impl_paginated_request!(ContactDataFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `ContactData` (PUT).
+///
+/// # Description
+///
+/// Only the given properties are modified. An empty string as value removes the value of a property. The properties
+/// must be declared using `/contactmetadata` beforehand.
+/// See [`/contactdata`](https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_put_contactdata_contact_ID).
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactDataUpdate {
+    pub data: Vec<ContactProperty>,
+}
+
+impl RequestObject for ContactDataUpdate {}
+
+/// Object that represents the allowed filters to use the endpoint `ParseRoute` (GET).
+///
+/// # Description
+///
+/// All the filters are optional. See [`/parseroute`](https://dev.mailjet.com/email/reference/parse-api#v3_get_parseroute).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ParseRouteFilters {
+    pub email: Option<String>,
+    pub url: Option<String>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    #[serde(serialize_with = "serialize_flag")]
+    pub count_only: Option<bool>,
+    pub sort: Option<String>,
+}
+
+impl RequestObject for ParseRouteFilters {}
+
+impl_paginated_request!(ParseRouteFilters);
+
+/// Object that represents the allowed parameters to use the endpoint `ParseRoute` (POST).
+///
+/// # Description
+///
+/// The `Url` is mandatory. When no `Email` is given, Mailjet assigns an address of the domain `parse-in1.mailjet.com`.
+/// See [`/parseroute`](https://dev.mailjet.com/email/reference/parse-api#v3_post_parseroute).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ParseRouteQuery {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+impl RequestObject for ParseRouteQuery {}
+
+/// Serialize a flag of a query string as `1` or `0`, which is the format used by Mailjet's docs.
+fn serialize_flag<S: Serializer>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(flag) => serializer.serialize_u8(*flag as u8),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_objects::{MessageBuilder, NameAndEmail, Track};
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn globals_are_merged() {
+        let globals = MessageProperty {
+            subject: Some("Your email flight plan!".into()),
+            text_part: Some("Dear passenger, welcome to Mailjet!".into()),
+            cc: Some(vec![NameAndEmail::new("copilot@mailjet.com", None)]),
+            track_opens: Some(Track::Enabled),
+            headers: Some(HashMap::from([
+                ("X-Flight".into(), "42".into()),
+                ("X-Gate".into(), "A1".into()),
+            ])),
+            variables: Some(HashMap::from([("day".into(), "Monday".into())])),
+            ..Default::default()
+        };
+        let request = SendEmailParamsBuilder::default()
+            .with_globals(globals)
+            .add_message(
+                MessageBuilder::default()
+                    .with_from("pilot@mailjet.com", None)
+                    .add_to("passenger@mailjet.com", None)
+                    .with_subject("Your flight was delayed")
+                    .with_header("X-Gate", "B2")
+                    .with_variable("seat", 12)
+                    .build()
+                    .expect("Failed to build the message"),
+            )
+            .add_message(
+                MessageBuilder::default()
+                    .with_from("pilot@mailjet.com", None)
+                    .add_to("passenger2@mailjet.com", None)
+                    .build()
+                    .expect("Failed to build the message"),
+            )
+            .build();
+
+        let messages = request.effective_messages();
+
+        assert_eq!(messages.len(), 2);
+        // The values of the message override the globals.
+        assert_eq!(
+            messages[0].subject.as_deref(),
+            Some("Your flight was delayed")
+        );
+        assert_eq!(
+            messages[1].subject.as_deref(),
+            Some("Your email flight plan!")
+        );
+        assert_eq!(
+            messages[0].text_part.as_deref(),
+            Some("Dear passenger, welcome to Mailjet!")
+        );
+        assert_eq!(
+            messages[1].cc.as_ref().unwrap()[0].email,
+            "copilot@mailjet.com"
+        );
+        assert_eq!(messages[1].track_opens, Some(Track::Enabled));
+        // Headers and variables are merged key-wise.
+        let headers = messages[0].headers.as_ref().unwrap();
+        assert_eq!(headers["X-Flight"], "42");
+        assert_eq!(headers["X-Gate"], "B2");
+        let variables = messages[0].variables.as_ref().unwrap();
+        assert_eq!(variables["day"], "Monday");
+        assert_eq!(variables["seat"], 12);
+        assert_eq!(messages[1].headers.as_ref().unwrap()["X-Gate"], "A1");
+    }
+
+    #[rstest]
+    fn effective_messages_match_the_payload() {
+        let request = SendEmailParamsBuilder::default()
+            .with_globals(MessageProperty {
+                from: Some(NameAndEmail::new("pilot@mailjet.com", Some("Pilot"))),
+                subject: Some("Your email flight plan!".into()),
+                ..Default::default()
+            })
+            .add_message(Message {
+                // The sender of a message is always sent, even when it is empty.
+                from: NameAndEmail::new("", None),
+                ..MessageBuilder::default()
+                    .with_from("unused@mailjet.com", None)
+                    .with_subject("Your flight was delayed")
+                    .build()
+                    .expect("Failed to build the message")
+            })
+            .build();
+
+        let payload = serde_json::to_value(&request).unwrap();
+        let effective = serde_json::to_value(request.effective_messages()).unwrap();
+
+        // The properties sent in a message override the globals, so the preview must match them.
+        let sent = payload["Messages"][0].as_object().unwrap();
+        for (field, value) in sent.iter().filter(|(_, v)| !v.is_null()) {
+            assert_eq!(&effective[0][field], value, "{field}");
+        }
+        assert_eq!(effective[0]["From"]["Email"], "");
+    }
+
+    #[rstest]
+    fn global_custom_id_makes_the_request_deduplicable() {
+        let message = MessageBuilder::default()
+            .with_from("pilot@mailjet.com", None)
+            .build()
+            .expect("Failed to build the message");
+        let request = SendEmailParamsBuilder::default().add_message(message);
+        assert!(!request.build().is_deduplicable());
+
+        let request = SendEmailParamsBuilder::default()
+            .add_message(
+                MessageBuilder::default()
+                    .with_from("pilot@mailjet.com", None)
+                    .build()
+                    .expect("Failed to build the message"),
+            )
+            .with_globals(MessageProperty {
+                custom_id: Some("flight-42".into()),
+                ..Default::default()
+            });
+        assert!(request.build().is_deduplicable());
+    }
+
+    #[rstest]
+    fn builder_sets_all_fields() {
+        let request = SendEmailParamsBuilder::default()
+            .with_sandbox_mode(true)
+            .with_advance_error_handling(false)
+            .build();
+
+        assert_eq!(request.sandbox_mode, Some(true));
+        assert_eq!(request.advance_error_handling, Some(false));
+        assert!(request.globals.is_none());
+        assert!(request.effective_messages().is_empty());
+    }
+}
@@ -5,8 +5,9 @@
 
 //! Translations of data objects returned by the endpoints of Mailjet's API.
 
-use crate::data_objects::ResponseObject;
-use serde::{Deserialize, Serialize};
+use crate::data_objects::{RequestObject, ResponseObject};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 
 /// Data object returned by `/send` (v3.1) as `Messages`. See [`/send`](https://dev.mailjet.com/email/reference/send-emails#v3_1_post_send)
 #[derive(Deserialize, Debug)]
@@ -21,14 +22,48 @@ pub struct SendResponseObject {
 
 impl ResponseObject for SendResponseObject {}
 
-/// Data object for the field `Errors` in the response of `/send`. See [`/send`](https://dev.mailjet.com/email/reference/send-emails#v3_1_post_send)
+/// Body of the response returned by `/send` (v3.1). See [`/send`](https://dev.mailjet.com/email/reference/send-emails#v3_1_post_send)
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct SendV3_1Response {
+    pub messages: Vec<SendResponseObject>,
+}
+
+impl From<SendV3_1Response> for Vec<SendResponseObject> {
+    fn from(value: SendV3_1Response) -> Self {
+        value.messages
+    }
+}
+
+/// Body of the responses returned by the endpoints of the REST API (v3).
+///
+/// # Description
+///
+/// All the endpoints of the REST API wrap the returned objects using this layout. See
+/// [Mailjet REST API responses](crate::data_objects).
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
+pub struct RestResponse<T> {
+    pub count: u64,
+    pub data: Vec<T>,
+    pub total: u64,
+}
+
+impl<T> From<RestResponse<T>> for Vec<T> {
+    fn from(value: RestResponse<T>) -> Self {
+        value.data
+    }
+}
+
+/// Data object for the field `Errors` in the response of `/send`. See [`/send`](https://dev.mailjet.com/email/reference/send-emails#v3_1_post_send)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
 pub struct ResponseError {
     pub error_identifier: String,
     pub error_code: String,
     pub status_code: i64,
     pub error_message: String,
+    #[serde(default)]
     pub error_related_to: Vec<String>,
 }
 
@@ -39,7 +74,7 @@ pub struct ResponseError {
 /// This object is shared between some fields returned by endpoints that implements the API v3.1 and as
 /// main payload of endpoints that implement the API v3.0.
 /// See [`/send`](https://dev.mailjet.com/email/reference/send-emails#v3_1_post_send), for example.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct MessageObject {
     pub email: Option<String>,
@@ -52,7 +87,36 @@ pub struct MessageObject {
 
 impl ResponseObject for MessageObject {}
 
-#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+impl MessageObject {
+    /// Get the ID of the message.
+    ///
+    /// # Description
+    ///
+    /// The ID is taken from `MessageID`, or from the last segment of `MessageHref` when the former is missing.
+    pub fn id(&self) -> Option<i64> {
+        self.message_id.or_else(|| {
+            self.message_href
+                .as_deref()
+                .and_then(|href| href.trim_end_matches('/').rsplit('/').next())
+                .and_then(|id| id.parse().ok())
+        })
+    }
+}
+
+/// Body of the response returned by `/send` (v3). See [`/send`](https://dev.mailjet.com/email/reference/send-emails#v3_post_send)
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct SendV3Response {
+    pub sent: Vec<MessageObject>,
+}
+
+impl From<SendV3Response> for Vec<MessageObject> {
+    fn from(value: SendV3Response) -> Self {
+        value.sent
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum EmailType {
     Transactional,
@@ -61,7 +125,7 @@ pub enum EmailType {
     Unknown,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
 pub enum Status {
     Inactive,
     #[default]
@@ -76,8 +140,9 @@ pub enum ResponseStatus {
     Error,
 }
 
+/// Data object returned by `/sender`. See [`/sender`](https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender)
 #[derive(Deserialize, Default, Debug, Clone)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Sender {
     pub email_type: EmailType,
     pub is_default_sender: bool,
@@ -91,3 +156,604 @@ pub struct Sender {
     pub id: i64,
     pub status: Status,
 }
+
+impl ResponseObject for Sender {}
+
+/// Data object returned by `/sender/{sender_ID}/validate`.
+///
+/// # Description
+///
+/// `errors` is empty when the validation succeeded. See
+/// [`/sender/{sender_ID}/validate`](https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender#v3_post_sender_sender_ID_validate).
+#[derive(Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SenderValidation {
+    pub errors: HashMap<String, String>,
+    pub validation_method: String,
+    pub global_error: String,
+}
+
+impl ResponseObject for SenderValidation {}
+
+/// Data object returned by `/contact`. See [`/contact`](https://dev.mailjet.com/email/reference/contacts/contact)
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Contact {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub email: String,
+    #[serde(default)]
+    pub name: String,
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub delivered_count: i64,
+    pub exclusion_from_campaigns_updated_at: Option<String>,
+    #[serde(default)]
+    pub is_excluded_from_campaigns: bool,
+    #[serde(default)]
+    pub is_opt_in_pending: bool,
+    #[serde(default)]
+    pub is_spam_complaining: bool,
+    pub last_activity_at: Option<String>,
+    pub last_update_at: Option<String>,
+    pub unsubscribed_at: Option<String>,
+    pub unsubscribed_by: Option<String>,
+}
+
+impl ResponseObject for Contact {}
+
+/// Type of the owner of a template.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateOwnerType {
+    #[default]
+    Apikey,
+    User,
+    Global,
+}
+
+/// Purpose of a template.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplatePurpose {
+    Marketing,
+    Transactional,
+    Automation,
+}
+
+/// Data object returned by `/template`. See [`/template`](https://dev.mailjet.com/email/reference/templates)
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Template {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub name: String,
+    pub author: String,
+    pub categories: Vec<String>,
+    pub copyright: String,
+    pub description: String,
+    /// `1`: drag and drop builder, `2`: HTML builder, `3`: saved section, `4`: MJML.
+    pub edit_mode: u8,
+    pub is_starred: bool,
+    pub is_text_part_generation_enabled: bool,
+    pub locale: String,
+    #[serde(rename = "OwnerId")]
+    pub owner_id: i64,
+    pub owner_type: TemplateOwnerType,
+    pub purposes: Vec<TemplatePurpose>,
+    pub created_at: Option<String>,
+    pub last_updated_at: Option<String>,
+}
+
+impl ResponseObject for Template {}
+
+/// Content of a template, used by `/template/{template_ID}/detailcontent`.
+///
+/// # Description
+///
+/// This object is returned by the external API, and it is also used to set the content of a template. Attributes set
+/// to `None` are not sent. The `Headers` include the default `Subject`, `SenderName`, `SenderEmail`, `From` and
+/// `Reply-To` of the messages sent using the template.
+/// See [`/template/{template_ID}/detailcontent`](https://dev.mailjet.com/email/reference/templates#v3_get_template_template_ID_detailcontent).
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TemplateContent {
+    #[serde(rename = "Html-part", skip_serializing_if = "Option::is_none")]
+    pub html_part: Option<String>,
+    #[serde(rename = "Text-part", skip_serializing_if = "Option::is_none")]
+    pub text_part: Option<String>,
+    #[serde(rename = "MJMLContent", skip_serializing_if = "Option::is_none")]
+    pub mjml_content: Option<serde_json::Value>,
+    #[serde(rename = "Headers", skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+}
+
+impl ResponseObject for TemplateContent {}
+
+impl RequestObject for TemplateContent {}
+
+/// Delivery status of a message, also used as the type of the events of its history.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageStatus {
+    Processed,
+    Queued,
+    Sent,
+    Opened,
+    Clicked,
+    #[serde(alias = "bounced")]
+    Bounce,
+    #[serde(alias = "hardbounce")]
+    HardBounced,
+    #[serde(alias = "softbounce")]
+    SoftBounced,
+    Spam,
+    Unsub,
+    Blocked,
+    Deferred,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Data object returned by `/message`. See [`/message`](https://dev.mailjet.com/email/reference/messages/message)
+///
+/// # Description
+///
+/// `subject` and `contact_alt` are only filled when the filters `ShowSubject` and `ShowContactAlt` are set.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SentMessage {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub arrived_at: Option<String>,
+    pub attachment_count: i64,
+    pub attempt_count: i64,
+    #[serde(rename = "CampaignID")]
+    pub campaign_id: i64,
+    pub contact_alt: String,
+    #[serde(rename = "ContactID")]
+    pub contact_id: i64,
+    pub delay: f64,
+    #[serde(rename = "DestinationID")]
+    pub destination_id: i64,
+    pub filter_time: i64,
+    pub is_click_tracked: bool,
+    #[serde(rename = "IsHTMLPartIncluded")]
+    pub is_html_part_included: bool,
+    pub is_open_tracked: bool,
+    pub is_text_part_included: bool,
+    pub is_unsub_tracked: bool,
+    pub message_size: i64,
+    #[serde(rename = "SenderID")]
+    pub sender_id: i64,
+    pub spamassassin_score: f64,
+    pub spamass_rules: String,
+    pub state_permanent: bool,
+    pub status: MessageStatus,
+    pub subject: String,
+    #[serde(rename = "UUID")]
+    pub uuid: String,
+}
+
+impl ResponseObject for SentMessage {}
+
+/// Data object returned by `/messagehistory/{message_ID}`: an event of the history of a message.
+///
+/// # Description
+///
+/// `event_at` is a Unix timestamp. See
+/// [`/messagehistory`](https://dev.mailjet.com/email/reference/messages/message#v3_get_messagehistory_message_ID).
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct MessageEvent {
+    pub comment: String,
+    pub event_at: i64,
+    pub event_type: MessageStatus,
+    pub state: String,
+    pub useragent: String,
+    #[serde(rename = "UseragentID")]
+    pub useragent_id: i64,
+}
+
+impl ResponseObject for MessageEvent {}
+
+/// SpamAssassin rules matched by a message.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SpamAssassinRules {
+    #[serde(rename = "ALT")]
+    pub alt: String,
+    #[serde(rename = "ID")]
+    pub id: i64,
+}
+
+/// Data object returned by `/messageinformation`. See [`/messageinformation`](https://dev.mailjet.com/email/reference/messages/message#v3_get_messageinformation)
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct MessageInformation {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "CampaignID")]
+    pub campaign_id: i64,
+    pub click_tracked_count: i64,
+    #[serde(rename = "ContactID")]
+    pub contact_id: i64,
+    pub created_at: Option<String>,
+    pub message_size: i64,
+    pub open_tracked_count: i64,
+    pub queued_count: i64,
+    pub send_end_at: Option<String>,
+    pub sent_count: i64,
+    pub spam_assassin_rules: SpamAssassinRules,
+    pub spam_assassin_score: f64,
+}
+
+impl ResponseObject for MessageInformation {}
+
+/// A message together with its delivery history, as returned by [crate::MailjetClient::track_message].
+#[derive(Debug, Clone, Default)]
+pub struct MessageDelivery {
+    pub message: SentMessage,
+    /// Events of the message, in the order given by the external API (oldest first).
+    pub history: Vec<MessageEvent>,
+}
+
+impl MessageDelivery {
+    /// Current delivery status of the message.
+    pub fn status(&self) -> MessageStatus {
+        self.message.status
+    }
+
+    /// Most recent event of the message, if any.
+    pub fn last_event(&self) -> Option<&MessageEvent> {
+        self.history.iter().max_by_key(|e| e.event_at)
+    }
+}
+
+/// Type of the events notified by Mailjet to a webhook.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum EventType {
+    Sent,
+    Open,
+    Click,
+    Bounce,
+    Blocked,
+    Spam,
+    Unsub,
+}
+
+/// Status of a webhook. Mailjet marks a webhook as `dead` when it fails to deliver events to it repeatedly.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CallbackStatus {
+    #[default]
+    Alive,
+    Dead,
+}
+
+/// Data object returned by `/eventcallbackurl`. See [`/eventcallbackurl`](https://dev.mailjet.com/email/reference/webhook)
+///
+/// # Description
+///
+/// `version` is `1` when the events are sent one per request, and `2` when they are grouped in arrays.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventCallbackUrl {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "APIKeyID", default)]
+    pub api_key_id: i64,
+    pub event_type: EventType,
+    #[serde(default)]
+    pub is_backup: bool,
+    #[serde(default)]
+    pub status: CallbackStatus,
+    pub url: String,
+    #[serde(default)]
+    pub version: u8,
+}
+
+impl ResponseObject for EventCallbackUrl {}
+
+/// Data object returned by `/parseroute`. See [`/parseroute`](https://dev.mailjet.com/email/reference/parse-api)
+///
+/// # Description
+///
+/// Mailjet forwards the emails received at `email` to `url`. See [crate::inbound::InboundEmail].
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ParseRoute {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "APIKeyID")]
+    pub api_key_id: i64,
+    pub email: String,
+    pub url: String,
+}
+
+impl ResponseObject for ParseRoute {}
+
+/// Data object returned by `/contactslist`. See [`/contactslist`](https://dev.mailjet.com/email/reference/contacts/contact-list)
+///
+/// # Description
+///
+/// `address` is the address generated by Mailjet to send a campaign to all the subscribers of the list.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactsList {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub address: String,
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub is_deleted: bool,
+    #[serde(default)]
+    pub subscriber_count: i64,
+}
+
+impl ResponseObject for ContactsList {}
+
+/// Data object returned by `/listrecipient`: the subscription of a contact to a list.
+/// See [`/listrecipient`](https://dev.mailjet.com/email/reference/contacts/subscriptions)
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListRecipient {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "ContactID")]
+    pub contact_id: i64,
+    #[serde(rename = "ListID")]
+    pub list_id: i64,
+    #[serde(default)]
+    pub list_name: String,
+    #[serde(default)]
+    pub is_active: bool,
+    #[serde(default)]
+    pub is_unsubscribed: bool,
+    pub subscribed_at: Option<String>,
+    pub unsubscribed_at: Option<String>,
+}
+
+impl ResponseObject for ListRecipient {}
+
+/// Data object returned by `/contact/{contact_ID}/getcontactslists`: a list the contact belongs to.
+/// See [`/contact/{contact_ID}/getcontactslists`](https://dev.mailjet.com/email/reference/contacts/contact#v3_get_contact_contact_ID_getcontactslists)
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactListMembership {
+    #[serde(rename = "ListID")]
+    pub list_id: i64,
+    #[serde(default)]
+    pub is_active: bool,
+    #[serde(default)]
+    pub is_unsub: bool,
+    pub subscribed_at: Option<String>,
+}
+
+impl ResponseObject for ContactListMembership {}
+
+/// Handle of an asynchronous job started by the external API.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct JobHandle {
+    #[serde(rename = "JobID")]
+    pub job_id: i64,
+}
+
+impl ResponseObject for JobHandle {}
+
+/// Status of an asynchronous job.
+///
+/// # Description
+///
+/// A status that the client doesn't know about is kept as [JobStatus::Unknown], with the raw string sent by the
+/// external API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobStatus {
+    Allocated,
+    Upload,
+    Pending,
+    InProgress,
+    Completed,
+    Error,
+    Abort,
+    Unknown(String),
+}
+
+impl Default for JobStatus {
+    fn default() -> Self {
+        JobStatus::Unknown(String::new())
+    }
+}
+
+impl<'de> Deserialize<'de> for JobStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+
+        Ok(match status.as_str() {
+            "Allocated" => JobStatus::Allocated,
+            "Upload" => JobStatus::Upload,
+            "Pending" => JobStatus::Pending,
+            "In Progress" | "InProgress" => JobStatus::InProgress,
+            "Completed" => JobStatus::Completed,
+            "Error" => JobStatus::Error,
+            "Abort" => JobStatus::Abort,
+            _ => JobStatus::Unknown(status),
+        })
+    }
+}
+
+impl JobStatus {
+    /// Whether the job finished, either successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::Error | JobStatus::Abort
+        )
+    }
+}
+
+/// Data object returned by `/contact/managemanycontacts/{job_ID}` and
+/// `/contactslist/{list_ID}/managemanycontacts/{job_ID}`.
+///
+/// # Description
+///
+/// `count` is the amount of contacts processed by the job. When some contacts couldn't be processed, `error_file`
+/// includes the ID of a file that lists them. See
+/// [`/contact/managemanycontacts`](https://dev.mailjet.com/email/reference/contacts/bulk-contact-management).
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ContactsJob {
+    pub count: i64,
+    pub error: String,
+    pub error_file: String,
+    pub job_start: String,
+    pub job_end: String,
+    pub status: JobStatus,
+}
+
+impl ResponseObject for ContactsJob {}
+
+/// Data object returned by the DATA API after uploading content.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct DataUpload {
+    /// ID of the uploaded content, used to reference it (e.g. `DataID` of [crate::data_objects::CsvImportQuery]).
+    #[serde(rename = "ID")]
+    pub id: i64,
+}
+
+/// Data object returned by `/csvimport`. See [`/csvimport`](https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_post_csvimport)
+///
+/// # Description
+///
+/// `count` is the amount of contacts imported, and `errcount` the amount of lines that couldn't be imported. The
+/// lines with errors can be downloaded using [crate::MailjetClient::download_csv_import_errors].
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CsvImport {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "ContactsListID")]
+    pub contacts_list_id: i64,
+    #[serde(rename = "DataID")]
+    pub data_id: i64,
+    pub count: i64,
+    pub current: i64,
+    pub errcount: i64,
+    pub err_treshold: i64,
+    pub import_options: String,
+    pub method: String,
+    pub request_at: String,
+    pub job_start: String,
+    pub job_end: String,
+    pub status: JobStatus,
+}
+
+impl ResponseObject for CsvImport {}
+
+/// Data type of a contact property.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PropertyDataType {
+    #[default]
+    Str,
+    Int,
+    Float,
+    Bool,
+    Datetime,
+}
+
+/// Namespace of a contact property.
+///
+/// # Description
+///
+/// `static` properties keep a single value per contact, while `historic` properties keep the values of past updates
+/// too (e.g. for segmentation on previous purchases).
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PropertyNamespace {
+    #[default]
+    Static,
+    Historic,
+}
+
+/// Data object returned by `/contactmetadata`. See [`/contactmetadata`](https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactmetadata)
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ContactMetadata {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub datatype: PropertyDataType,
+    pub name: String,
+    #[serde(rename = "NameSpace")]
+    pub namespace: PropertyNamespace,
+}
+
+impl ResponseObject for ContactMetadata {}
+
+/// Value of a contact property.
+///
+/// # Description
+///
+/// The external API returns all the values as strings, regardless of the data type of the property. Use
+/// [crate::contact_properties::ContactProperties] to convert them into typed values.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactProperty {
+    pub name: String,
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+/// Data object returned by `/contactdata`. See [`/contactdata`](https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactdata)
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ContactData {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    #[serde(rename = "ContactID")]
+    pub contact_id: i64,
+    pub data: Vec<ContactProperty>,
+}
+
+impl ContactData {
+    /// Get the value of a property, if the contact has one.
+    pub fn get(&self, name: &str) -> Option<&serde_json::Value> {
+        self.data.iter().find(|p| p.name == name).map(|p| &p.value)
+    }
+}
+
+impl ResponseObject for ContactData {}
+
+/// Status of a segmentation filter: whether it is used by some campaign.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContactFilterStatus {
+    Used,
+    Unused,
+    Deleted,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Data object returned by `/contactfilter`. See [`/contactfilter`](https://dev.mailjet.com/email/reference/segmentation#v3_get_contactfilter)
+///
+/// # Description
+///
+/// `expression` follows the segmentation syntax of Mailjet, e.g. `(age<35) AND (gender="female")`. See
+/// [crate::segmentation] to build it.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ContactFilter {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub name: String,
+    pub description: String,
+    pub expression: String,
+    pub status: ContactFilterStatus,
+}
+
+impl ResponseObject for ContactFilter {}
@@ -0,0 +1,429 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Module that implements the local (pre-flight) validation of the data objects used to send emails.
+
+use crate::{
+    data_objects::{
+        check_attachments_size, Attachment, Message, MessageProperty, NameAndEmail,
+        SendEmailParams, SimpleMessage,
+    },
+    MAX_MESSAGES_PER_REQUEST,
+};
+use std::{collections::HashMap, fmt};
+
+/// Maximum amount of recipients (`To`, `Cc` and `Bcc`) of a single message.
+pub const MAX_RECIPIENTS: usize = 50;
+
+/// Maximum value of the `Priority` of a message.
+pub const MAX_PRIORITY: u8 = 4;
+
+/// A rule of the external API that is broken by a data object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// Path of the offending field, using the names of the external API, e.g. `Messages[0].To[1].Email`.
+    pub field: String,
+    /// Description of the broken rule.
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// List of the violations found when validating a data object. It is never empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationErrors {
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationErrors {
+    /// Whether any violation was found for the given field path.
+    pub fn contains(&self, field: &str) -> bool {
+        self.violations.iter().any(|v| v.field == field)
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let violations: Vec<_> = self.violations.iter().map(Violation::to_string).collect();
+
+        write!(f, "{}", violations.join("; "))
+    }
+}
+
+/// Local validation of the data objects used to send emails.
+///
+/// # Description
+///
+/// The documented rules of the endpoint `/send` are checked offline, so malformed requests are caught before
+/// reaching the external API:
+/// - The sender's address (`From`) and the recipients' addresses are syntactically valid.
+/// - Messages have at least one recipient, and no more than [MAX_RECIPIENTS] (`To`, `Cc` and `Bcc` together).
+/// - Messages have content: a text part, an HTML part or a template.
+/// - The `Priority` is within the range `[0, 4]`.
+/// - The attachments don't exceed [crate::data_objects::MAX_MESSAGE_SIZE].
+/// - A request includes between 1 and [MAX_MESSAGES_PER_REQUEST] messages, and their `CustomID` are unique.
+///
+/// The client runs the validation when sending emails if enabled using [crate::MailjetClient::enable_validation].
+pub trait Validate {
+    /// Check the data object, returning all the violations found.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+impl Validate for Message {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut violations = Violations::default();
+        violations.message(self, "", None);
+
+        violations.into_result()
+    }
+}
+
+impl Validate for SendEmailParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut violations = Violations::default();
+
+        if self.messages.is_empty() {
+            violations.push("Messages", "at least one message is required");
+        } else if self.messages.len() > MAX_MESSAGES_PER_REQUEST {
+            violations.push(
+                "Messages",
+                &format!("no more than {MAX_MESSAGES_PER_REQUEST} messages are allowed"),
+            );
+        }
+
+        if let Some(globals) = &self.globals {
+            violations.globals(globals);
+        }
+
+        let mut custom_ids = HashMap::new();
+
+        for (i, message) in self.messages.iter().enumerate() {
+            let prefix = format!("Messages[{i}].");
+            violations.message(message, &prefix, self.globals.as_ref());
+
+            if let Some(custom_id) = &message.custom_id {
+                if let Some(first) = custom_ids.insert(custom_id, i) {
+                    violations.push(
+                        &format!("{prefix}CustomID"),
+                        &format!("duplicated CustomID (first used by Messages[{first}])"),
+                    );
+                }
+            }
+        }
+
+        violations.into_result()
+    }
+}
+
+impl Validate for SimpleMessage {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut violations = Violations::default();
+
+        violations.address("FromEmail", &self.from_email);
+
+        let has_headers = [&self.to, &self.cc, &self.bcc]
+            .into_iter()
+            .any(|r| r.as_deref().is_some_and(|r| !r.trim().is_empty()));
+
+        if self.recipients.is_empty() && !has_headers {
+            violations.push("Recipients", "at least one recipient is required");
+        } else if self.recipients.len() > MAX_RECIPIENTS {
+            violations.push(
+                "Recipients",
+                &format!("no more than {MAX_RECIPIENTS} recipients are allowed"),
+            );
+        }
+
+        for (i, recipient) in self.recipients.iter().enumerate() {
+            violations.address(&format!("Recipients[{i}].Email"), &recipient.email);
+        }
+
+        if self.text_part.is_none() && self.html_part.is_none() {
+            violations.push("Text-part", "a text part or an HTML part is required");
+        }
+
+        violations.attachments("", &[&self.attachments, &self.inline_attachments]);
+
+        violations.into_result()
+    }
+}
+
+/// Accumulator of violations.
+#[derive(Default)]
+struct Violations(Vec<Violation>);
+
+impl Violations {
+    fn push(&mut self, field: &str, message: &str) {
+        self.0.push(Violation {
+            field: field.into(),
+            message: message.into(),
+        });
+    }
+
+    fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors { violations: self.0 })
+        }
+    }
+
+    fn address(&mut self, field: &str, email: &str) {
+        if !is_valid_email(email) {
+            self.push(field, &format!("'{email}' is not a valid email address"));
+        }
+    }
+
+    fn recipient(&mut self, field: &str, recipient: &Option<NameAndEmail>) {
+        if let Some(recipient) = recipient {
+            self.address(&format!("{field}.Email"), &recipient.email);
+        }
+    }
+
+    fn recipients(&mut self, field: &str, recipients: &Option<Vec<NameAndEmail>>) {
+        for (i, recipient) in recipients.iter().flatten().enumerate() {
+            self.address(&format!("{field}[{i}].Email"), &recipient.email);
+        }
+    }
+
+    fn priority(&mut self, field: &str, priority: Option<u8>) {
+        if priority.is_some_and(|p| p > MAX_PRIORITY) {
+            self.push(
+                field,
+                &format!("the priority must be within the range [0, {MAX_PRIORITY}]"),
+            );
+        }
+    }
+
+    fn attachments(&mut self, prefix: &str, attachments: &[&Option<Vec<Attachment>>]) {
+        if let Err(e) = check_attachments_size(attachments.iter().copied()) {
+            self.push(&format!("{prefix}Attachments"), &e.to_string());
+        }
+    }
+
+    fn globals(&mut self, globals: &MessageProperty) {
+        self.recipient("Globals.From", &globals.from);
+        self.recipient("Globals.Sender", &globals.sender);
+        self.recipient("Globals.ReplyTo", &globals.reply_to);
+        self.recipients("Globals.Cc", &globals.cc);
+        self.recipients("Globals.Bcc", &globals.bcc);
+        self.priority("Globals.Priority", globals.priority);
+    }
+
+    fn message(&mut self, message: &Message, prefix: &str, globals: Option<&MessageProperty>) {
+        let field = |name: &str| format!("{prefix}{name}");
+
+        self.address(&field("From.Email"), &message.from.email);
+        self.recipient(&field("Sender"), &message.sender);
+        self.recipient(&field("ReplyTo"), &message.reply_to);
+        self.recipients(&field("To"), &message.to);
+        self.recipients(&field("Cc"), &message.cc);
+        self.recipients(&field("Bcc"), &message.bcc);
+
+        let global_recipients = globals.map_or(0, |g| count(&g.cc) + count(&g.bcc));
+        let recipients = count(&message.to) + count(&message.cc) + count(&message.bcc);
+
+        if recipients + global_recipients == 0 {
+            self.push(&field("To"), "at least one recipient is required");
+        } else if recipients + global_recipients > MAX_RECIPIENTS {
+            self.push(
+                &field("To"),
+                &format!("no more than {MAX_RECIPIENTS} recipients (To, Cc and Bcc) are allowed"),
+            );
+        }
+
+        let has_content = |text: &Option<String>, html: &Option<String>, template: Option<u64>| {
+            text.is_some() || html.is_some() || template.is_some()
+        };
+
+        if !has_content(&message.text_part, &message.html_part, message.template_id)
+            && !globals.is_some_and(|g| has_content(&g.text_part, &g.html_part, g.template_id))
+        {
+            self.push(
+                &field("TextPart"),
+                "a text part, an HTML part or a template ID is required",
+            );
+        }
+
+        self.priority(&field("Priority"), message.priority);
+
+        let (global_attachments, global_inline) = match globals {
+            Some(g) => (&g.attachments, &g.inline_attachments),
+            None => (&None, &None),
+        };
+        self.attachments(
+            prefix,
+            &[
+                &message.attachments,
+                &message.inline_attachments,
+                global_attachments,
+                global_inline,
+            ],
+        );
+    }
+}
+
+fn count(recipients: &Option<Vec<NameAndEmail>>) -> usize {
+    recipients.as_ref().map_or(0, Vec::len)
+}
+
+/// Basic syntactic check of an email address: `local@domain.tld`, without whitespace and with a single `@`.
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !local.contains('@')
+        && local.len() <= 64
+        && !email.chars().any(|c| c.is_whitespace() || c.is_control())
+        && domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_objects::MessageBuilder;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[fixture]
+    fn message() -> Message {
+        MessageBuilder::default()
+            .with_from("pilot@mailjet.com", Some("Mailjet Pilot"))
+            .with_to("passenger@mailjet.com", None)
+            .with_subject("Your email flight plan!")
+            .with_text_body("Dear passenger, welcome to Mailjet!")
+            .build()
+            .expect("Failed to build the message")
+    }
+
+    #[fixture]
+    fn request(message: Message) -> SendEmailParams {
+        SendEmailParams {
+            sandbox_mode: None,
+            advance_error_handling: None,
+            globals: None,
+            messages: vec![message],
+        }
+    }
+
+    #[rstest]
+    #[case("passenger@mailjet.com", true)]
+    #[case("first.last+tag@sub.mailjet.com", true)]
+    #[case("passenger", false)]
+    #[case("@mailjet.com", false)]
+    #[case("passenger@mailjet", false)]
+    #[case("passenger@mail..com", false)]
+    #[case("pass enger@mailjet.com", false)]
+    #[case("passenger@-mailjet.com", false)]
+    #[case("a@b@example.com", false)]
+    fn email_syntax(#[case] email: &str, #[case] valid: bool) {
+        assert_eq!(is_valid_email(email), valid);
+    }
+
+    #[rstest]
+    fn valid_message(message: Message) {
+        assert_eq!(message.validate(), Ok(()));
+    }
+
+    #[rstest]
+    fn invalid_message(mut message: Message) {
+        message.from.email = "pilot".into();
+        message.to = None;
+        message.text_part = None;
+        message.priority = Some(5);
+
+        let errors = message.validate().unwrap_err();
+
+        assert_eq!(errors.violations.len(), 4);
+        assert!(errors.contains("From.Email"));
+        assert!(errors.contains("To"));
+        assert!(errors.contains("TextPart"));
+        assert!(errors.contains("Priority"));
+    }
+
+    #[rstest]
+    fn too_many_recipients(mut message: Message) {
+        message.cc = Some(vec![
+            NameAndEmail::new("cc@mailjet.com", None);
+            MAX_RECIPIENTS
+        ]);
+
+        assert!(message.validate().unwrap_err().contains("To"));
+    }
+
+    #[rstest]
+    fn globals_are_considered(mut request: SendEmailParams) {
+        request.messages[0].text_part = None;
+        assert!(request
+            .validate()
+            .unwrap_err()
+            .contains("Messages[0].TextPart"));
+
+        request.globals = Some(MessageProperty {
+            text_part: Some("Dear passenger, welcome to Mailjet!".into()),
+            bcc: Some(vec![NameAndEmail::new("bcc@mailjet", None)]),
+            ..Default::default()
+        });
+        let errors = request.validate().unwrap_err();
+        assert_eq!(errors.violations.len(), 1);
+        assert!(errors.contains("Globals.Bcc[0].Email"));
+    }
+
+    #[rstest]
+    fn duplicated_custom_ids(message: Message, mut request: SendEmailParams) {
+        request.messages[0].custom_id = Some("flight".into());
+        request.messages.push(message.clone());
+        request.messages.push(Message {
+            custom_id: Some("flight".into()),
+            ..message
+        });
+
+        let errors = request.validate().unwrap_err();
+
+        assert_eq!(errors.violations.len(), 1);
+        assert!(errors.contains("Messages[2].CustomID"));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(MAX_MESSAGES_PER_REQUEST + 1)]
+    fn message_count(message: Message, mut request: SendEmailParams, #[case] messages: usize) {
+        request.messages = vec![message; messages];
+
+        assert!(request.validate().unwrap_err().contains("Messages"));
+    }
+
+    #[rstest]
+    fn simple_message() {
+        let mut message = SimpleMessage {
+            from_email: "pilot@mailjet.com".into(),
+            recipients: vec![NameAndEmail::new("passenger@mailjet.com", None)],
+            text_part: Some("Dear passenger, welcome to Mailjet!".into()),
+            ..Default::default()
+        };
+        assert_eq!(message.validate(), Ok(()));
+
+        message.recipients.clear();
+        message.text_part = None;
+        let errors = message.validate().unwrap_err();
+        assert!(errors.contains("Recipients"));
+        assert!(errors.contains("Text-part"));
+
+        message.to = Some("Passenger <passenger@mailjet.com>".into());
+        assert!(!message.validate().unwrap_err().contains("Recipients"));
+    }
+}
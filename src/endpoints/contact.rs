@@ -0,0 +1,154 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage contacts.
+
+use crate::{
+    data_objects::{
+        Contact, ContactFilters, ContactListMembership, ContactQuery, ContactUpdate, NoParams,
+        ResourceId, RestResponse,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/contact`][contact] (POST): create a new contact.
+///
+/// [contact]: https://dev.mailjet.com/email/reference/contacts/contact#v3_post_contact
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateContact;
+
+impl Endpoint for CreateContact {
+    type Request = ContactQuery;
+    type Response = Contact;
+    type Payload = RestResponse<Contact>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contact")
+    }
+}
+
+/// Endpoint [`/contact/{contact_ID}`][contact] (GET): retrieve a contact by its ID or email address.
+///
+/// [contact]: https://dev.mailjet.com/email/reference/contacts/contact#v3_get_contact_contact_ID
+#[derive(Debug, Clone)]
+pub struct GetContact(pub ResourceId);
+
+impl Endpoint for GetContact {
+    type Request = NoParams;
+    type Response = Contact;
+    type Payload = RestResponse<Contact>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contact/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contact`][contact] (GET): retrieve a list of contacts.
+///
+/// [contact]: https://dev.mailjet.com/email/reference/contacts/contact#v3_get_contact
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListContacts;
+
+impl Endpoint for ListContacts {
+    type Request = ContactFilters;
+    type Response = Contact;
+    type Payload = RestResponse<Contact>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contact")
+    }
+}
+
+/// Endpoint [`/contact/{contact_ID}`][contact] (PUT): update the name or the exclusion flag of a contact.
+///
+/// [contact]: https://dev.mailjet.com/email/reference/contacts/contact#v3_put_contact_contact_ID
+#[derive(Debug, Clone)]
+pub struct UpdateContact(pub ResourceId);
+
+impl Endpoint for UpdateContact {
+    type Request = ContactUpdate;
+    type Response = Contact;
+    type Payload = RestResponse<Contact>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contact/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contacts/{contact_ID}`][contacts] (DELETE) of the API v4: delete a contact (GDPR).
+///
+/// # Description
+///
+/// The external API doesn't include a payload in the response.
+///
+/// [contacts]: https://dev.mailjet.com/email/reference/contacts/contact#v4_delete_contacts_contact_ID
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteContact(pub i64);
+
+impl Endpoint for DeleteContact {
+    type Request = NoParams;
+    type Response = Contact;
+    type Payload = RestResponse<Contact>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V4;
+
+    fn path(&self) -> String {
+        ApiUrl::v4(&format!("contacts/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contact/{contact_ID}/getcontactslists`][contact] (GET): retrieve the lists a contact belongs to.
+///
+/// [contact]: https://dev.mailjet.com/email/reference/contacts/contact#v3_get_contact_contact_ID_getcontactslists
+#[derive(Debug, Clone)]
+pub struct GetContactsLists(pub ResourceId);
+
+impl Endpoint for GetContactsLists {
+    type Request = NoParams;
+    type Response = ContactListMembership;
+    type Payload = RestResponse<ContactListMembership>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contact/{}/getcontactslists", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn contact_endpoints() {
+        assert_eq!(CreateContact.path(), "v3/REST/contact");
+        assert_eq!(ListContacts.path(), "v3/REST/contact");
+        assert_eq!(GetContact(42.into()).path(), "v3/REST/contact/42");
+        assert_eq!(
+            GetContact("jane@mail.com".into()).path(),
+            "v3/REST/contact/jane@mail.com"
+        );
+        assert_eq!(UpdateContact(42.into()).path(), "v3/REST/contact/42");
+        assert_eq!(
+            GetContactsLists(42.into()).path(),
+            "v3/REST/contact/42/getcontactslists"
+        );
+        assert_eq!(DeleteContact(42).path(), "v4/contacts/42");
+        assert_eq!(DeleteContact::API_VERSION, ApiVersion::V4);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage the values of the contact properties.
+
+use crate::{
+    data_objects::{
+        ContactData, ContactDataFilters, ContactDataUpdate, NoParams, ResourceId, RestResponse,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/contactdata`][data] (GET): retrieve the properties of a list of contacts.
+///
+/// [data]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactdata
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListContactData;
+
+impl Endpoint for ListContactData {
+    type Request = ContactDataFilters;
+    type Response = ContactData;
+    type Payload = RestResponse<ContactData>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contactdata")
+    }
+}
+
+/// Endpoint [`/contactdata/{contact_ID}`][data] (GET): retrieve the properties of a contact by its ID or email
+/// address.
+///
+/// [data]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactdata_contact_ID
+#[derive(Debug, Clone)]
+pub struct GetContactData(pub ResourceId);
+
+impl Endpoint for GetContactData {
+    type Request = NoParams;
+    type Response = ContactData;
+    type Payload = RestResponse<ContactData>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactdata/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactdata/{contact_ID}`][data] (PUT): update the properties of a contact.
+///
+/// [data]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_put_contactdata_contact_ID
+#[derive(Debug, Clone)]
+pub struct UpdateContactData(pub ResourceId);
+
+impl Endpoint for UpdateContactData {
+    type Request = ContactDataUpdate;
+    type Response = ContactData;
+    type Payload = RestResponse<ContactData>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactdata/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn contact_data_endpoints() {
+        assert_eq!(ListContactData.path(), "v3/REST/contactdata");
+        assert_eq!(GetContactData(7.into()).path(), "v3/REST/contactdata/7");
+        assert_eq!(
+            UpdateContactData("passenger@mailjet.com".into()).path(),
+            "v3/REST/contactdata/passenger@mailjet.com"
+        );
+    }
+}
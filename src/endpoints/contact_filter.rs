@@ -0,0 +1,122 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage the segmentation filters of the contacts.
+
+use crate::{
+    data_objects::{
+        ContactFilter, ContactFilterFilters, ContactFilterQuery, NoParams, RestResponse,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/contactfilter`][filter] (GET): retrieve the list of segmentation filters.
+///
+/// [filter]: https://dev.mailjet.com/email/reference/segmentation#v3_get_contactfilter
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListContactFilters;
+
+impl Endpoint for ListContactFilters {
+    type Request = ContactFilterFilters;
+    type Response = ContactFilter;
+    type Payload = RestResponse<ContactFilter>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contactfilter")
+    }
+}
+
+/// Endpoint [`/contactfilter/{contactfilter_ID}`][filter] (GET): retrieve a segmentation filter.
+///
+/// [filter]: https://dev.mailjet.com/email/reference/segmentation#v3_get_contactfilter_contactfilter_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetContactFilter(pub i64);
+
+impl Endpoint for GetContactFilter {
+    type Request = NoParams;
+    type Response = ContactFilter;
+    type Payload = RestResponse<ContactFilter>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactfilter/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactfilter`][filter] (POST): create a new segmentation filter.
+///
+/// [filter]: https://dev.mailjet.com/email/reference/segmentation#v3_post_contactfilter
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateContactFilter;
+
+impl Endpoint for CreateContactFilter {
+    type Request = ContactFilterQuery;
+    type Response = ContactFilter;
+    type Payload = RestResponse<ContactFilter>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contactfilter")
+    }
+}
+
+/// Endpoint [`/contactfilter/{contactfilter_ID}`][filter] (PUT): update a segmentation filter.
+///
+/// [filter]: https://dev.mailjet.com/email/reference/segmentation#v3_put_contactfilter_contactfilter_ID
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateContactFilter(pub i64);
+
+impl Endpoint for UpdateContactFilter {
+    type Request = ContactFilterQuery;
+    type Response = ContactFilter;
+    type Payload = RestResponse<ContactFilter>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactfilter/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactfilter/{contactfilter_ID}`][filter] (DELETE): delete a segmentation filter.
+///
+/// [filter]: https://dev.mailjet.com/email/reference/segmentation#v3_delete_contactfilter_contactfilter_ID
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteContactFilter(pub i64);
+
+impl Endpoint for DeleteContactFilter {
+    type Request = NoParams;
+    type Response = ContactFilter;
+    type Payload = RestResponse<ContactFilter>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactfilter/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn contact_filter_endpoints() {
+        assert_eq!(ListContactFilters.path(), "v3/REST/contactfilter");
+        assert_eq!(CreateContactFilter.path(), "v3/REST/contactfilter");
+        assert_eq!(GetContactFilter(5).path(), "v3/REST/contactfilter/5");
+        assert_eq!(UpdateContactFilter(5).path(), "v3/REST/contactfilter/5");
+        assert_eq!(DeleteContactFilter(5).path(), "v3/REST/contactfilter/5");
+    }
+}
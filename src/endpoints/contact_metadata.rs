@@ -0,0 +1,122 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage the definitions of the contact properties.
+
+use crate::{
+    data_objects::{
+        ContactMetadata, ContactMetadataFilters, ContactMetadataQuery, NoParams, RestResponse,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/contactmetadata`][metadata] (GET): retrieve the list of contact properties.
+///
+/// [metadata]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactmetadata
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListContactMetadata;
+
+impl Endpoint for ListContactMetadata {
+    type Request = ContactMetadataFilters;
+    type Response = ContactMetadata;
+    type Payload = RestResponse<ContactMetadata>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contactmetadata")
+    }
+}
+
+/// Endpoint [`/contactmetadata/{contactmetadata_ID}`][metadata] (GET): retrieve a contact property.
+///
+/// [metadata]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_get_contactmetadata_contactmetadata_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetContactMetadata(pub i64);
+
+impl Endpoint for GetContactMetadata {
+    type Request = NoParams;
+    type Response = ContactMetadata;
+    type Payload = RestResponse<ContactMetadata>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactmetadata/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactmetadata`][metadata] (POST): declare a new contact property.
+///
+/// [metadata]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_post_contactmetadata
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateContactMetadata;
+
+impl Endpoint for CreateContactMetadata {
+    type Request = ContactMetadataQuery;
+    type Response = ContactMetadata;
+    type Payload = RestResponse<ContactMetadata>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contactmetadata")
+    }
+}
+
+/// Endpoint [`/contactmetadata/{contactmetadata_ID}`][metadata] (PUT): update a contact property.
+///
+/// [metadata]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_put_contactmetadata_contactmetadata_ID
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateContactMetadata(pub i64);
+
+impl Endpoint for UpdateContactMetadata {
+    type Request = ContactMetadataQuery;
+    type Response = ContactMetadata;
+    type Payload = RestResponse<ContactMetadata>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactmetadata/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactmetadata/{contactmetadata_ID}`][metadata] (DELETE): delete a contact property.
+///
+/// [metadata]: https://dev.mailjet.com/email/reference/contacts/contact-properties#v3_delete_contactmetadata_contactmetadata_ID
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteContactMetadata(pub i64);
+
+impl Endpoint for DeleteContactMetadata {
+    type Request = NoParams;
+    type Response = ContactMetadata;
+    type Payload = RestResponse<ContactMetadata>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactmetadata/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn contact_metadata_endpoints() {
+        assert_eq!(ListContactMetadata.path(), "v3/REST/contactmetadata");
+        assert_eq!(CreateContactMetadata.path(), "v3/REST/contactmetadata");
+        assert_eq!(GetContactMetadata(3).path(), "v3/REST/contactmetadata/3");
+        assert_eq!(UpdateContactMetadata(3).path(), "v3/REST/contactmetadata/3");
+        assert_eq!(DeleteContactMetadata(3).path(), "v3/REST/contactmetadata/3");
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage contact lists.
+
+use crate::{
+    data_objects::{ContactsList, ContactsListFilters, ContactsListQuery, NoParams, RestResponse},
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/contactslist`][list] (GET): retrieve a list of contact lists.
+///
+/// [list]: https://dev.mailjet.com/email/reference/contacts/contact-list#v3_get_contactslist
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListContactsLists;
+
+impl Endpoint for ListContactsLists {
+    type Request = ContactsListFilters;
+    type Response = ContactsList;
+    type Payload = RestResponse<ContactsList>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contactslist")
+    }
+}
+
+/// Endpoint [`/contactslist/{list_ID}`][list] (GET): retrieve a contact list by its ID.
+///
+/// [list]: https://dev.mailjet.com/email/reference/contacts/contact-list#v3_get_contactslist_list_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetContactsList(pub i64);
+
+impl Endpoint for GetContactsList {
+    type Request = NoParams;
+    type Response = ContactsList;
+    type Payload = RestResponse<ContactsList>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactslist/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactslist`][list] (POST): create a new contact list.
+///
+/// [list]: https://dev.mailjet.com/email/reference/contacts/contact-list#v3_post_contactslist
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateContactsList;
+
+impl Endpoint for CreateContactsList {
+    type Request = ContactsListQuery;
+    type Response = ContactsList;
+    type Payload = RestResponse<ContactsList>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contactslist")
+    }
+}
+
+/// Endpoint [`/contactslist/{list_ID}`][list] (PUT): rename, trash or restore a contact list.
+///
+/// [list]: https://dev.mailjet.com/email/reference/contacts/contact-list#v3_put_contactslist_list_ID
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateContactsList(pub i64);
+
+impl Endpoint for UpdateContactsList {
+    type Request = ContactsListQuery;
+    type Response = ContactsList;
+    type Payload = RestResponse<ContactsList>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactslist/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactslist/{list_ID}`][list] (DELETE): delete a contact list.
+///
+/// # Description
+///
+/// The external API doesn't include a payload in the response.
+///
+/// [list]: https://dev.mailjet.com/email/reference/contacts/contact-list#v3_delete_contactslist_list_ID
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteContactsList(pub i64);
+
+impl Endpoint for DeleteContactsList {
+    type Request = NoParams;
+    type Response = ContactsList;
+    type Payload = RestResponse<ContactsList>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactslist/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn contacts_list_endpoints() {
+        assert_eq!(ListContactsLists.path(), "v3/REST/contactslist");
+        assert_eq!(CreateContactsList.path(), "v3/REST/contactslist");
+        assert_eq!(GetContactsList(7).path(), "v3/REST/contactslist/7");
+        assert_eq!(UpdateContactsList(7).path(), "v3/REST/contactslist/7");
+        assert_eq!(DeleteContactsList(7).path(), "v3/REST/contactslist/7");
+    }
+}
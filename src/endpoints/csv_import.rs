@@ -0,0 +1,63 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to import contacts from CSV files.
+
+use crate::{
+    data_objects::{CsvImport, CsvImportQuery, NoParams, RestResponse},
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/csvimport`][import] (POST): start the import of a CSV file uploaded to the DATA API.
+///
+/// [import]: https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_post_csvimport
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateCsvImport;
+
+impl Endpoint for CreateCsvImport {
+    type Request = CsvImportQuery;
+    type Response = CsvImport;
+    type Payload = RestResponse<CsvImport>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("csvimport")
+    }
+}
+
+/// Endpoint [`/csvimport/{importjob_ID}`][import] (GET): retrieve the status of a CSV import.
+///
+/// [import]: https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_get_csvimport_importjob_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetCsvImport(pub i64);
+
+impl Endpoint for GetCsvImport {
+    type Request = NoParams;
+    type Response = CsvImport;
+    type Payload = RestResponse<CsvImport>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("csvimport/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn csv_import_endpoints() {
+        assert_eq!(CreateCsvImport.path(), "v3/REST/csvimport");
+        assert_eq!(GetCsvImport(7).path(), "v3/REST/csvimport/7");
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage the webhooks (event callback URLs) of an account.
+
+use crate::{
+    data_objects::{
+        EventCallbackUrl, EventCallbackUrlFilters, EventCallbackUrlQuery, NoParams, RestResponse,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/eventcallbackurl`][webhook] (GET): retrieve the list of webhooks.
+///
+/// [webhook]: https://dev.mailjet.com/email/reference/webhook#v3_get_eventcallbackurl
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListEventCallbackUrls;
+
+impl Endpoint for ListEventCallbackUrls {
+    type Request = EventCallbackUrlFilters;
+    type Response = EventCallbackUrl;
+    type Payload = RestResponse<EventCallbackUrl>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("eventcallbackurl")
+    }
+}
+
+/// Endpoint [`/eventcallbackurl/{url_ID}`][webhook] (GET): retrieve a webhook by its ID.
+///
+/// [webhook]: https://dev.mailjet.com/email/reference/webhook#v3_get_eventcallbackurl_url_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetEventCallbackUrl(pub i64);
+
+impl Endpoint for GetEventCallbackUrl {
+    type Request = NoParams;
+    type Response = EventCallbackUrl;
+    type Payload = RestResponse<EventCallbackUrl>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("eventcallbackurl/{}", self.0))
+    }
+}
+
+/// Endpoint [`/eventcallbackurl`][webhook] (POST): create a new webhook.
+///
+/// [webhook]: https://dev.mailjet.com/email/reference/webhook#v3_post_eventcallbackurl
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateEventCallbackUrl;
+
+impl Endpoint for CreateEventCallbackUrl {
+    type Request = EventCallbackUrlQuery;
+    type Response = EventCallbackUrl;
+    type Payload = RestResponse<EventCallbackUrl>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("eventcallbackurl")
+    }
+}
+
+/// Endpoint [`/eventcallbackurl/{url_ID}`][webhook] (PUT): update a webhook.
+///
+/// [webhook]: https://dev.mailjet.com/email/reference/webhook#v3_put_eventcallbackurl_url_ID
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateEventCallbackUrl(pub i64);
+
+impl Endpoint for UpdateEventCallbackUrl {
+    type Request = EventCallbackUrlQuery;
+    type Response = EventCallbackUrl;
+    type Payload = RestResponse<EventCallbackUrl>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("eventcallbackurl/{}", self.0))
+    }
+}
+
+/// Endpoint [`/eventcallbackurl/{url_ID}`][webhook] (DELETE): delete a webhook.
+///
+/// # Description
+///
+/// The external API doesn't include a payload in the response.
+///
+/// [webhook]: https://dev.mailjet.com/email/reference/webhook#v3_delete_eventcallbackurl_url_ID
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteEventCallbackUrl(pub i64);
+
+impl Endpoint for DeleteEventCallbackUrl {
+    type Request = NoParams;
+    type Response = EventCallbackUrl;
+    type Payload = RestResponse<EventCallbackUrl>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("eventcallbackurl/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn event_callback_url_endpoints() {
+        assert_eq!(ListEventCallbackUrls.path(), "v3/REST/eventcallbackurl");
+        assert_eq!(CreateEventCallbackUrl.path(), "v3/REST/eventcallbackurl");
+        assert_eq!(GetEventCallbackUrl(7).path(), "v3/REST/eventcallbackurl/7");
+        assert_eq!(
+            UpdateEventCallbackUrl(7).path(),
+            "v3/REST/eventcallbackurl/7"
+        );
+        assert_eq!(
+            DeleteEventCallbackUrl(7).path(),
+            "v3/REST/eventcallbackurl/7"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage the subscriptions of contacts to contact lists.
+
+use crate::{
+    data_objects::{
+        ListRecipient, ListRecipientFilters, ListRecipientQuery, ListRecipientUpdate, NoParams,
+        RestResponse,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/listrecipient`][recipient] (GET): retrieve a list of subscriptions.
+///
+/// [recipient]: https://dev.mailjet.com/email/reference/contacts/subscriptions#v3_get_listrecipient
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListListRecipients;
+
+impl Endpoint for ListListRecipients {
+    type Request = ListRecipientFilters;
+    type Response = ListRecipient;
+    type Payload = RestResponse<ListRecipient>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("listrecipient")
+    }
+}
+
+/// Endpoint [`/listrecipient/{listrecipient_ID}`][recipient] (GET): retrieve a subscription by its ID.
+///
+/// [recipient]: https://dev.mailjet.com/email/reference/contacts/subscriptions#v3_get_listrecipient_listrecipient_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetListRecipient(pub i64);
+
+impl Endpoint for GetListRecipient {
+    type Request = NoParams;
+    type Response = ListRecipient;
+    type Payload = RestResponse<ListRecipient>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("listrecipient/{}", self.0))
+    }
+}
+
+/// Endpoint [`/listrecipient`][recipient] (POST): subscribe a contact to a list.
+///
+/// [recipient]: https://dev.mailjet.com/email/reference/contacts/subscriptions#v3_post_listrecipient
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateListRecipient;
+
+impl Endpoint for CreateListRecipient {
+    type Request = ListRecipientQuery;
+    type Response = ListRecipient;
+    type Payload = RestResponse<ListRecipient>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("listrecipient")
+    }
+}
+
+/// Endpoint [`/listrecipient/{listrecipient_ID}`][recipient] (PUT): update the status of a subscription.
+///
+/// [recipient]: https://dev.mailjet.com/email/reference/contacts/subscriptions#v3_put_listrecipient_listrecipient_ID
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateListRecipient(pub i64);
+
+impl Endpoint for UpdateListRecipient {
+    type Request = ListRecipientUpdate;
+    type Response = ListRecipient;
+    type Payload = RestResponse<ListRecipient>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("listrecipient/{}", self.0))
+    }
+}
+
+/// Endpoint [`/listrecipient/{listrecipient_ID}`][recipient] (DELETE): remove a contact from a list.
+///
+/// # Description
+///
+/// The external API doesn't include a payload in the response.
+///
+/// [recipient]: https://dev.mailjet.com/email/reference/contacts/subscriptions#v3_delete_listrecipient_listrecipient_ID
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteListRecipient(pub i64);
+
+impl Endpoint for DeleteListRecipient {
+    type Request = NoParams;
+    type Response = ListRecipient;
+    type Payload = RestResponse<ListRecipient>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("listrecipient/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn list_recipient_endpoints() {
+        assert_eq!(ListListRecipients.path(), "v3/REST/listrecipient");
+        assert_eq!(CreateListRecipient.path(), "v3/REST/listrecipient");
+        assert_eq!(GetListRecipient(7).path(), "v3/REST/listrecipient/7");
+        assert_eq!(UpdateListRecipient(7).path(), "v3/REST/listrecipient/7");
+        assert_eq!(DeleteListRecipient(7).path(), "v3/REST/listrecipient/7");
+    }
+}
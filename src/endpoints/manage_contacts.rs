@@ -0,0 +1,128 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage contacts in bulk using asynchronous jobs.
+
+use crate::{
+    data_objects::{
+        ContactsJob, JobHandle, ManageContactsQuery, ManageListContactsQuery, NoParams,
+        RestResponse,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/contact/managemanycontacts`][bulk] (POST): manage many contacts and their lists at once.
+///
+/// [bulk]: https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_post_contact_managemanycontacts
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ManageManyContacts;
+
+impl Endpoint for ManageManyContacts {
+    type Request = ManageContactsQuery;
+    type Response = JobHandle;
+    type Payload = RestResponse<JobHandle>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("contact/managemanycontacts")
+    }
+}
+
+/// Endpoint [`/contact/managemanycontacts/{job_ID}`][bulk] (GET): retrieve the status of a job started by
+/// [ManageManyContacts].
+///
+/// [bulk]: https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_get_contact_managemanycontacts_job_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetManyContactsJob(pub i64);
+
+impl Endpoint for GetManyContactsJob {
+    type Request = NoParams;
+    type Response = ContactsJob;
+    type Payload = RestResponse<ContactsJob>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contact/managemanycontacts/{}", self.0))
+    }
+}
+
+/// Endpoint [`/contactslist/{list_ID}/managemanycontacts`][bulk] (POST): manage many contacts of a list at once.
+///
+/// [bulk]: https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_post_contactslist_list_ID_managemanycontacts
+#[derive(Debug, Clone, Copy)]
+pub struct ManageListContacts(pub i64);
+
+impl Endpoint for ManageListContacts {
+    type Request = ManageListContactsQuery;
+    type Response = JobHandle;
+    type Payload = RestResponse<JobHandle>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("contactslist/{}/managemanycontacts", self.0))
+    }
+}
+
+/// Endpoint [`/contactslist/{list_ID}/managemanycontacts/{job_ID}`][bulk] (GET): retrieve the status of a job
+/// started by [ManageListContacts].
+///
+/// [bulk]: https://dev.mailjet.com/email/reference/contacts/bulk-contact-management#v3_get_contactslist_list_ID_managemanycontacts_job_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetListContactsJob {
+    pub list_id: i64,
+    pub job_id: i64,
+}
+
+impl Endpoint for GetListContactsJob {
+    type Request = NoParams;
+    type Response = ContactsJob;
+    type Payload = RestResponse<ContactsJob>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!(
+            "contactslist/{}/managemanycontacts/{}",
+            self.list_id, self.job_id
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn manage_contacts_endpoints() {
+        assert_eq!(
+            ManageManyContacts.path(),
+            "v3/REST/contact/managemanycontacts"
+        );
+        assert_eq!(
+            GetManyContactsJob(3).path(),
+            "v3/REST/contact/managemanycontacts/3"
+        );
+        assert_eq!(
+            ManageListContacts(7).path(),
+            "v3/REST/contactslist/7/managemanycontacts"
+        );
+        assert_eq!(
+            GetListContactsJob {
+                list_id: 7,
+                job_id: 3
+            }
+            .path(),
+            "v3/REST/contactslist/7/managemanycontacts/3"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to retrieve the messages sent, and their delivery history.
+
+use crate::{
+    data_objects::{
+        MessageEvent, MessageFilters, MessageInformation, NoParams, RestResponse, SentMessage,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/message`][message] (GET): retrieve a list of messages.
+///
+/// [message]: https://dev.mailjet.com/email/reference/messages/message#v3_get_message
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListMessages;
+
+impl Endpoint for ListMessages {
+    type Request = MessageFilters;
+    type Response = SentMessage;
+    type Payload = RestResponse<SentMessage>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("message")
+    }
+}
+
+/// Endpoint [`/message/{message_ID}`][message] (GET): retrieve a message by its ID.
+///
+/// [message]: https://dev.mailjet.com/email/reference/messages/message#v3_get_message_message_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetMessage(pub i64);
+
+impl Endpoint for GetMessage {
+    type Request = NoParams;
+    type Response = SentMessage;
+    type Payload = RestResponse<SentMessage>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("message/{}", self.0))
+    }
+}
+
+/// Endpoint [`/messagehistory/{message_ID}`][history] (GET): retrieve the events of a message.
+///
+/// [history]: https://dev.mailjet.com/email/reference/messages/message#v3_get_messagehistory_message_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetMessageHistory(pub i64);
+
+impl Endpoint for GetMessageHistory {
+    type Request = NoParams;
+    type Response = MessageEvent;
+    type Payload = RestResponse<MessageEvent>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("messagehistory/{}", self.0))
+    }
+}
+
+/// Endpoint [`/messageinformation`][information] (GET): retrieve the sending information of a list of messages.
+///
+/// [information]: https://dev.mailjet.com/email/reference/messages/message#v3_get_messageinformation
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListMessageInformation;
+
+impl Endpoint for ListMessageInformation {
+    type Request = MessageFilters;
+    type Response = MessageInformation;
+    type Payload = RestResponse<MessageInformation>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("messageinformation")
+    }
+}
+
+/// Endpoint [`/messageinformation/{message_ID}`][information] (GET): retrieve the sending information of a message.
+///
+/// [information]: https://dev.mailjet.com/email/reference/messages/message#v3_get_messageinformation_message_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetMessageInformation(pub i64);
+
+impl Endpoint for GetMessageInformation {
+    type Request = NoParams;
+    type Response = MessageInformation;
+    type Payload = RestResponse<MessageInformation>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("messageinformation/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn message_endpoints() {
+        assert_eq!(ListMessages.path(), "v3/REST/message");
+        assert_eq!(GetMessage(7).path(), "v3/REST/message/7");
+        assert_eq!(GetMessageHistory(7).path(), "v3/REST/messagehistory/7");
+        assert_eq!(ListMessageInformation.path(), "v3/REST/messageinformation");
+        assert_eq!(
+            GetMessageInformation(7).path(),
+            "v3/REST/messageinformation/7"
+        );
+    }
+}
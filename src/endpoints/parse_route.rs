@@ -0,0 +1,105 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage the routes of the Parse API (inbound emails).
+
+use crate::{
+    data_objects::{NoParams, ParseRoute, ParseRouteFilters, ParseRouteQuery, RestResponse},
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/parseroute`][parse] (GET): retrieve the list of parse routes.
+///
+/// [parse]: https://dev.mailjet.com/email/reference/parse-api#v3_get_parseroute
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListParseRoutes;
+
+impl Endpoint for ListParseRoutes {
+    type Request = ParseRouteFilters;
+    type Response = ParseRoute;
+    type Payload = RestResponse<ParseRoute>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("parseroute")
+    }
+}
+
+/// Endpoint [`/parseroute/{parseroute_ID}`][parse] (GET): retrieve a parse route by its ID.
+///
+/// [parse]: https://dev.mailjet.com/email/reference/parse-api#v3_get_parseroute_parseroute_ID
+#[derive(Debug, Clone, Copy)]
+pub struct GetParseRoute(pub i64);
+
+impl Endpoint for GetParseRoute {
+    type Request = NoParams;
+    type Response = ParseRoute;
+    type Payload = RestResponse<ParseRoute>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("parseroute/{}", self.0))
+    }
+}
+
+/// Endpoint [`/parseroute`][parse] (POST): create a new parse route.
+///
+/// [parse]: https://dev.mailjet.com/email/reference/parse-api#v3_post_parseroute
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateParseRoute;
+
+impl Endpoint for CreateParseRoute {
+    type Request = ParseRouteQuery;
+    type Response = ParseRoute;
+    type Payload = RestResponse<ParseRoute>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("parseroute")
+    }
+}
+
+/// Endpoint [`/parseroute/{parseroute_ID}`][parse] (DELETE): delete a parse route.
+///
+/// # Description
+///
+/// The external API doesn't include a payload in the response.
+///
+/// [parse]: https://dev.mailjet.com/email/reference/parse-api#v3_delete_parseroute_parseroute_ID
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteParseRoute(pub i64);
+
+impl Endpoint for DeleteParseRoute {
+    type Request = NoParams;
+    type Response = ParseRoute;
+    type Payload = RestResponse<ParseRoute>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("parseroute/{}", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn parse_route_endpoints() {
+        assert_eq!(ListParseRoutes.path(), "v3/REST/parseroute");
+        assert_eq!(CreateParseRoute.path(), "v3/REST/parseroute");
+        assert_eq!(GetParseRoute(7).path(), "v3/REST/parseroute/7");
+        assert_eq!(DeleteParseRoute(7).path(), "v3/REST/parseroute/7");
+    }
+}
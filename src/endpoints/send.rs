@@ -0,0 +1,181 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to send emails.
+
+use crate::{
+    data_objects::{
+        check_attachments_size, MessageObject, RequestObject, SendEmailParams, SendResponseObject,
+        SendV3Response, SendV3_1Response, SimpleMessage, Validate,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion, ClientError, RateLimitBucket,
+};
+use reqwest::Method;
+
+/// Endpoint [`/send`][send] of the API v3.
+///
+/// [send]: https://dev.mailjet.com/email/reference/send-emails#v3_post_send
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SendV3;
+
+impl Endpoint for SendV3 {
+    type Request = SimpleMessage;
+    type Response = MessageObject;
+    type Payload = SendV3Response;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::send(&Self::API_VERSION)
+    }
+
+    fn rate_limit_bucket(&self) -> RateLimitBucket {
+        RateLimitBucket::Send
+    }
+}
+
+/// Endpoint [`/send`][send] of the API v3.1.
+///
+/// [send]: https://dev.mailjet.com/email/reference/send-emails#v3_1_post_send
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SendV3_1;
+
+impl Endpoint for SendV3_1 {
+    type Request = SendEmailParams;
+    type Response = SendResponseObject;
+    type Payload = SendV3_1Response;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3_1;
+
+    fn path(&self) -> String {
+        ApiUrl::send(&Self::API_VERSION)
+    }
+
+    fn rate_limit_bucket(&self) -> RateLimitBucket {
+        RateLimitBucket::Send
+    }
+}
+
+/// Trait that identifies the request objects accepted by [crate::MailjetClient::send_email].
+///
+/// # Description
+///
+/// Each request object is linked to the version of the `/send` endpoint that accepts it, so the client selects the
+/// endpoint based on the type of the message rather than on a runtime check.
+pub trait SendRequest: RequestObject + Validate + Clone {
+    /// Endpoint that accepts this request object.
+    type Endpoint: Endpoint<Request = Self> + Default;
+
+    /// Apply the client's global _sandbox mode_ to the request.
+    ///
+    /// # Description
+    ///
+    /// Only the API >= v3.1 supports the _sandbox mode_, so the default implementation does nothing.
+    fn apply_sandbox_mode(&mut self, _sandbox_mode: bool) {}
+
+    /// Check that the attachments of the message(s) don't exceed [crate::data_objects::MAX_MESSAGE_SIZE].
+    fn check_size(&self) -> Result<(), ClientError>;
+}
+
+impl SendRequest for SimpleMessage {
+    type Endpoint = SendV3;
+
+    fn check_size(&self) -> Result<(), ClientError> {
+        check_attachments_size([&self.attachments, &self.inline_attachments])
+    }
+}
+
+impl SendRequest for SendEmailParams {
+    type Endpoint = SendV3_1;
+
+    /// The value given in the request is honored when the global _sandbox mode_ is disabled.
+    fn apply_sandbox_mode(&mut self, sandbox_mode: bool) {
+        if !self.sandbox_mode.unwrap_or_default() {
+            self.sandbox_mode = Some(sandbox_mode);
+        }
+    }
+
+    /// The limit applies to each message, including the attachments given in the globals.
+    fn check_size(&self) -> Result<(), ClientError> {
+        let globals = self.globals.as_ref();
+
+        self.messages.iter().try_for_each(|m| {
+            check_attachments_size([
+                &m.attachments,
+                &m.inline_attachments,
+                globals.map_or(&None, |g| &g.attachments),
+                globals.map_or(&None, |g| &g.inline_attachments),
+            ])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_objects::{Attachment, MessageBuilder, MessageProperty, MAX_MESSAGE_SIZE};
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn send_endpoints() {
+        assert_eq!(SendV3.path(), "v3/send");
+        assert_eq!(SendV3::METHOD, Method::POST);
+        assert_eq!(SendV3::API_VERSION, ApiVersion::V3);
+
+        assert_eq!(SendV3_1.path(), "v3.1/send");
+        assert_eq!(SendV3_1::METHOD, Method::POST);
+        assert_eq!(SendV3_1::API_VERSION, ApiVersion::V3_1);
+        assert_eq!(SendV3_1.rate_limit_bucket(), RateLimitBucket::Send);
+    }
+
+    #[rstest]
+    #[case(None, false, Some(false))]
+    #[case(None, true, Some(true))]
+    #[case(Some(false), true, Some(true))]
+    #[case(Some(true), false, Some(true))]
+    fn sandbox_mode_is_applied(
+        #[case] local: Option<bool>,
+        #[case] global: bool,
+        #[case] expected: Option<bool>,
+    ) {
+        let mut request = SendEmailParams {
+            sandbox_mode: local,
+            advance_error_handling: None,
+            globals: None,
+            messages: Vec::new(),
+        };
+
+        request.apply_sandbox_mode(global);
+        assert_eq!(request.sandbox_mode, expected);
+    }
+
+    #[rstest]
+    fn globals_count_for_the_size_limit() {
+        let big = Attachment::from_bytes("big.bin", vec![0u8; MAX_MESSAGE_SIZE / 2]);
+        let mut request = SendEmailParams {
+            sandbox_mode: None,
+            advance_error_handling: None,
+            globals: None,
+            messages: vec![MessageBuilder::default()
+                .with_from("pilot@mailjet.com", None)
+                .with_attachment(big.clone())
+                .build()
+                .expect("Failed to build the message")],
+        };
+        assert!(request.check_size().is_ok());
+
+        request.globals = Some(MessageProperty {
+            attachments: Some(vec![big]),
+            ..Default::default()
+        });
+        assert!(matches!(
+            request.check_size(),
+            Err(ClientError::MessageTooLarge { .. })
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage sender addresses.
+
+use crate::{
+    data_objects::{
+        NoParams, ResourceId, RestResponse, Sender, SenderCreate, SenderQuery, SenderValidation,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/sender`][sender] (GET): retrieve a list of senders.
+///
+/// [sender]: https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender#v3_get_sender
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListSenders;
+
+impl Endpoint for ListSenders {
+    type Request = SenderQuery;
+    type Response = Sender;
+    type Payload = RestResponse<Sender>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("sender")
+    }
+}
+
+/// Endpoint [`/sender/{sender_ID}`][sender] (GET): retrieve a sender by its ID or email address.
+///
+/// [sender]: https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender#v3_get_sender_sender_ID
+#[derive(Debug, Clone)]
+pub struct GetSender(pub ResourceId);
+
+impl Endpoint for GetSender {
+    type Request = NoParams;
+    type Response = Sender;
+    type Payload = RestResponse<Sender>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("sender/{}", self.0))
+    }
+}
+
+/// Endpoint [`/sender`][sender] (POST): create a new sender.
+///
+/// [sender]: https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender#v3_post_sender
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateSender;
+
+impl Endpoint for CreateSender {
+    type Request = SenderCreate;
+    type Response = Sender;
+    type Payload = RestResponse<Sender>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("sender")
+    }
+}
+
+/// Endpoint [`/sender/{sender_ID}`][sender] (DELETE): delete a sender.
+///
+/// # Description
+///
+/// The external API doesn't include a payload in the response.
+///
+/// [sender]: https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender#v3_delete_sender_sender_ID
+#[derive(Debug, Clone)]
+pub struct DeleteSender(pub ResourceId);
+
+impl Endpoint for DeleteSender {
+    type Request = NoParams;
+    type Response = Sender;
+    type Payload = RestResponse<Sender>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("sender/{}", self.0))
+    }
+}
+
+/// Endpoint [`/sender/{sender_ID}/validate`][sender] (POST): validate a sender.
+///
+/// [sender]: https://dev.mailjet.com/email/reference/sender-addresses-and-domains/sender#v3_post_sender_sender_ID_validate
+#[derive(Debug, Clone)]
+pub struct ValidateSender(pub ResourceId);
+
+impl Endpoint for ValidateSender {
+    type Request = NoParams;
+    type Response = SenderValidation;
+    type Payload = RestResponse<SenderValidation>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("sender/{}/validate", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn sender_endpoints() {
+        assert_eq!(ListSenders.path(), "v3/REST/sender");
+        assert_eq!(CreateSender.path(), "v3/REST/sender");
+        assert_eq!(GetSender(7.into()).path(), "v3/REST/sender/7");
+        assert_eq!(DeleteSender(7.into()).path(), "v3/REST/sender/7");
+        assert_eq!(ValidateSender(7.into()).path(), "v3/REST/sender/7/validate");
+    }
+}
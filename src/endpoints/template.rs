@@ -0,0 +1,175 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Endpoints to manage templates.
+
+use crate::{
+    data_objects::{
+        NoParams, ResourceId, RestResponse, Template, TemplateContent, TemplateFilters,
+        TemplateQuery,
+    },
+    endpoints::Endpoint,
+    mailjet_api::ApiUrl,
+    ApiVersion,
+};
+use reqwest::Method;
+
+/// Endpoint [`/template`][template] (GET): retrieve a list of templates.
+///
+/// [template]: https://dev.mailjet.com/email/reference/templates#v3_get_template
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ListTemplates;
+
+impl Endpoint for ListTemplates {
+    type Request = TemplateFilters;
+    type Response = Template;
+    type Payload = RestResponse<Template>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("template")
+    }
+}
+
+/// Endpoint [`/template/{template_ID}`][template] (GET): retrieve a template by its ID or `apikey|name`.
+///
+/// [template]: https://dev.mailjet.com/email/reference/templates#v3_get_template_template_ID
+#[derive(Debug, Clone)]
+pub struct GetTemplate(pub ResourceId);
+
+impl Endpoint for GetTemplate {
+    type Request = NoParams;
+    type Response = Template;
+    type Payload = RestResponse<Template>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("template/{}", self.0))
+    }
+}
+
+/// Endpoint [`/template`][template] (POST): create a new template.
+///
+/// [template]: https://dev.mailjet.com/email/reference/templates#v3_post_template
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CreateTemplate;
+
+impl Endpoint for CreateTemplate {
+    type Request = TemplateQuery;
+    type Response = Template;
+    type Payload = RestResponse<Template>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest("template")
+    }
+}
+
+/// Endpoint [`/template/{template_ID}`][template] (PUT): update the attributes of a template.
+///
+/// [template]: https://dev.mailjet.com/email/reference/templates#v3_put_template_template_ID
+#[derive(Debug, Clone)]
+pub struct UpdateTemplate(pub ResourceId);
+
+impl Endpoint for UpdateTemplate {
+    type Request = TemplateQuery;
+    type Response = Template;
+    type Payload = RestResponse<Template>;
+    const METHOD: Method = Method::PUT;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("template/{}", self.0))
+    }
+}
+
+/// Endpoint [`/template/{template_ID}`][template] (DELETE): delete a template.
+///
+/// # Description
+///
+/// The external API doesn't include a payload in the response.
+///
+/// [template]: https://dev.mailjet.com/email/reference/templates#v3_delete_template_template_ID
+#[derive(Debug, Clone)]
+pub struct DeleteTemplate(pub ResourceId);
+
+impl Endpoint for DeleteTemplate {
+    type Request = NoParams;
+    type Response = Template;
+    type Payload = RestResponse<Template>;
+    const METHOD: Method = Method::DELETE;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("template/{}", self.0))
+    }
+}
+
+/// Endpoint [`/template/{template_ID}/detailcontent`][template] (GET): retrieve the content of a template.
+///
+/// [template]: https://dev.mailjet.com/email/reference/templates#v3_get_template_template_ID_detailcontent
+#[derive(Debug, Clone)]
+pub struct GetTemplateContent(pub ResourceId);
+
+impl Endpoint for GetTemplateContent {
+    type Request = NoParams;
+    type Response = TemplateContent;
+    type Payload = RestResponse<TemplateContent>;
+    const METHOD: Method = Method::GET;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("template/{}/detailcontent", self.0))
+    }
+}
+
+/// Endpoint [`/template/{template_ID}/detailcontent`][template] (POST): set the content of a template.
+///
+/// [template]: https://dev.mailjet.com/email/reference/templates#v3_post_template_template_ID_detailcontent
+#[derive(Debug, Clone)]
+pub struct SetTemplateContent(pub ResourceId);
+
+impl Endpoint for SetTemplateContent {
+    type Request = TemplateContent;
+    type Response = TemplateContent;
+    type Payload = RestResponse<TemplateContent>;
+    const METHOD: Method = Method::POST;
+    const API_VERSION: ApiVersion = ApiVersion::V3;
+
+    fn path(&self) -> String {
+        ApiUrl::rest(&format!("template/{}/detailcontent", self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn template_endpoints() {
+        assert_eq!(ListTemplates.path(), "v3/REST/template");
+        assert_eq!(CreateTemplate.path(), "v3/REST/template");
+        assert_eq!(GetTemplate(7.into()).path(), "v3/REST/template/7");
+        assert_eq!(
+            GetTemplate("apikey|welcome".into()).path(),
+            "v3/REST/template/apikey|welcome"
+        );
+        assert_eq!(UpdateTemplate(7.into()).path(), "v3/REST/template/7");
+        assert_eq!(DeleteTemplate(7.into()).path(), "v3/REST/template/7");
+        assert_eq!(
+            GetTemplateContent(7.into()).path(),
+            "v3/REST/template/7/detailcontent"
+        );
+        assert_eq!(
+            SetTemplateContent(7.into()).path(),
+            "v3/REST/template/7/detailcontent"
+        );
+    }
+}
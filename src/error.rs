@@ -5,23 +5,326 @@
 
 //! Custom error types.
 
+use crate::data_objects::{ResponseError, ValidationErrors};
+use core::fmt;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use serde::Deserialize;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
+/// Name of the header that Mailjet uses to identify a request.
+pub(crate) const REQUEST_ID_HEADER: &str = "X-MJ-Request-GUID";
+
 /// Error types returned by the client.
-#[derive(Error, Debug, PartialEq, Eq)]
+///
+/// # Description
+///
+/// Errors returned by the external API are classified by the status code of the response. All of them carry an
+/// [ApiErrorDetails] object with the information that Mailjet included in the response. Errors that are detected
+/// before reaching the external API, or while reading the response, wrap the original error, which is available
+/// using [std::error::Error::source].
+#[derive(Error, Debug)]
 pub enum ClientError {
     #[error("An error was found in the internal HTTP Client")]
-    HTTPClient,
+    HTTPClient(#[source] reqwest::Error),
     #[error("Wrong API version specified")]
     WrongApiVersion,
     #[error("Missing Mailjet API key token")]
     MissingApiKey,
     #[error("Unknown error")]
     UnknownError(String),
-    #[error("External error")]
-    ExternalError(String),
-    #[error("The given data is formatted wrongly")]
-    BadRequest(String),
+    #[error("The given data is formatted wrongly ({0})")]
+    BadRequest(Box<ApiErrorDetails>),
+    #[error("Authentication failed ({0})")]
+    Unauthorized(Box<ApiErrorDetails>),
+    #[error("Access to the resource is forbidden ({0})")]
+    Forbidden(Box<ApiErrorDetails>),
+    #[error("The requested resource was not found ({0})")]
+    NotFound(Box<ApiErrorDetails>),
+    #[error("Too many requests sent to the external API ({details})")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        details: Box<ApiErrorDetails>,
+    },
+    #[error("The external API failed to process the request ({0})")]
+    ServerError(Box<ApiErrorDetails>),
+    #[error("Unexpected response from the external API ({0})")]
+    UnexpectedStatus(Box<ApiErrorDetails>),
+    #[error("Failed to communicate with the external API")]
+    Transport(#[source] reqwest_middleware::Error),
+    #[error("The request to the external API timed out")]
+    Timeout(#[source] reqwest_middleware::Error),
+    #[error("Failed to deserialize the response (status code {status_code})")]
+    Deserialization {
+        status_code: u16,
+        request_id: Option<String>,
+        #[source]
+        source: serde_json::Error,
+    },
     #[error("Error found while parsing data")]
     ParseError(String),
+    #[error("The query matches more than {0} objects")]
+    TooManyItems(usize),
+    #[error("Failed to read or write local data")]
+    Io(#[source] std::io::Error),
+    #[error("The request failed the local validation ({0})")]
+    Validation(ValidationErrors),
+    #[error("The attachments of the message exceed the maximum size ({size} > {limit} bytes)")]
+    MessageTooLarge { size: usize, limit: usize },
+    #[error("The asynchronous job didn't finish in {0:?}")]
+    JobTimeout(Duration),
+    #[error("The asynchronous job has an unknown status ({0})")]
+    UnknownJobStatus(String),
+}
+
+/// Information about an error response returned by the external API.
+///
+/// # Description
+///
+/// Endpoints of the API v3.1 describe errors using a list of [ResponseError], whilst endpoints of the API v3 include
+/// a brief `ErrorInfo` and `ErrorMessage`. Both are parsed when present in the body of the response. The raw body is
+/// kept as well, just in case it doesn't follow any of the known layouts.
+#[derive(Debug, Clone, Default)]
+pub struct ApiErrorDetails {
+    /// Status code of the response.
+    pub status_code: u16,
+    /// Identifier assigned by Mailjet to the request (`X-MJ-Request-GUID`).
+    pub request_id: Option<String>,
+    /// Errors reported by endpoints of the API v3.1.
+    pub errors: Vec<ResponseError>,
+    /// `ErrorInfo` reported by endpoints of the API v3.
+    pub error_info: Option<String>,
+    /// `ErrorMessage` reported by endpoints of the API v3.
+    pub error_message: Option<String>,
+    /// Raw body of the response.
+    pub payload: String,
+}
+
+impl ApiErrorDetails {
+    /// Build a new object from the parts of a response.
+    pub(crate) fn new(status_code: u16, headers: &HeaderMap, payload: String) -> Self {
+        let request_id = headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        // Temporal struct that accepts any of the error layouts used by the external API.
+        #[derive(Deserialize, Default)]
+        #[serde(rename_all = "PascalCase")]
+        struct ErrorBody {
+            error_info: Option<String>,
+            error_message: Option<String>,
+            error_identifier: Option<String>,
+            error_code: Option<String>,
+            errors: Option<Vec<ResponseError>>,
+            messages: Option<Vec<MessageErrors>>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct MessageErrors {
+            errors: Option<Vec<ResponseError>>,
+        }
+
+        let body: ErrorBody = serde_json::from_str(&payload).unwrap_or_default();
+
+        let mut errors = body.errors.unwrap_or_default();
+        errors.extend(
+            body.messages
+                .unwrap_or_default()
+                .into_iter()
+                .flat_map(|m| m.errors.unwrap_or_default()),
+        );
+
+        // Errors not linked to a message are returned as a top level object by the API v3.1.
+        if let (Some(identifier), Some(code)) = (body.error_identifier, body.error_code) {
+            errors.push(ResponseError {
+                error_identifier: identifier,
+                error_code: code,
+                status_code: status_code as i64,
+                error_message: body.error_message.clone().unwrap_or_default(),
+                error_related_to: Vec::new(),
+            });
+        }
+
+        ApiErrorDetails {
+            status_code,
+            request_id,
+            errors,
+            error_info: body.error_info.filter(|i| !i.is_empty()),
+            error_message: body.error_message,
+            payload,
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status code {}", self.status_code)?;
+
+        if let Some(id) = &self.request_id {
+            write!(f, ", request ID {id}")?;
+        }
+
+        if let Some(message) = self.errors.first().map(|e| &e.error_message) {
+            write!(f, ": {message}")
+        } else if let Some(message) = &self.error_message {
+            write!(f, ": {message}")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ClientError {
+    /// Classify an error response of the external API by its status code.
+    pub(crate) fn from_response(status_code: u16, headers: &HeaderMap, payload: String) -> Self {
+        let details = Box::new(ApiErrorDetails::new(status_code, headers, payload));
+
+        match status_code {
+            400 => ClientError::BadRequest(details),
+            401 => ClientError::Unauthorized(details),
+            403 => ClientError::Forbidden(details),
+            404 => ClientError::NotFound(details),
+            429 => ClientError::RateLimited {
+                retry_after: parse_retry_after(headers),
+                details,
+            },
+            500..=599 => ClientError::ServerError(details),
+            _ => ClientError::UnexpectedStatus(details),
+        }
+    }
+
+    /// Classify an error found while sending a request or receiving its response.
+    pub(crate) fn from_transport(error: reqwest_middleware::Error) -> Self {
+        if error.is_timeout() {
+            ClientError::Timeout(error)
+        } else {
+            ClientError::Transport(error)
+        }
+    }
+
+    /// Details of the error response returned by the external API, if any.
+    pub fn details(&self) -> Option<&ApiErrorDetails> {
+        match self {
+            ClientError::BadRequest(d)
+            | ClientError::Unauthorized(d)
+            | ClientError::Forbidden(d)
+            | ClientError::NotFound(d)
+            | ClientError::RateLimited { details: d, .. }
+            | ClientError::ServerError(d)
+            | ClientError::UnexpectedStatus(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// Status code of the response that caused the error, if any.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            ClientError::Deserialization { status_code, .. } => Some(*status_code),
+            _ => self.details().map(|d| d.status_code),
+        }
+    }
+
+    /// Identifier assigned by Mailjet to the request that caused the error, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ClientError::Deserialization { request_id, .. } => request_id.as_deref(),
+            _ => self.details().and_then(|d| d.request_id.as_deref()),
+        }
+    }
+}
+
+/// Parse the value of the header `Retry-After`, which might be given in seconds or as an HTTP date.
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    httpdate::parse_http_date(value)
+        .ok()
+        .map(|date| date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use reqwest::header::HeaderValue;
+    use rstest::*;
+
+    #[rstest]
+    #[case(400, "BadRequest")]
+    #[case(401, "Unauthorized")]
+    #[case(403, "Forbidden")]
+    #[case(404, "NotFound")]
+    #[case(429, "RateLimited")]
+    #[case(500, "ServerError")]
+    #[case(503, "ServerError")]
+    #[case(418, "UnexpectedStatus")]
+    fn errors_are_classified(#[case] status_code: u16, #[case] expected: &str) {
+        let error = ClientError::from_response(status_code, &HeaderMap::new(), String::new());
+
+        assert!(format!("{error:?}").starts_with(expected));
+        assert_eq!(error.status_code(), Some(status_code));
+    }
+
+    #[rstest]
+    fn v3_1_errors_are_parsed() {
+        let payload = r#"{"Messages":[{"Status":"error","Errors":[{"ErrorIdentifier":"f987008f",
+            "ErrorCode":"send-0008","StatusCode":400,"ErrorMessage":"\"From\" is missing.",
+            "ErrorRelatedTo":["From"]}]}]}"#;
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("abcd-1234"));
+
+        let error = ClientError::from_response(400, &headers, payload.into());
+
+        assert_eq!(error.request_id(), Some("abcd-1234"));
+        let details = error.details().unwrap();
+        assert_eq!(details.errors.len(), 1);
+        assert_eq!(details.errors[0].error_code, "send-0008");
+        assert_eq!(details.errors[0].error_related_to, vec!["From".to_string()]);
+    }
+
+    #[rstest]
+    fn v3_1_top_level_errors_are_parsed() {
+        let payload = r#"{"ErrorIdentifier":"ab12","ErrorCode":"mj-0002","StatusCode":401,
+            "ErrorMessage":"API key authentication/authorization failure."}"#;
+
+        let error = ClientError::from_response(401, &HeaderMap::new(), payload.into());
+
+        let details = error.details().unwrap();
+        assert_eq!(details.errors.len(), 1);
+        assert_eq!(details.errors[0].error_code, "mj-0002");
+        assert!(error.to_string().contains("authorization failure"));
+    }
+
+    #[rstest]
+    fn v3_errors_are_parsed() {
+        let payload = r#"{"ErrorInfo":"","ErrorMessage":"Unknown resource","StatusCode":404}"#;
+
+        let error = ClientError::from_response(404, &HeaderMap::new(), payload.into());
+
+        let details = error.details().unwrap();
+        assert!(details.errors.is_empty());
+        assert_eq!(details.error_info, None);
+        assert_eq!(details.error_message.as_deref(), Some("Unknown resource"));
+        assert_eq!(details.payload, payload);
+    }
+
+    #[rstest]
+    #[case("120", Some(Duration::from_secs(120)))]
+    #[case("Wed, 21 Oct 2015 07:28:00 GMT", Some(Duration::ZERO))]
+    #[case("soon", None)]
+    fn retry_after_is_parsed(#[case] value: &str, #[case] expected: Option<Duration>) {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+
+        match ClientError::from_response(429, &headers, String::new()) {
+            ClientError::RateLimited { retry_after, .. } => assert_eq!(retry_after, expected),
+            e => panic!("Unexpected error: {e:?}"),
+        }
+    }
 }
@@ -0,0 +1,297 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Types for the emails that Mailjet forwards using the Parse API.
+//!
+//! # Description
+//!
+//! Mailjet forwards the emails received at the address of a parse route (see
+//! [crate::MailjetClient::create_parse_route]) issuing a POST request to the URL of the route. The body of the request
+//! is a JSON object that deserializes into an [InboundEmail].
+//! See [Parse API](https://dev.mailjet.com/email/guides/parse-api/).
+//!
+//! ## Example
+//!
+//! ```
+//! use mailjet_client::inbound::InboundEmail;
+//!
+//! let body = r#"{
+//!     "Sender": "passenger@mailjet.com",
+//!     "Recipient": "pilot@parse-in1.mailjet.com",
+//!     "From": "Passenger <passenger@mailjet.com>",
+//!     "Subject": "Re: Your email flight plan!",
+//!     "Headers": {"Subject": "Re: Your email flight plan!"},
+//!     "Parts": [{"Headers": {"Content-Type": "text/plain; charset=UTF-8"}, "ContentRef": "Text-part"}],
+//!     "Text-part": "Thanks!",
+//!     "SpamAssassinScore": "0.602"
+//! }"#;
+//!
+//! let email: InboundEmail = serde_json::from_str(body).expect("Failed to parse the email");
+//!
+//! assert_eq!(email.text_part.as_deref(), Some("Thanks!"));
+//! assert_eq!(email.spam_assassin_score, Some(0.602));
+//! ```
+
+use crate::data_objects::Attachment;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Prefix of the fields of the payload that include the content of the attachments.
+const ATTACHMENT_PREFIX: &str = "Attachment";
+
+/// A part of the MIME structure of an inbound email.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct EmailPart {
+    #[serde(deserialize_with = "deserialize_headers")]
+    pub headers: HashMap<String, Vec<String>>,
+    /// Field of the payload that includes the content of the part: `Text-part`, `Html-part` or `AttachmentN`.
+    pub content_ref: String,
+}
+
+impl EmailPart {
+    /// Get the first value of a header, ignoring the case of its name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// An email forwarded by Mailjet to the URL of a parse route.
+///
+/// # Description
+///
+/// The headers are kept as lists of values, as a header might appear several times in an email (e.g.
+/// `Received`). The attachments (fields `Attachment1`..`AttachmentN` of the payload) are collected into
+/// [InboundEmail::attachments], taking their name and content type from the matching [EmailPart]. Their content
+/// is kept base64 encoded, use [Attachment::content] to decode it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InboundEmail {
+    /// Address that sent the email (envelope sender).
+    pub sender: String,
+    /// Address of the parse route that received the email.
+    pub recipient: String,
+    pub date: Option<String>,
+    /// Value of the `From` header.
+    pub from: String,
+    pub subject: String,
+    pub headers: HashMap<String, Vec<String>>,
+    pub parts: Vec<EmailPart>,
+    pub text_part: Option<String>,
+    pub html_part: Option<String>,
+    pub spam_assassin_score: Option<f64>,
+    pub custom_id: Option<String>,
+    pub payload: Option<String>,
+    pub attachments: Vec<Attachment>,
+}
+
+impl InboundEmail {
+    /// Get the first value of a header, ignoring the case of its name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+impl<'de> Deserialize<'de> for InboundEmail {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Payload {
+            #[serde(default)]
+            sender: String,
+            #[serde(default)]
+            recipient: String,
+            date: Option<String>,
+            #[serde(default)]
+            from: String,
+            #[serde(default)]
+            subject: String,
+            #[serde(default, deserialize_with = "deserialize_headers")]
+            headers: HashMap<String, Vec<String>>,
+            #[serde(default)]
+            parts: Vec<EmailPart>,
+            #[serde(rename = "Text-part")]
+            text_part: Option<String>,
+            #[serde(rename = "Html-part")]
+            html_part: Option<String>,
+            #[serde(default, deserialize_with = "deserialize_score")]
+            spam_assassin_score: Option<f64>,
+            #[serde(rename = "CustomID")]
+            custom_id: Option<String>,
+            payload: Option<String>,
+            #[serde(flatten)]
+            other: HashMap<String, Value>,
+        }
+
+        let payload = Payload::deserialize(deserializer)?;
+
+        let mut attachments: Vec<_> = payload
+            .other
+            .iter()
+            .filter_map(|(key, value)| {
+                let index: u32 = key.strip_prefix(ATTACHMENT_PREFIX)?.parse().ok()?;
+                let content = value.as_str()?;
+
+                Some((index, attachment(key, content, &payload.parts)))
+            })
+            .collect();
+        attachments.sort_by_key(|(index, _)| *index);
+
+        Ok(InboundEmail {
+            sender: payload.sender,
+            recipient: payload.recipient,
+            date: payload.date,
+            from: payload.from,
+            subject: payload.subject,
+            headers: payload.headers,
+            parts: payload.parts,
+            text_part: payload.text_part,
+            html_part: payload.html_part,
+            spam_assassin_score: payload.spam_assassin_score,
+            custom_id: payload.custom_id,
+            payload: payload.payload,
+            attachments: attachments.into_iter().map(|(_, a)| a).collect(),
+        })
+    }
+}
+
+/// Build the attachment stored in the field `key` of the payload, using the headers of its part.
+fn attachment(key: &str, content: &str, parts: &[EmailPart]) -> Attachment {
+    let part = parts.iter().find(|p| p.content_ref == key);
+    let header = |name| part.and_then(|p| p.header(name));
+
+    let content_type = header("Content-Type").unwrap_or("application/octet-stream");
+    let filename = header("Content-Disposition")
+        .and_then(|h| header_param(h, "filename"))
+        .or_else(|| header_param(content_type, "name"))
+        .unwrap_or(key);
+
+    Attachment {
+        filename: filename.to_string(),
+        content_type: content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+        base_64_content: content.to_string(),
+        content_id: header("Content-ID")
+            .map(|id| id.trim_matches(|c| c == '<' || c == '>').to_string()),
+    }
+}
+
+/// Get the value of a parameter of a header, e.g. `filename` from `attachment; filename="plan.pdf"`.
+fn header_param<'a>(header: &'a str, name: &str) -> Option<&'a str> {
+    header.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then(|| value.trim().trim_matches('"'))
+    })
+}
+
+fn find_header<'a>(headers: &'a HashMap<String, Vec<String>>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .and_then(|(_, values)| values.first())
+        .map(String::as_str)
+}
+
+/// Headers are sent as a string, or as a list of strings when the header appears several times.
+fn deserialize_headers<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<String, Vec<String>>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum HeaderValue {
+        Single(String),
+        Multiple(Vec<String>),
+    }
+
+    let headers = HashMap::<String, HeaderValue>::deserialize(deserializer)?;
+
+    Ok(headers
+        .into_iter()
+        .map(|(key, value)| match value {
+            HeaderValue::Single(value) => (key, vec![value]),
+            HeaderValue::Multiple(values) => (key, values),
+        })
+        .collect())
+}
+
+/// The score is sent as a string, though a number is accepted too.
+fn deserialize_score<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Number(n)) => n.as_f64(),
+        Some(Value::String(s)) => s.trim().parse().ok(),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn inbound_email_with_attachments() {
+        let body = r#"{
+            "Sender": "passenger@mailjet.com",
+            "Recipient": "pilot@parse-in1.mailjet.com",
+            "Date": "20240920T100000",
+            "From": "Passenger <passenger@mailjet.com>",
+            "Subject": "Re: Your email flight plan!",
+            "Headers": {
+                "Received": ["from a.mailjet.com", "from b.mailjet.com"],
+                "Subject": "Re: Your email flight plan!"
+            },
+            "Parts": [
+                {"Headers": {"Content-Type": "text/plain; charset=UTF-8"}, "ContentRef": "Text-part"},
+                {"Headers": {"Content-Type": "text/html; charset=UTF-8"}, "ContentRef": "Html-part"},
+                {"Headers": {"Content-Type": "application/pdf; name=\"plan.pdf\"",
+                             "Content-Disposition": "attachment; filename=\"flight plan.pdf\""},
+                 "ContentRef": "Attachment2"},
+                {"Headers": {"Content-Type": "image/png", "Content-ID": "<logo>"}, "ContentRef": "Attachment1"}
+            ],
+            "Text-part": "Thanks!",
+            "Html-part": "<p>Thanks!</p>",
+            "SpamAssassinScore": "0.602",
+            "Attachment2": "JVBERi0=",
+            "Attachment1": "iVBORw0KGgo="
+        }"#;
+
+        let email: InboundEmail = serde_json::from_str(body).expect("Failed to parse the email");
+
+        assert_eq!(email.headers["Received"].len(), 2);
+        assert_eq!(email.header("subject"), Some("Re: Your email flight plan!"));
+        assert_eq!(email.html_part.as_deref(), Some("<p>Thanks!</p>"));
+        assert_eq!(email.spam_assassin_score, Some(0.602));
+        assert_eq!(email.attachments.len(), 2);
+
+        let logo = &email.attachments[0];
+        assert_eq!(logo.filename, "Attachment1");
+        assert_eq!(logo.content_type, "image/png");
+        assert_eq!(logo.content_id.as_deref(), Some("logo"));
+        assert_eq!(logo.content().unwrap(), b"\x89PNG\r\n\x1a\n");
+
+        let plan = &email.attachments[1];
+        assert_eq!(plan.filename, "flight plan.pdf");
+        assert_eq!(plan.content_type, "application/pdf");
+        assert_eq!(plan.content_id, None);
+    }
+
+    #[rstest]
+    #[case(r#"{"SpamAssassinScore": 1.5}"#, Some(1.5))]
+    #[case(r#"{"SpamAssassinScore": "n/a"}"#, None)]
+    #[case(r#"{}"#, None)]
+    fn spam_assassin_score(#[case] body: &str, #[case] expected: Option<f64>) {
+        let email: InboundEmail = serde_json::from_str(body).expect("Failed to parse the email");
+
+        assert_eq!(email.spam_assassin_score, expected);
+    }
+}
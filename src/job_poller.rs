@@ -0,0 +1,175 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Module that implements the polling of asynchronous jobs.
+
+use crate::{
+    data_objects::{ContactsJob, CsvImport, JobStatus, NoParams, ResponseObject},
+    endpoints::Endpoint,
+    ClientError, MailjetClient,
+};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use tracing::debug;
+
+/// Status object of an asynchronous job of the external API.
+///
+/// # Description
+///
+/// Some endpoints of the external API (e.g. `/contact/managemanycontacts`) start a job and return its ID straight
+/// away. The status of the job is retrieved from another endpoint, whose response object implements this trait so
+/// it can be polled using a [JobPoller].
+pub trait AsyncJob: ResponseObject {
+    /// Whether the job finished, either successfully or not.
+    fn is_finished(&self) -> bool;
+
+    /// Raw status of the job when the client doesn't know about it.
+    fn unknown_status(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl AsyncJob for ContactsJob {
+    fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    fn unknown_status(&self) -> Option<&str> {
+        match &self.status {
+            JobStatus::Unknown(status) => Some(status),
+            _ => None,
+        }
+    }
+}
+
+impl AsyncJob for CsvImport {
+    fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    fn unknown_status(&self) -> Option<&str> {
+        match &self.status {
+            JobStatus::Unknown(status) => Some(status),
+            _ => None,
+        }
+    }
+}
+
+/// Object that waits until an asynchronous job of the external API finishes.
+///
+/// # Description
+///
+/// Build a new object using [MailjetClient::poll_job], passing the endpoint that retrieves the status of the job.
+/// Then, use [JobPoller::wait] to get the final status of the job. The status is requested repeatedly, and the delay
+/// between requests doubles on every attempt, up to a maximum. If the job doesn't finish before the timeout,
+/// [ClientError::JobTimeout] is returned. If the external API reports a status that the client doesn't know about,
+/// the poller stops and returns [ClientError::UnknownJobStatus] with the raw status.
+///
+/// A job that finished with errors is not an error of the poller: check the status of the returned object.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # async fn example(client: mailjet_client::MailjetClient) -> Result<(), mailjet_client::ClientError> {
+/// use mailjet_client::{
+///     data_objects::{ContactAction, ManageListContactsQuery, ManagedContact},
+///     endpoints::GetListContactsJob,
+/// };
+/// use std::time::Duration;
+///
+/// let query = ManageListContactsQuery {
+///     action: ContactAction::AddNoForce,
+///     contacts: vec![ManagedContact::new("passenger@mailjet.com")],
+/// };
+/// let handle = client.manage_list_contacts(42, &query).await?.payload.unwrap_or_default();
+///
+/// let job = client
+///     .poll_job(GetListContactsJob { list_id: 42, job_id: handle[0].job_id })
+///     .with_timeout(Duration::from_secs(300))
+///     .wait()
+///     .await?;
+///
+/// println!("{} contacts processed: {:?}", job.count, job.status);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct JobPoller<'a, E: Endpoint> {
+    client: &'a MailjetClient,
+    endpoint: E,
+    initial_delay: Duration,
+    max_delay: Duration,
+    timeout: Duration,
+}
+
+impl<'a, E> JobPoller<'a, E>
+where
+    E: Endpoint<Request = NoParams>,
+    E::Response: AsyncJob,
+{
+    /// Constructor. By default, the status is requested after 1 s, the delay grows up to 30 s, and the poller gives
+    /// up after 10 minutes.
+    pub fn new(client: &'a MailjetClient, endpoint: E) -> Self {
+        JobPoller {
+            client,
+            endpoint,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            timeout: Duration::from_secs(600),
+        }
+    }
+
+    /// Initial delay between requests and the upper bound for it.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_delay = initial;
+        self.max_delay = max.max(initial);
+
+        self
+    }
+
+    /// Maximum time waiting for the job to finish, including the time spent on the requests.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Wait until the job finishes, and return its final status.
+    pub async fn wait(self) -> Result<E::Response, ClientError> {
+        let mut delay = self.initial_delay;
+        let start = Instant::now();
+
+        loop {
+            let job = self
+                .client
+                .execute(&self.endpoint, &NoParams::default())
+                .await?
+                .payload
+                .and_then(|p| p.into_iter().next())
+                .ok_or_else(|| {
+                    ClientError::UnknownError("The external API returned no job status".into())
+                })?;
+
+            if job.is_finished() {
+                return Ok(job);
+            }
+
+            if let Some(status) = job.unknown_status() {
+                return Err(ClientError::UnknownJobStatus(status.into()));
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= self.timeout {
+                return Err(ClientError::JobTimeout(self.timeout));
+            }
+
+            let pause = delay.min(self.timeout - elapsed);
+            debug!("The job is not finished yet, waiting {pause:?}");
+            sleep(pause).await;
+
+            delay = delay.saturating_mul(2).min(self.max_delay);
+        }
+    }
+}
@@ -36,6 +36,14 @@
 //! The library's code attempts to abstract such thing as much as possible using traits. Visit the documentation page
 //! for the module [crate::data_objects] to read a full explanation. In brief, two main objects are distinguish:
 //!
+//! ## Endpoints
+//!
+//! Every endpoint of the external API that is supported by this client is modelled by a type that implements the
+//! trait [crate::endpoints::Endpoint]. That trait binds, at compile time, the request object that the endpoint
+//! accepts, the response object that it returns, the HTTP method, the path and the API version. This way, sending a
+//! [crate::data_objects::SimpleMessage] to an endpoint of the API v3.1 is detected by the compiler rather than by the
+//! external API.
+//!
 //! ## Response Objects
 //!
 //! Responses object vary depending on the used endpoint. Whilst endpoints that implement the API v3.1 include
@@ -43,19 +51,19 @@
 //! error code when errors ocurred. Aside from that, the returned objects as the payload of the response vary quite
 //! a lot.
 //!
-//! To offer a client as homogeneous as possible, all the client's member functions return a trait object:
-//! [crate::data_objects::ResponseObject]. Then, `struct`s implement a particular response object, and you'll need to
-//! cast from the trait object to a particular object based on the endpoint that you are using.
+//! To offer a client as homogeneous as possible, all the client's member functions return a generic
+//! [crate::data_objects::Response] whose payload is a list of objects of the type declared by the endpoint. All those
+//! types implement the trait [crate::data_objects::ResponseObject].
 //!
 //! ## Request Objects
 //!
-//! The same applies to request objects: client's member function expect a trait object that implements the trait
-//! [crate::data_objects::RequestObject]. Then, is the client's implementation who should cast such thing to the
-//! particular object expected by the external endpoint.
+//! The same applies to request objects: every object used as parameters of a request to the external API implements
+//! the trait [crate::data_objects::RequestObject], and each endpoint declares which one it expects.
 //!
 //! # Usage
 //!
-//! The current client only supports sending emails, either using Mailjet's API v3 or v3.1.
+//! The current client supports sending emails, either using Mailjet's API v3 or v3.1, and managing contacts and
+//! senders.
 //!
 //! To start sending emails, instantiate the client [crate::MailjetClient] either directly using
 //! [crate::MailjetClient::new] or (best choice) using the object [crate::MailjetClientBuilder], which eases the
@@ -104,10 +112,53 @@
 /// This is a simple table that links what objects you should use from this crate when you target a particular
 /// endpoint of the external API:
 ///
-/// |    Endpoint   | Request object | Response object |
-/// |:-------------:|:---------------|:----------------|
-/// | `/send` v3    | [crate::data_objects::SimpleMessage] | [crate::data_objects::MessageObject] |
-/// | `/send` v3.1  | [crate::data_objects::SendEmailParams] | [crate::data_objects::SendResponseObject] |
+/// |    Endpoint   | Endpoint type | Request object | Response object |
+/// |:-------------:|:--------------|:---------------|:----------------|
+/// | `/send` v3    | [crate::endpoints::SendV3] | [crate::data_objects::SimpleMessage] | [crate::data_objects::MessageObject] |
+/// | `/send` v3.1  | [crate::endpoints::SendV3_1] | [crate::data_objects::SendEmailParams] | [crate::data_objects::SendResponseObject] |
+/// | `/contact` (POST) | [crate::endpoints::CreateContact] | [crate::data_objects::ContactQuery] | [crate::data_objects::Contact] |
+/// | `/contact` (GET) | [crate::endpoints::ListContacts], [crate::endpoints::GetContact] | [crate::data_objects::ContactFilters] | [crate::data_objects::Contact] |
+/// | `/contact` (PUT) | [crate::endpoints::UpdateContact] | [crate::data_objects::ContactUpdate] | [crate::data_objects::Contact] |
+/// | `/contact/{id}/getcontactslists` (GET) | [crate::endpoints::GetContactsLists] | [crate::data_objects::NoParams] | [crate::data_objects::ContactListMembership] |
+/// | `/contactfilter` (GET) | [crate::endpoints::ListContactFilters], [crate::endpoints::GetContactFilter] | [crate::data_objects::ContactFilterFilters] | [crate::data_objects::ContactFilter] |
+/// | `/contactfilter` (POST, PUT) | [crate::endpoints::CreateContactFilter], [crate::endpoints::UpdateContactFilter] | [crate::data_objects::ContactFilterQuery] | [crate::data_objects::ContactFilter] |
+/// | `/contactfilter` (DELETE) | [crate::endpoints::DeleteContactFilter] | [crate::data_objects::NoParams] | - |
+/// | `/contactmetadata` (GET) | [crate::endpoints::ListContactMetadata], [crate::endpoints::GetContactMetadata] | [crate::data_objects::ContactMetadataFilters] | [crate::data_objects::ContactMetadata] |
+/// | `/contactmetadata` (POST, PUT) | [crate::endpoints::CreateContactMetadata], [crate::endpoints::UpdateContactMetadata] | [crate::data_objects::ContactMetadataQuery] | [crate::data_objects::ContactMetadata] |
+/// | `/contactmetadata` (DELETE) | [crate::endpoints::DeleteContactMetadata] | [crate::data_objects::NoParams] | - |
+/// | `/contactdata` (GET) | [crate::endpoints::ListContactData], [crate::endpoints::GetContactData] | [crate::data_objects::ContactDataFilters] | [crate::data_objects::ContactData] |
+/// | `/contactdata` (PUT) | [crate::endpoints::UpdateContactData] | [crate::data_objects::ContactDataUpdate] | [crate::data_objects::ContactData] |
+/// | `/contactslist` (GET) | [crate::endpoints::ListContactsLists], [crate::endpoints::GetContactsList] | [crate::data_objects::ContactsListFilters] | [crate::data_objects::ContactsList] |
+/// | `/contactslist` (POST, PUT) | [crate::endpoints::CreateContactsList], [crate::endpoints::UpdateContactsList] | [crate::data_objects::ContactsListQuery] | [crate::data_objects::ContactsList] |
+/// | `/contactslist` (DELETE) | [crate::endpoints::DeleteContactsList] | [crate::data_objects::NoParams] | - |
+/// | `/listrecipient` (GET) | [crate::endpoints::ListListRecipients], [crate::endpoints::GetListRecipient] | [crate::data_objects::ListRecipientFilters] | [crate::data_objects::ListRecipient] |
+/// | `/listrecipient` (POST) | [crate::endpoints::CreateListRecipient] | [crate::data_objects::ListRecipientQuery] | [crate::data_objects::ListRecipient] |
+/// | `/listrecipient` (PUT) | [crate::endpoints::UpdateListRecipient] | [crate::data_objects::ListRecipientUpdate] | [crate::data_objects::ListRecipient] |
+/// | `/listrecipient` (DELETE) | [crate::endpoints::DeleteListRecipient] | [crate::data_objects::NoParams] | - |
+/// | `/contact/managemanycontacts` (POST) | [crate::endpoints::ManageManyContacts] | [crate::data_objects::ManageContactsQuery] | [crate::data_objects::JobHandle] |
+/// | `/contact/managemanycontacts/{job_id}` (GET) | [crate::endpoints::GetManyContactsJob] | [crate::data_objects::NoParams] | [crate::data_objects::ContactsJob] |
+/// | `/contactslist/{id}/managemanycontacts` (POST) | [crate::endpoints::ManageListContacts] | [crate::data_objects::ManageListContactsQuery] | [crate::data_objects::JobHandle] |
+/// | `/contactslist/{id}/managemanycontacts/{job_id}` (GET) | [crate::endpoints::GetListContactsJob] | [crate::data_objects::NoParams] | [crate::data_objects::ContactsJob] |
+/// | `/contacts` v4 (DELETE) | [crate::endpoints::DeleteContact] | [crate::data_objects::NoParams] | - |
+/// | `/parseroute` (GET) | [crate::endpoints::ListParseRoutes], [crate::endpoints::GetParseRoute] | [crate::data_objects::ParseRouteFilters] | [crate::data_objects::ParseRoute] |
+/// | `/parseroute` (POST) | [crate::endpoints::CreateParseRoute] | [crate::data_objects::ParseRouteQuery] | [crate::data_objects::ParseRoute] |
+/// | `/parseroute` (DELETE) | [crate::endpoints::DeleteParseRoute] | [crate::data_objects::NoParams] | - |
+/// | `/sender` (GET) | [crate::endpoints::ListSenders], [crate::endpoints::GetSender] | [crate::data_objects::SenderQuery] | [crate::data_objects::Sender] |
+/// | `/sender` (POST) | [crate::endpoints::CreateSender] | [crate::data_objects::SenderCreate] | [crate::data_objects::Sender] |
+/// | `/sender` (DELETE) | [crate::endpoints::DeleteSender] | [crate::data_objects::NoParams] | - |
+/// | `/sender/{id}/validate` (POST) | [crate::endpoints::ValidateSender] | [crate::data_objects::NoParams] | [crate::data_objects::SenderValidation] |
+/// | `/csvimport` (POST) | [crate::endpoints::CreateCsvImport] | [crate::data_objects::CsvImportQuery] | [crate::data_objects::CsvImport] |
+/// | `/csvimport` (GET) | [crate::endpoints::GetCsvImport] | [crate::data_objects::NoParams] | [crate::data_objects::CsvImport] |
+/// | `/eventcallbackurl` (GET) | [crate::endpoints::ListEventCallbackUrls], [crate::endpoints::GetEventCallbackUrl] | [crate::data_objects::EventCallbackUrlFilters] | [crate::data_objects::EventCallbackUrl] |
+/// | `/eventcallbackurl` (POST, PUT) | [crate::endpoints::CreateEventCallbackUrl], [crate::endpoints::UpdateEventCallbackUrl] | [crate::data_objects::EventCallbackUrlQuery] | [crate::data_objects::EventCallbackUrl] |
+/// | `/eventcallbackurl` (DELETE) | [crate::endpoints::DeleteEventCallbackUrl] | [crate::data_objects::NoParams] | - |
+/// | `/message` (GET) | [crate::endpoints::ListMessages], [crate::endpoints::GetMessage] | [crate::data_objects::MessageFilters] | [crate::data_objects::SentMessage] |
+/// | `/messagehistory/{id}` (GET) | [crate::endpoints::GetMessageHistory] | [crate::data_objects::NoParams] | [crate::data_objects::MessageEvent] |
+/// | `/messageinformation` (GET) | [crate::endpoints::ListMessageInformation], [crate::endpoints::GetMessageInformation] | [crate::data_objects::MessageFilters] | [crate::data_objects::MessageInformation] |
+/// | `/template` (GET) | [crate::endpoints::ListTemplates], [crate::endpoints::GetTemplate] | [crate::data_objects::TemplateFilters] | [crate::data_objects::Template] |
+/// | `/template` (POST, PUT) | [crate::endpoints::CreateTemplate], [crate::endpoints::UpdateTemplate] | [crate::data_objects::TemplateQuery] | [crate::data_objects::Template] |
+/// | `/template` (DELETE) | [crate::endpoints::DeleteTemplate] | [crate::data_objects::NoParams] | - |
+/// | `/template/{id}/detailcontent` (GET, POST) | [crate::endpoints::GetTemplateContent], [crate::endpoints::SetTemplateContent] | [crate::data_objects::TemplateContent] | [crate::data_objects::TemplateContent] |
 ///
 /// # Mailjet REST API responses
 ///
@@ -129,35 +180,55 @@
 /// - A couple of integers to inform about the amount of matches that the query generated, and the amount of items
 ///   returned in the response. This is useful for queries that generate high number of matches (hundreds), and
 ///   need pagination. However, this is gently opaqued from the implementation of this client of the API, so any
-///   user needs to worry about it. **A client's call will return all the matches at once.**
+///   user needs to worry about it. **Use [crate::MailjetClient::paginate] to retrieve all the matches at once**, or
+///   as a stream that fetches the pages lazily (see [crate::Paginator]).
 ///
 /// Only the two first items of the list are included in the response objects returned by this client. To cope
 /// with the problem of a non-homogeneous response data object definition, the trait
-/// [crate::data_objects::ResponseObject] is defined in this crate. This way, a common type
-/// [crate::data_objects::Response] is returned by every call of the client that wraps a GET method of the REST API.
+/// [crate::data_objects::ResponseObject] is defined in this crate. This way, a common generic type
+/// [crate::data_objects::Response] is returned by every call of the client, and its payload is typed after the
+/// response object declared by the target endpoint (see [crate::endpoints::Endpoint]).
 ///
 /// [mapi]: https://dev.mailjet.com/email/reference/overview/
 pub mod data_objects {
 
-    use std::any::Any;
+    use serde::{de::DeserializeOwned, Serialize};
 
-    /// An alias to ease developers difficult lives: a [Vec] of objects that implement [ResponseObject].
-    pub type ObjectType = Vec<Box<dyn ResponseObject>>;
-
-    /// Common response type for GET methods of Mailjet's API.
+    /// Common response type for the methods of Mailjet's API.
     ///
     /// # Description
     ///
     /// This object encapsulates the response of any endpoint of Mailjet's API. If [Response::status_code] is a
-    /// success code, the payload will be populated with a particular response object (depends on the used endpoint).
+    /// success code, the payload will be populated with a list of response objects of the type `T`, which is
+    /// declared by the used endpoint (see [crate::endpoints::Endpoint::Response]).
     ///
     /// The [Response::payload] includes the response data from the API. The following list matches APIs's objects
     /// returned as responses and the data objects defined by this crate:
-    /// - `/sender (GET)` -> [crate::data_objects::responses::Sender]
+    /// - `/send (POST)` v3 -> [crate::data_objects::MessageObject]
+    /// - `/send (POST)` v3.1 -> [crate::data_objects::SendResponseObject]
+    /// - `/parseroute (GET, POST)` -> [crate::data_objects::ParseRoute]
+    /// - `/sender (GET, POST)` -> [crate::data_objects::Sender]
+    /// - `/sender/{sender_ID}/validate (POST)` -> [crate::data_objects::SenderValidation]
+    /// - `/contact (GET, POST, PUT)` -> [crate::data_objects::Contact]
+    /// - `/contact/{contact_ID}/getcontactslists (GET)` -> [crate::data_objects::ContactListMembership]
+    /// - `/contactfilter (GET, POST, PUT)` -> [crate::data_objects::ContactFilter]
+    /// - `/contactmetadata (GET, POST, PUT)` -> [crate::data_objects::ContactMetadata]
+    /// - `/contactdata (GET, PUT)` -> [crate::data_objects::ContactData]
+    /// - `/contactslist (GET, POST, PUT)` -> [crate::data_objects::ContactsList]
+    /// - `managemanycontacts (POST)` -> [crate::data_objects::JobHandle]
+    /// - `managemanycontacts/{job_ID} (GET)` -> [crate::data_objects::ContactsJob]
+    /// - `/listrecipient (GET, POST, PUT)` -> [crate::data_objects::ListRecipient]
+    /// - `/csvimport (GET, POST)` -> [crate::data_objects::CsvImport]
+    /// - `/eventcallbackurl (GET, POST, PUT)` -> [crate::data_objects::EventCallbackUrl]
+    /// - `/message (GET)` -> [crate::data_objects::SentMessage]
+    /// - `/messagehistory/{message_ID} (GET)` -> [crate::data_objects::MessageEvent]
+    /// - `/messageinformation (GET)` -> [crate::data_objects::MessageInformation]
+    /// - `/template (GET, POST, PUT)` -> [crate::data_objects::Template]
+    /// - `/template/{template_ID}/detailcontent (GET, POST)` -> [crate::data_objects::TemplateContent]
     #[derive(Debug)]
-    pub struct Response {
+    pub struct Response<T: ResponseObject> {
         pub status_code: u16,
-        pub payload: Option<ObjectType>,
+        pub payload: Option<Vec<T>>,
     }
 
     /// Trait that identifies any object that is returned by Mailjet's REST API.
@@ -166,24 +237,193 @@ pub mod data_objects {
     ///
     /// Every response from the API shall include a matching type in this crate that implements this trait.
     /// This is mandatory to return a generic [Response] from all the client calls provided by this client.
-    pub trait ResponseObject: std::fmt::Debug {}
+    pub trait ResponseObject: DeserializeOwned + std::fmt::Debug {}
 
     /// Trait that identifies any object that is used as parameters for a request to the external API.
-    pub trait RequestObject: std::fmt::Debug {
-        fn as_any(&self) -> &dyn Any;
+    pub trait RequestObject: Serialize + std::fmt::Debug {
+        /// Whether the external API is able to detect duplicates of this request.
+        ///
+        /// # Description
+        ///
+        /// A request that can be deduplicated by Mailjet is safe to be sent more than once, thus the client is allowed
+        /// to retry it after a transient failure (see [crate::RetryPolicy]). By default, requests are not considered
+        /// deduplicable.
+        fn is_deduplicable(&self) -> bool {
+            false
+        }
+    }
+
+    /// Trait that identifies request objects of list (GET) endpoints that support pagination.
+    ///
+    /// # Description
+    ///
+    /// Such request objects include the parameters `Limit`, `Offset` and `CountOnly`. See [crate::Paginator].
+    pub trait PaginatedRequest: RequestObject {
+        /// Offset of the first object requested.
+        fn offset(&self) -> u32;
+        /// Set the maximum amount of objects returned in a response.
+        fn set_limit(&mut self, limit: u16);
+        /// Set the offset of the first object returned in a response.
+        fn set_offset(&mut self, offset: u32);
+        /// Request only the amount of matches, rather than the objects.
+        fn set_count_only(&mut self, count_only: bool);
     }
 
     mod responses;
 
     pub use responses::*;
 
+    mod attachment;
+    pub(crate) use attachment::check_attachments_size;
+    pub use attachment::{Attachment, MAX_MESSAGE_SIZE};
+
     mod message;
     pub use message::{
-        Attachment, Message, MessageBuilder, MessageProperty, NameAndEmail, SimpleMessage, Track,
+        to_variables, Message, MessageBuilder, MessageProperty, NameAndEmail, SimpleMessage, Track,
+        Variables,
     };
 
+    mod conversion;
+    pub use conversion::{Conversion, EmailMessage};
+
+    mod validation;
+    pub use validation::{Validate, ValidationErrors, Violation, MAX_PRIORITY, MAX_RECIPIENTS};
+
     mod request;
-    pub use request::{ContactQuery, SendEmailParams};
+    pub use request::{
+        ContactAction, ContactDataFilters, ContactDataUpdate, ContactFilterFilters,
+        ContactFilterQuery, ContactFilters, ContactMetadataFilters, ContactMetadataQuery,
+        ContactQuery, ContactUpdate, ContactsListAction, ContactsListFilters, ContactsListQuery,
+        CsvImportQuery, EventCallbackUrlFilters, EventCallbackUrlQuery, ImportOptions,
+        ListRecipientFilters, ListRecipientQuery, ListRecipientUpdate, ManageContactsQuery,
+        ManageListContactsQuery, ManagedContact, MessageFilters, NoParams, ParseRouteFilters,
+        ParseRouteQuery, ResourceId, SendEmailParams, SendEmailParamsBuilder, SenderCreate,
+        SenderQuery, TemplateFilters, TemplateQuery,
+    };
+}
+
+/// This module includes the definition of the endpoints of Mailjet's REST API supported by the client.
+///
+/// # Description
+///
+/// Each endpoint of the external [API][mapi] is modelled by a type that implements the trait [Endpoint](crate::endpoints::Endpoint). Such trait
+/// declares all the information that the client needs to issue a request to the endpoint:
+/// - The request object that is sent as parameters of the request ([Endpoint::Request](crate::endpoints::Endpoint::Request)).
+/// - The response object that is returned within the payload of the response ([Endpoint::Response](crate::endpoints::Endpoint::Response)).
+/// - The HTTP method, the API version and the path of the endpoint.
+///
+/// Thanks to that, the client's member functions are generic over the endpoint, and a request object that doesn't
+/// match the target endpoint is rejected by the compiler instead of failing at runtime.
+///
+/// Objects implementing [Endpoint](crate::endpoints::Endpoint) are usually unit `struct`s. Endpoints whose path includes some parameter (an ID,
+/// for example) carry that parameter as an attribute of the `struct`.
+///
+/// [mapi]: https://dev.mailjet.com/email/reference/overview/
+pub mod endpoints {
+
+    use crate::{
+        data_objects::{RequestObject, ResponseObject},
+        ApiVersion, RateLimitBucket,
+    };
+    use reqwest::Method;
+    use serde::de::DeserializeOwned;
+
+    /// Trait that describes an endpoint of Mailjet's REST API.
+    pub trait Endpoint: std::fmt::Debug {
+        /// Data object sent as parameters of the request. It is serialized as the query string for GET and DELETE
+        /// requests, and as a JSON body otherwise.
+        type Request: RequestObject;
+        /// Data object returned within the payload of the response.
+        type Response: ResponseObject;
+        /// Raw layout of the response's body. The client unwraps the response objects from it.
+        type Payload: DeserializeOwned + Into<Vec<Self::Response>>;
+        /// HTTP method used by the endpoint.
+        const METHOD: Method;
+        /// API version implemented by the endpoint.
+        const API_VERSION: ApiVersion;
+
+        /// Path of the endpoint, relative to the base URL of the API.
+        fn path(&self) -> String;
+
+        /// Bucket of the client-side rate limiter that requests to this endpoint consume from.
+        ///
+        /// # Description
+        ///
+        /// By default, GET requests are considered reads, and the rest writes. See [crate::RateLimiter].
+        fn rate_limit_bucket(&self) -> RateLimitBucket {
+            if Self::METHOD == Method::GET {
+                RateLimitBucket::Read
+            } else {
+                RateLimitBucket::Write
+            }
+        }
+    }
+
+    mod send;
+    pub use send::{SendRequest, SendV3, SendV3_1};
+
+    mod contact;
+    pub use contact::{
+        CreateContact, DeleteContact, GetContact, GetContactsLists, ListContacts, UpdateContact,
+    };
+
+    mod contact_data;
+    pub use contact_data::{GetContactData, ListContactData, UpdateContactData};
+
+    mod contact_filter;
+    pub use contact_filter::{
+        CreateContactFilter, DeleteContactFilter, GetContactFilter, ListContactFilters,
+        UpdateContactFilter,
+    };
+
+    mod contact_metadata;
+    pub use contact_metadata::{
+        CreateContactMetadata, DeleteContactMetadata, GetContactMetadata, ListContactMetadata,
+        UpdateContactMetadata,
+    };
+
+    mod contacts_list;
+    pub use contacts_list::{
+        CreateContactsList, DeleteContactsList, GetContactsList, ListContactsLists,
+        UpdateContactsList,
+    };
+
+    mod csv_import;
+    pub use csv_import::{CreateCsvImport, GetCsvImport};
+
+    mod event_callback_url;
+    pub use event_callback_url::{
+        CreateEventCallbackUrl, DeleteEventCallbackUrl, GetEventCallbackUrl, ListEventCallbackUrls,
+        UpdateEventCallbackUrl,
+    };
+
+    mod list_recipient;
+    pub use list_recipient::{
+        CreateListRecipient, DeleteListRecipient, GetListRecipient, ListListRecipients,
+        UpdateListRecipient,
+    };
+
+    mod manage_contacts;
+    pub use manage_contacts::{
+        GetListContactsJob, GetManyContactsJob, ManageListContacts, ManageManyContacts,
+    };
+
+    mod message;
+    pub use message::{
+        GetMessage, GetMessageHistory, GetMessageInformation, ListMessageInformation, ListMessages,
+    };
+
+    mod parse_route;
+    pub use parse_route::{CreateParseRoute, DeleteParseRoute, GetParseRoute, ListParseRoutes};
+
+    mod sender;
+    pub use sender::{CreateSender, DeleteSender, GetSender, ListSenders, ValidateSender};
+
+    mod template;
+    pub use template::{
+        CreateTemplate, DeleteTemplate, GetTemplate, GetTemplateContent, ListTemplates,
+        SetTemplateContent, UpdateTemplate,
+    };
 }
 
 // Re-export
@@ -194,10 +434,38 @@ mod mailjet_client;
 pub use mailjet_client::MailjetClient;
 
 mod error;
-pub use error::ClientError;
+pub use error::{ApiErrorDetails, ClientError};
 
 mod mailjet_api;
 pub use mailjet_api::ApiVersion;
 
 mod mailjet_client_builder;
 pub use mailjet_client_builder::MailjetClientBuilder;
+
+mod retry_policy;
+pub use retry_policy::RetryPolicy;
+
+mod paginator;
+pub use paginator::{Paginator, MAX_PAGE_SIZE};
+
+mod rate_limiter;
+pub use rate_limiter::{RateLimit, RateLimitBucket, RateLimitMetrics, RateLimiter};
+
+mod job_poller;
+pub use job_poller::{AsyncJob, JobPoller};
+
+mod bulk_send;
+pub use bulk_send::{SendOutcome, MAX_MESSAGES_PER_REQUEST};
+
+mod template_renderer;
+pub use template_renderer::{
+    Rendered, RenderedMessage, TemplateError, TemplateErrorKind, TemplateRenderer,
+};
+
+pub mod inbound;
+
+pub mod webhooks;
+
+pub mod contact_properties;
+
+pub mod segmentation;
@@ -8,11 +8,12 @@ use core::convert::TryFrom;
 use core::fmt;
 
 /// `Enum` to select the API version of Mailjet's REST API.
-#[derive(PartialEq, Default)]
+#[derive(PartialEq, Eq, Clone, Copy, Default)]
 pub enum ApiVersion {
     #[default]
     V3,
     V3_1,
+    V4,
 }
 
 /// A simple look-up table that builds the URL of a particular endpoint.
@@ -36,6 +37,23 @@ impl ApiUrl {
             _ => format!("{}/{endpoint}", ApiVersion::default()),
         }
     }
+
+    /// URL of a resource of the [REST API][rest] (v3).
+    /// [rest]: https://dev.mailjet.com/email/reference/overview/
+    pub fn rest(resource: &str) -> String {
+        format!("{}/REST/{resource}", ApiVersion::V3)
+    }
+
+    /// URL of a resource of the [DATA API][data] (v3), which exchanges raw content rather than JSON objects.
+    /// [data]: https://dev.mailjet.com/email/reference/contacts/bulk-contact-management/
+    pub fn data(resource: &str) -> String {
+        format!("{}/DATA/{resource}", ApiVersion::V3)
+    }
+
+    /// URL of a resource of the API v4.
+    pub fn v4(resource: &str) -> String {
+        format!("{}/{resource}", ApiVersion::V4)
+    }
 }
 
 impl TryFrom<&str> for ApiVersion {
@@ -47,6 +65,7 @@ impl TryFrom<&str> for ApiVersion {
         match value.as_str() {
             "v3.1" => Ok(ApiVersion::V3_1),
             "v3" => Ok(ApiVersion::V3),
+            "v4" => Ok(ApiVersion::V4),
             _ => Err(ClientError::WrongApiVersion),
         }
     }
@@ -57,6 +76,7 @@ impl fmt::Debug for ApiVersion {
         let version = match self {
             ApiVersion::V3 => "v3",
             ApiVersion::V3_1 => "v3.1",
+            ApiVersion::V4 => "v4",
         };
 
         write!(f, "{version}")
@@ -68,6 +88,7 @@ impl fmt::Display for ApiVersion {
         let version = match self {
             ApiVersion::V3 => "v3",
             ApiVersion::V3_1 => "v3.1",
+            ApiVersion::V4 => "v4",
         };
 
         write!(f, "{version}")
@@ -90,15 +111,12 @@ mod tests {
     }
 
     #[rstest]
-    #[case("default", ClientError::WrongApiVersion)]
-    fn check_apiversion_fails_when_using_wrong_data(
-        #[case] input: &str,
-        #[case] expected: ClientError,
-    ) {
+    #[case("default")]
+    fn check_apiversion_fails_when_using_wrong_data(#[case] input: &str) {
         let api_version = ApiVersion::try_from(input);
         assert!(api_version.is_err());
         let error = api_version.err().unwrap();
-        assert_eq!(error, expected);
+        assert!(matches!(error, ClientError::WrongApiVersion));
     }
 
     #[rstest]
@@ -117,4 +135,25 @@ mod tests {
         api_version = ApiVersion::V3_1;
         assert_eq!(ApiUrl::send(&api_version), "v3.1/send".to_string());
     }
+
+    #[rstest]
+    #[case("contact", "v3/REST/contact")]
+    #[case("contact/42", "v3/REST/contact/42")]
+    fn check_api_rest(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(ApiUrl::rest(input), expected.to_string());
+    }
+
+    #[rstest]
+    fn check_api_data() {
+        assert_eq!(
+            ApiUrl::data("contactslist/42/CSVData/text:plain"),
+            "v3/DATA/contactslist/42/CSVData/text:plain".to_string()
+        );
+    }
+
+    #[rstest]
+    fn check_api_v4() {
+        assert_eq!(ApiUrl::v4("contacts/42"), "v4/contacts/42".to_string());
+        assert_eq!(ApiVersion::try_from("v4").unwrap(), ApiVersion::V4);
+    }
 }
@@ -6,17 +6,56 @@
 //! Client module.
 
 use crate::{
+    bulk_send::{self, SendOutcome},
+    contact_properties::{self, ContactProperties},
     data_objects::{
-        MessageObject, RequestObject, Response, ResponseObject, SendEmailParams,
-        SendResponseObject, SimpleMessage,
+        Contact, ContactData, ContactDataFilters, ContactDataUpdate, ContactFilter,
+        ContactFilterFilters, ContactFilterQuery, ContactFilters, ContactListMembership,
+        ContactMetadata, ContactMetadataFilters, ContactMetadataQuery, ContactQuery, ContactUpdate,
+        ContactsJob, ContactsList, ContactsListFilters, ContactsListQuery, CsvImport,
+        CsvImportQuery, DataUpload, EmailMessage, EventCallbackUrl, EventCallbackUrlFilters,
+        EventCallbackUrlQuery, JobHandle, ListRecipient, ListRecipientFilters, ListRecipientQuery,
+        ListRecipientUpdate, ManageContactsQuery, ManageListContactsQuery, Message,
+        MessageDelivery, MessageEvent, MessageFilters, MessageInformation, MessageObject,
+        MessageProperty, NoParams, PaginatedRequest, ParseRoute, ParseRouteFilters,
+        ParseRouteQuery, RequestObject, ResourceId, Response, RestResponse, SendEmailParamsBuilder,
+        Sender, SenderCreate, SenderQuery, SenderValidation, SentMessage, Status, Template,
+        TemplateContent, TemplateFilters, TemplateQuery, Validate,
     },
+    endpoints::{
+        CreateContact, CreateContactFilter, CreateContactMetadata, CreateContactsList,
+        CreateCsvImport, CreateEventCallbackUrl, CreateListRecipient, CreateParseRoute,
+        CreateSender, CreateTemplate, DeleteContact, DeleteContactFilter, DeleteContactMetadata,
+        DeleteContactsList, DeleteEventCallbackUrl, DeleteListRecipient, DeleteParseRoute,
+        DeleteSender, DeleteTemplate, Endpoint, GetContact, GetContactData, GetContactFilter,
+        GetContactMetadata, GetContactsList, GetContactsLists, GetCsvImport, GetEventCallbackUrl,
+        GetListContactsJob, GetListRecipient, GetManyContactsJob, GetMessage, GetMessageHistory,
+        GetMessageInformation, GetParseRoute, GetSender, GetTemplate, GetTemplateContent,
+        ListContactData, ListContactFilters, ListContactMetadata, ListContacts, ListContactsLists,
+        ListEventCallbackUrls, ListListRecipients, ListMessageInformation, ListMessages,
+        ListParseRoutes, ListSenders, ListTemplates, ManageListContacts, ManageManyContacts,
+        SendRequest, SetTemplateContent, UpdateContact, UpdateContactData, UpdateContactFilter,
+        UpdateContactMetadata, UpdateContactsList, UpdateEventCallbackUrl, UpdateListRecipient,
+        UpdateTemplate, ValidateSender,
+    },
+    error::REQUEST_ID_HEADER,
     mailjet_api::ApiUrl,
-    ApiVersion, ClientError,
+    webhooks::{self, WebhookChanges, WebhookSpec},
+    ApiVersion, AsyncJob, ClientError, JobPoller, Paginator, RateLimitBucket, RateLimitMetrics,
+    RateLimiter, RetryPolicy, MAX_PAGE_SIZE,
 };
+use futures::{stream, StreamExt};
+use reqwest::{header::CONTENT_TYPE, Body, Method};
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_tracing::TracingMiddleware;
 use secrecy::{ExposeSecret, SecretString};
-use serde::Deserialize;
+use std::path::Path;
+use tokio::{
+    fs::File,
+    io::{AsyncRead, AsyncWrite, AsyncWriteExt},
+    time::sleep,
+};
+use tokio_util::io::ReaderStream;
 use tracing::{debug, error, info, instrument, trace, warn};
 
 /// This object implements a client for [Mailjet's][mapi] REST API.
@@ -24,9 +63,10 @@ use tracing::{debug, error, info, instrument, trace, warn};
 /// # Description
 ///
 /// The member functions of this object match the names of the endpoints for Mailjet REST API.
-/// When you build a new instance of the client, you shall specify what API version you aim to use.
-/// However, you are free to change it using [MailjetClient::use_api_version]. The supported versions
-/// are listed by the `enum`: [crate::ApiVersion].
+/// Each endpoint declares the API version that it implements (see [crate::endpoints::Endpoint]), so the request
+/// objects passed to the client select the target API version. When you build a new instance of the client, you
+/// might specify what API version you aim to use by default. You are free to change it using
+/// [MailjetClient::use_api_version]. The supported versions are listed by the `enum`: [crate::ApiVersion].
 ///
 /// A fluent builder object is included to build a new client using [crate::MailjetClientBuilder] rather than using
 ///  [MailjetClient::new].
@@ -37,7 +77,7 @@ use tracing::{debug, error, info, instrument, trace, warn};
 ///
 /// When using the external API >= 3.1, it is possible to specify if we aim to run our requests in _sandbox mode_.
 /// By default, this mode is disabled. Enable it using [MailjetClient::enable_sandbox_mode]. However, if the attribute
-/// is defined as part of [SendEmailParams], the local value is honored when the global _sandbox mode_ is disabled.
+/// is defined as part of [crate::data_objects::SendEmailParams], the local value is honored when the global _sandbox mode_ is disabled.
 ///
 ///
 /// [mapi]: https://dev.mailjet.com/email/reference/overview/
@@ -51,6 +91,9 @@ pub struct MailjetClient {
     api_url: String,
     api_version: ApiVersion,
     sandbox_mode: bool,
+    validation: bool,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl MailjetClient {
@@ -115,7 +158,7 @@ impl MailjetClient {
             .use_native_tls()
             .https_only(force_https.unwrap_or(true))
             .build()
-            .map_err(|_| ClientError::HTTPClient)?;
+            .map_err(ClientError::HTTPClient)?;
 
         let wrapped_client = reqwest_middleware::ClientBuilder::new(http_client)
             .with(TracingMiddleware::default())
@@ -132,6 +175,9 @@ impl MailjetClient {
             api_url,
             api_version,
             sandbox_mode: false,
+            validation: false,
+            retry_policy: RetryPolicy::disabled(),
+            rate_limiter: None,
         })
     }
 
@@ -140,6 +186,21 @@ impl MailjetClient {
         self.api_version = version;
     }
 
+    /// Change the policy used to retry requests that failed due to transient errors. See [RetryPolicy].
+    pub fn use_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Limit the rate of requests sent to the external API. See [RateLimiter].
+    pub fn use_rate_limiter(&mut self, limiter: RateLimiter) {
+        self.rate_limiter = Some(limiter);
+    }
+
+    /// Metrics of the client-side rate limiter, if any.
+    pub fn rate_limit_metrics(&self) -> Option<RateLimitMetrics> {
+        self.rate_limiter.as_ref().map(RateLimiter::metrics)
+    }
+
     /// Enable the _sandbox mode_ for sending messages.
     pub fn enable_sandbox_mode(&mut self) {
         if self.api_version == ApiVersion::V3 {
@@ -157,206 +218,1339 @@ impl MailjetClient {
         }
     }
 
+    /// Validate the messages locally before sending them. See [Validate].
+    ///
+    /// [Validate]: crate::data_objects::Validate
+    pub fn enable_validation(&mut self) {
+        self.validation = true;
+    }
+
+    /// Send the messages without validating them locally.
+    pub fn disable_validation(&mut self) {
+        self.validation = false;
+    }
+
     /// Send a new email.
     ///
     /// # Description
     ///
-    /// This is the public method to send a new email. Depending on the selected target API version, a different
-    /// type of object shall be passed as argument. Check out [crate::data_objects] docs.
+    /// This is the public method to send a new email. The type of the given request object selects the target
+    /// version of the endpoint `/send`: [SimpleMessage] is sent to the API v3, whilst [SendEmailParams] is sent to
+    /// the API v3.1. Check out [crate::data_objects] docs.
     ///
     /// When the request is successfully sent to the external API, an `Ok(Response)` is returned from this method.
-    /// If the external API detected some issue with the content of your request, it will be signaled via
-    /// [Response::status_code], not as a [ClientError]. The latter is returned when a problem is detected in the
-    /// internal logic of this client.
-    pub async fn send_email(&self, request: &impl RequestObject) -> Result<Response, ClientError> {
-        match self.api_version {
-            ApiVersion::V3 => {
-                trace!("Sending email to the external API (v3)");
-                self.send_email_v3(request).await
+    /// If the external API rejects the request, a [ClientError] is returned that carries the status code of the
+    /// response, and the errors reported by the external API (see [crate::ApiErrorDetails]). Messages whose
+    /// attachments exceed [crate::data_objects::MAX_MESSAGE_SIZE] are rejected before sending the request. When the
+    /// validation is enabled (see [MailjetClient::enable_validation]), the request is fully validated instead, and
+    /// [ClientError::Validation] is returned if any rule is broken.
+    ///
+    /// [SimpleMessage]: crate::data_objects::SimpleMessage
+    /// [SendEmailParams]: crate::data_objects::SendEmailParams
+    pub async fn send_email<R: SendRequest>(
+        &self,
+        request: &R,
+    ) -> Result<Response<<R::Endpoint as Endpoint>::Response>, ClientError> {
+        let endpoint = R::Endpoint::default();
+        trace!(
+            "Sending email to the external API ({})",
+            <R::Endpoint as Endpoint>::API_VERSION
+        );
+
+        // Catch messages that the external API would reject.
+        if self.validation {
+            request.validate().map_err(ClientError::Validation)?;
+        } else {
+            request.check_size()?;
+        }
+
+        let mut request = request.clone();
+        // Apply the sandbox mode if needed.
+        request.apply_sandbox_mode(self.sandbox_mode);
+
+        self.execute(&endpoint, &request).await
+    }
+
+    /// Send a message using the API version configured in the client.
+    ///
+    /// # Description
+    ///
+    /// This method accepts a message built using any of the models ([SimpleMessage] or [Message]), and converts it
+    /// to the model of the configured API version when needed: the API v3 is used when the client targets it, and
+    /// the API v3.1 otherwise. The fields that can't be represented in the target model are dropped, and reported
+    /// using a warning log (see [Conversion]).
+    ///
+    /// The returned [Response] includes an object per recipient of the message, regardless of the API version.
+    ///
+    /// [SimpleMessage]: crate::data_objects::SimpleMessage
+    /// [Conversion]: crate::data_objects::Conversion
+    pub async fn send_message(
+        &self,
+        message: impl Into<EmailMessage>,
+    ) -> Result<Response<MessageObject>, ClientError> {
+        let message = message.into();
+
+        if self.api_version == ApiVersion::V3 {
+            let conversion = message.into_simple_message();
+            if !conversion.is_lossless() {
+                warn!(
+                    "Fields not supported by the API v3 were dropped: {:?}",
+                    conversion.lost_fields
+                );
             }
-            ApiVersion::V3_1 => {
-                trace!("Sending email to the external API (v3.1)");
-                self.send_email_v3_1(request).await
+
+            self.send_email(&conversion.value).await
+        } else {
+            let conversion = message.into_message();
+            if !conversion.is_lossless() {
+                warn!(
+                    "Fields not supported by the API v3.1 were dropped: {:?}",
+                    conversion.lost_fields
+                );
             }
+
+            let request = SendEmailParamsBuilder::default()
+                .add_message(conversion.value)
+                .build();
+            let response = self.send_email(&request).await?;
+
+            Ok(Response {
+                status_code: response.status_code,
+                payload: response.payload.map(|messages| {
+                    messages
+                        .into_iter()
+                        .flat_map(|m| [m.to, m.cc, m.bcc])
+                        .flatten()
+                        .flatten()
+                        .collect()
+                }),
+            })
         }
     }
 
-    #[instrument]
-    async fn send_email_v3_1(&self, request: &impl RequestObject) -> Result<Response, ClientError> {
-        debug!("Request parameters: {:#?}", request);
+    /// Send an arbitrary amount of messages using the API v3.1.
+    ///
+    /// # Description
+    ///
+    /// The endpoint `/send` accepts up to [MAX_MESSAGES_PER_REQUEST] messages in a single request. This method splits
+    /// the given messages into as many requests as needed (each of them including `globals`), and sends them using
+    /// [MailjetClient::send_email], with up to `concurrency` requests in flight at the same time.
+    ///
+    /// A [SendOutcome] is returned for each of the given messages, in the same order. Errors don't stop the
+    /// delivery of the rest of the requests.
+    ///
+    /// [MAX_MESSAGES_PER_REQUEST]: crate::MAX_MESSAGES_PER_REQUEST
+    pub async fn send_bulk(
+        &self,
+        messages: Vec<Message>,
+        globals: Option<MessageProperty>,
+        concurrency: usize,
+    ) -> Vec<SendOutcome> {
+        let requests = bulk_send::split_messages(messages, globals);
+        debug!("Sending {} bulk requests", requests.len());
 
-        let mut request_params: SendEmailParams =
-            match request.as_any().downcast_ref::<SendEmailParams>() {
-                Some(r) => r.clone(),
-                None => return Err(ClientError::UnknownError("Invalid request".to_string())),
-            };
+        stream::iter(requests)
+            .map(|request| async move {
+                let result = self.send_email(&request).await;
+                bulk_send::outcomes(result, request.messages.len())
+            })
+            .buffered(concurrency.max(1))
+            .flat_map(stream::iter)
+            .collect()
+            .await
+    }
 
-        // Apply the sandbox mode if needed.
-        if !request_params.sandbox_mode.unwrap_or_default() {
-            debug!("The global sandbox mode is applied to the current message");
-            request_params.sandbox_mode = Some(self.sandbox_mode);
+    /// Create a new contact.
+    ///
+    /// # Description
+    ///
+    /// See [`/contact`](https://dev.mailjet.com/email/reference/contacts/contact#v3_post_contact). The returned
+    /// [Response] includes the new [Contact].
+    pub async fn create_contact(
+        &self,
+        contact: &ContactQuery,
+    ) -> Result<Response<Contact>, ClientError> {
+        self.execute(&CreateContact, contact).await
+    }
+
+    /// Retrieve a contact using its ID or its email address.
+    pub async fn get_contact(
+        &self,
+        contact: impl Into<ResourceId>,
+    ) -> Result<Response<Contact>, ClientError> {
+        self.execute(&GetContact(contact.into()), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve a list of contacts that match the given filters.
+    pub async fn list_contacts(
+        &self,
+        filters: &ContactFilters,
+    ) -> Result<Response<Contact>, ClientError> {
+        self.execute(&ListContacts, filters).await
+    }
+
+    /// Update the name, or the exclusion from campaigns flag of a contact.
+    pub async fn update_contact(
+        &self,
+        contact: impl Into<ResourceId>,
+        update: &ContactUpdate,
+    ) -> Result<Response<Contact>, ClientError> {
+        self.execute(&UpdateContact(contact.into()), update).await
+    }
+
+    /// Delete a contact and all its personal data (GDPR).
+    ///
+    /// # Description
+    ///
+    /// This call uses the API v4. See
+    /// [`/contacts`](https://dev.mailjet.com/email/reference/contacts/contact#v4_delete_contacts_contact_ID).
+    /// The returned [Response] carries no payload.
+    pub async fn delete_contact(&self, contact_id: i64) -> Result<Response<Contact>, ClientError> {
+        self.execute(&DeleteContact(contact_id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve the lists a contact belongs to, including those it unsubscribed from.
+    pub async fn get_contact_lists(
+        &self,
+        contact: impl Into<ResourceId>,
+    ) -> Result<Response<ContactListMembership>, ClientError> {
+        self.execute(&GetContactsLists(contact.into()), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve a list of the segmentation filters of the account.
+    pub async fn list_contact_filters(
+        &self,
+        filters: &ContactFilterFilters,
+    ) -> Result<Response<ContactFilter>, ClientError> {
+        self.execute(&ListContactFilters, filters).await
+    }
+
+    /// Retrieve a segmentation filter by its ID.
+    pub async fn get_contact_filter(
+        &self,
+        id: i64,
+    ) -> Result<Response<ContactFilter>, ClientError> {
+        self.execute(&GetContactFilter(id), &NoParams::default())
+            .await
+    }
+
+    /// Create a new segmentation filter.
+    ///
+    /// # Description
+    ///
+    /// The syntax of the expression is checked before sending the request, so [ClientError::Validation] is returned
+    /// for malformed expressions. See [crate::segmentation].
+    pub async fn create_contact_filter(
+        &self,
+        query: &ContactFilterQuery,
+    ) -> Result<Response<ContactFilter>, ClientError> {
+        query.validate().map_err(ClientError::Validation)?;
+
+        self.execute(&CreateContactFilter, query).await
+    }
+
+    /// Update a segmentation filter. The expression is checked as in [MailjetClient::create_contact_filter].
+    pub async fn update_contact_filter(
+        &self,
+        id: i64,
+        query: &ContactFilterQuery,
+    ) -> Result<Response<ContactFilter>, ClientError> {
+        query.validate().map_err(ClientError::Validation)?;
+
+        self.execute(&UpdateContactFilter(id), query).await
+    }
+
+    /// Delete a segmentation filter.
+    pub async fn delete_contact_filter(
+        &self,
+        id: i64,
+    ) -> Result<Response<ContactFilter>, ClientError> {
+        self.execute(&DeleteContactFilter(id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve a list of the contact properties declared in the account.
+    pub async fn list_contact_metadata(
+        &self,
+        filters: &ContactMetadataFilters,
+    ) -> Result<Response<ContactMetadata>, ClientError> {
+        self.execute(&ListContactMetadata, filters).await
+    }
+
+    /// Retrieve a contact property by its ID.
+    pub async fn get_contact_metadata(
+        &self,
+        id: i64,
+    ) -> Result<Response<ContactMetadata>, ClientError> {
+        self.execute(&GetContactMetadata(id), &NoParams::default())
+            .await
+    }
+
+    /// Declare a new contact property.
+    pub async fn create_contact_metadata(
+        &self,
+        query: &ContactMetadataQuery,
+    ) -> Result<Response<ContactMetadata>, ClientError> {
+        self.execute(&CreateContactMetadata, query).await
+    }
+
+    /// Update the name or the data type of a contact property.
+    pub async fn update_contact_metadata(
+        &self,
+        id: i64,
+        query: &ContactMetadataQuery,
+    ) -> Result<Response<ContactMetadata>, ClientError> {
+        self.execute(&UpdateContactMetadata(id), query).await
+    }
+
+    /// Delete a contact property, along with its values for all the contacts.
+    pub async fn delete_contact_metadata(
+        &self,
+        id: i64,
+    ) -> Result<Response<ContactMetadata>, ClientError> {
+        self.execute(&DeleteContactMetadata(id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve the properties of a list of contacts.
+    pub async fn list_contact_data(
+        &self,
+        filters: &ContactDataFilters,
+    ) -> Result<Response<ContactData>, ClientError> {
+        self.execute(&ListContactData, filters).await
+    }
+
+    /// Retrieve the properties of a contact, by its ID or email address.
+    pub async fn get_contact_data(
+        &self,
+        contact: impl Into<ResourceId>,
+    ) -> Result<Response<ContactData>, ClientError> {
+        self.execute(&GetContactData(contact.into()), &NoParams::default())
+            .await
+    }
+
+    /// Update the properties of a contact, by its ID or email address.
+    pub async fn update_contact_data(
+        &self,
+        contact: impl Into<ResourceId>,
+        update: &ContactDataUpdate,
+    ) -> Result<Response<ContactData>, ClientError> {
+        self.execute(&UpdateContactData(contact.into()), update)
+            .await
+    }
+
+    /// Declare in the account the contact properties of `T` that are missing.
+    ///
+    /// # Description
+    ///
+    /// Properties that are already declared are kept. If any of them uses a different data type or namespace than
+    /// declared by [ContactProperties::properties], [ClientError::Validation] is returned and nothing is created.
+    /// Returns the properties that were created.
+    pub async fn declare_contact_properties<T: ContactProperties>(
+        &self,
+    ) -> Result<Vec<ContactMetadata>, ClientError> {
+        let metadata = self
+            .paginate(ListContactMetadata, ContactMetadataFilters::default())
+            .collect_all(MAX_PAGE_SIZE as usize)
+            .await?;
+
+        let (declared, missing): (Vec<_>, Vec<_>) = T::properties()
+            .into_iter()
+            .partition(|p| metadata.iter().any(|m| m.name == p.name));
+        contact_properties::check_metadata(&declared, &metadata)
+            .map_err(ClientError::Validation)?;
+
+        let mut created = Vec::new();
+        for property in &missing {
+            info!("Declaring the contact property {}", property.name);
+            let response = self
+                .create_contact_metadata(&ContactMetadataQuery::from(property))
+                .await?;
+            created.extend(response.payload.unwrap_or_default());
         }
 
-        // Build a new request using the HTTP client.
-        let request = self
+        Ok(created)
+    }
+
+    /// Retrieve the properties of a contact as an object of type `T`.
+    ///
+    /// # Description
+    ///
+    /// The values are converted into the data types declared by [ContactProperties::properties].
+    /// [ClientError::ParseError] is returned when some value doesn't match its type.
+    pub async fn get_contact_properties<T: ContactProperties>(
+        &self,
+        contact: impl Into<ResourceId>,
+    ) -> Result<T, ClientError> {
+        let data = self
+            .get_contact_data(contact)
+            .await?
+            .payload
+            .and_then(|p| p.into_iter().next())
+            .unwrap_or_default();
+
+        T::from_contact_data(&data)
+    }
+
+    /// Update the properties of a contact using an object of type `T`.
+    ///
+    /// # Description
+    ///
+    /// Before sending the update, the values are checked against the data types declared by
+    /// [ContactProperties::properties], and the declarations are checked against the metadata of the account (which
+    /// costs an extra request). [ClientError::Validation] is returned when any of the checks fails.
+    pub async fn update_contact_properties<T: ContactProperties>(
+        &self,
+        contact: impl Into<ResourceId>,
+        properties: &T,
+    ) -> Result<Response<ContactData>, ClientError> {
+        let update = properties.to_contact_data()?;
+        let metadata = self
+            .paginate(ListContactMetadata, ContactMetadataFilters::default())
+            .collect_all(MAX_PAGE_SIZE as usize)
+            .await?;
+        contact_properties::check_metadata(&T::properties(), &metadata)
+            .map_err(ClientError::Validation)?;
+
+        self.update_contact_data(contact, &update).await
+    }
+
+    /// Retrieve a list of contact lists that match the given filters.
+    pub async fn list_contacts_lists(
+        &self,
+        filters: &ContactsListFilters,
+    ) -> Result<Response<ContactsList>, ClientError> {
+        self.execute(&ListContactsLists, filters).await
+    }
+
+    /// Retrieve a contact list using its ID.
+    pub async fn get_contacts_list(&self, id: i64) -> Result<Response<ContactsList>, ClientError> {
+        self.execute(&GetContactsList(id), &NoParams::default())
+            .await
+    }
+
+    /// Create a new contact list.
+    pub async fn create_contacts_list(
+        &self,
+        list: &ContactsListQuery,
+    ) -> Result<Response<ContactsList>, ClientError> {
+        self.execute(&CreateContactsList, list).await
+    }
+
+    /// Rename a contact list, or move it to (or restore it from) the trash.
+    pub async fn update_contacts_list(
+        &self,
+        id: i64,
+        update: &ContactsListQuery,
+    ) -> Result<Response<ContactsList>, ClientError> {
+        self.execute(&UpdateContactsList(id), update).await
+    }
+
+    /// Delete a contact list. The returned [Response] carries no payload.
+    pub async fn delete_contacts_list(
+        &self,
+        id: i64,
+    ) -> Result<Response<ContactsList>, ClientError> {
+        self.execute(&DeleteContactsList(id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve a list of subscriptions of contacts to lists that match the given filters.
+    pub async fn list_list_recipients(
+        &self,
+        filters: &ListRecipientFilters,
+    ) -> Result<Response<ListRecipient>, ClientError> {
+        self.execute(&ListListRecipients, filters).await
+    }
+
+    /// Retrieve a subscription using its ID.
+    pub async fn get_list_recipient(
+        &self,
+        id: i64,
+    ) -> Result<Response<ListRecipient>, ClientError> {
+        self.execute(&GetListRecipient(id), &NoParams::default())
+            .await
+    }
+
+    /// Add a contact to a list.
+    pub async fn create_list_recipient(
+        &self,
+        recipient: &ListRecipientQuery,
+    ) -> Result<Response<ListRecipient>, ClientError> {
+        self.execute(&CreateListRecipient, recipient).await
+    }
+
+    /// Update the status of a subscription.
+    pub async fn update_list_recipient(
+        &self,
+        id: i64,
+        update: &ListRecipientUpdate,
+    ) -> Result<Response<ListRecipient>, ClientError> {
+        self.execute(&UpdateListRecipient(id), update).await
+    }
+
+    /// Remove a contact from a list. The returned [Response] carries no payload.
+    pub async fn delete_list_recipient(
+        &self,
+        id: i64,
+    ) -> Result<Response<ListRecipient>, ClientError> {
+        self.execute(&DeleteListRecipient(id), &NoParams::default())
+            .await
+    }
+
+    /// Subscribe a contact, given by its ID or its email address, to a list.
+    ///
+    /// # Description
+    ///
+    /// The external API rejects the request when the contact already belongs to the list, even if it unsubscribed
+    /// from it. Use [MailjetClient::update_list_recipient] to subscribe it again in that case.
+    pub async fn subscribe_contact(
+        &self,
+        contact: impl Into<ResourceId>,
+        list_id: i64,
+    ) -> Result<Response<ListRecipient>, ClientError> {
+        let mut recipient = ListRecipientQuery {
+            list_id: Some(list_id),
+            is_unsubscribed: Some(false),
+            ..Default::default()
+        };
+
+        match contact.into() {
+            ResourceId::Id(id) => recipient.contact_id = Some(id),
+            ResourceId::Email(email) => recipient.contact_alt = Some(email),
+        }
+
+        self.create_list_recipient(&recipient).await
+    }
+
+    /// Unsubscribe a contact, given by its ID or its email address, from a list.
+    ///
+    /// # Description
+    ///
+    /// The contact is kept in the list, flagged as unsubscribed. The updated subscriptions are returned, so an empty
+    /// list means that the contact didn't belong to the list.
+    pub async fn unsubscribe_contact(
+        &self,
+        contact: impl Into<ResourceId>,
+        list_id: i64,
+    ) -> Result<Vec<ListRecipient>, ClientError> {
+        let mut filters = ListRecipientFilters {
+            contacts_list: Some(list_id),
+            is_unsubscribed: Some(false),
+            ..Default::default()
+        };
+
+        match contact.into() {
+            ResourceId::Id(id) => filters.contact = Some(id),
+            ResourceId::Email(email) => filters.contact_email = Some(email),
+        }
+
+        let subscriptions = self
+            .list_list_recipients(&filters)
+            .await?
+            .payload
+            .unwrap_or_default();
+        let update = ListRecipientUpdate {
+            is_unsubscribed: Some(true),
+        };
+        let mut updated = Vec::new();
+
+        for subscription in subscriptions {
+            let response = self.update_list_recipient(subscription.id, &update).await?;
+            updated.extend(response.payload.unwrap_or_default());
+        }
+
+        Ok(updated)
+    }
+
+    /// Create or update many contacts at once, and manage their subscriptions to several lists.
+    ///
+    /// # Description
+    ///
+    /// The external API processes the contacts asynchronously, and returns the ID of the job. Use
+    /// [MailjetClient::poll_job] with [GetManyContactsJob] to wait until it finishes.
+    pub async fn manage_many_contacts(
+        &self,
+        query: &ManageContactsQuery,
+    ) -> Result<Response<JobHandle>, ClientError> {
+        self.execute(&ManageManyContacts, query).await
+    }
+
+    /// Retrieve the status of a job started by [MailjetClient::manage_many_contacts].
+    pub async fn get_many_contacts_job(
+        &self,
+        job_id: i64,
+    ) -> Result<Response<ContactsJob>, ClientError> {
+        self.execute(&GetManyContactsJob(job_id), &NoParams::default())
+            .await
+    }
+
+    /// Add, remove or unsubscribe many contacts of a list at once.
+    ///
+    /// # Description
+    ///
+    /// The external API processes the contacts asynchronously, and returns the ID of the job. Use
+    /// [MailjetClient::poll_job] with [GetListContactsJob] to wait until it finishes.
+    pub async fn manage_list_contacts(
+        &self,
+        list_id: i64,
+        query: &ManageListContactsQuery,
+    ) -> Result<Response<JobHandle>, ClientError> {
+        self.execute(&ManageListContacts(list_id), query).await
+    }
+
+    /// Retrieve the status of a job started by [MailjetClient::manage_list_contacts].
+    pub async fn get_list_contacts_job(
+        &self,
+        list_id: i64,
+        job_id: i64,
+    ) -> Result<Response<ContactsJob>, ClientError> {
+        self.execute(
+            &GetListContactsJob { list_id, job_id },
+            &NoParams::default(),
+        )
+        .await
+    }
+
+    /// Upload a CSV file with contacts to the DATA API, to import them later into the list `list_id`.
+    ///
+    /// # Description
+    ///
+    /// The file is streamed from disk, so big files are not loaded in memory. The returned [DataUpload] includes the
+    /// ID to use as `DataID` of [CsvImportQuery] when calling [MailjetClient::create_csv_import].
+    /// Uploads are not retried, as the content of the file can't be sent twice.
+    pub async fn upload_contacts_csv(
+        &self,
+        list_id: i64,
+        path: impl AsRef<Path>,
+    ) -> Result<DataUpload, ClientError> {
+        let file = File::open(path).await.map_err(ClientError::Io)?;
+        let size = file.metadata().await.map_err(ClientError::Io)?.len();
+        debug!("Uploading a CSV file of {size} bytes");
+
+        let response = self
+            .send_data(
+                Method::POST,
+                &contacts_csv_path(list_id),
+                Some((Body::wrap_stream(ReaderStream::new(file)), size)),
+            )
+            .await?;
+
+        Self::parse_data_upload(response).await
+    }
+
+    /// Upload CSV content with contacts to the DATA API, reading it from any asynchronous reader.
+    ///
+    /// # Description
+    ///
+    /// Same as [MailjetClient::upload_contacts_csv], though the size of the content is not known in advance, so the
+    /// body is sent using chunked transfer encoding.
+    pub async fn upload_contacts_csv_from_reader<R>(
+        &self,
+        list_id: i64,
+        reader: R,
+    ) -> Result<DataUpload, ClientError>
+    where
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        let body = Body::wrap_stream(ReaderStream::new(reader));
+        let response = self
+            .send_data(Method::POST, &contacts_csv_path(list_id), Some((body, 0)))
+            .await?;
+
+        Self::parse_data_upload(response).await
+    }
+
+    /// Start the import of contacts uploaded using [MailjetClient::upload_contacts_csv].
+    ///
+    /// # Description
+    ///
+    /// The external API processes the file asynchronously. Use [MailjetClient::poll_job] with [GetCsvImport] to wait
+    /// until it finishes.
+    pub async fn create_csv_import(
+        &self,
+        query: &CsvImportQuery,
+    ) -> Result<Response<CsvImport>, ClientError> {
+        self.execute(&CreateCsvImport, query).await
+    }
+
+    /// Retrieve the status of a CSV import.
+    pub async fn get_csv_import(&self, import_id: i64) -> Result<Response<CsvImport>, ClientError> {
+        self.execute(&GetCsvImport(import_id), &NoParams::default())
+            .await
+    }
+
+    /// Download the contacts of the list `list_id` as a CSV file, and store it at `path`.
+    ///
+    /// # Description
+    ///
+    /// The content is streamed to disk as it is received. An existing file at `path` is overwritten. Returns the
+    /// amount of bytes written.
+    pub async fn download_contacts_csv(
+        &self,
+        list_id: i64,
+        path: impl AsRef<Path>,
+    ) -> Result<u64, ClientError> {
+        let mut file = File::create(path).await.map_err(ClientError::Io)?;
+
+        self.download_contacts_csv_to_writer(list_id, &mut file)
+            .await
+    }
+
+    /// Download the contacts of the list `list_id` as CSV, writing the content to any asynchronous writer.
+    pub async fn download_contacts_csv_to_writer<W>(
+        &self,
+        list_id: i64,
+        writer: &mut W,
+    ) -> Result<u64, ClientError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let response = self
+            .send_data(Method::GET, &contacts_csv_path(list_id), None)
+            .await?;
+
+        Self::stream_to_writer(response, writer).await
+    }
+
+    /// Download the lines of a CSV import that couldn't be imported, writing them to any asynchronous writer.
+    ///
+    /// # Description
+    ///
+    /// Each line includes the original content plus the error found while importing it. Returns the amount of bytes
+    /// written.
+    pub async fn download_csv_import_errors<W>(
+        &self,
+        import_id: i64,
+        writer: &mut W,
+    ) -> Result<u64, ClientError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let response = self
+            .send_data(
+                Method::GET,
+                &ApiUrl::data(&format!("BatchJob/{import_id}/CSVError/text:csv")),
+                None,
+            )
+            .await?;
+
+        Self::stream_to_writer(response, writer).await
+    }
+
+    /// Retrieve a list of senders that match the given filters.
+    pub async fn list_senders(&self, query: &SenderQuery) -> Result<Response<Sender>, ClientError> {
+        self.execute(&ListSenders, query).await
+    }
+
+    /// Retrieve a sender using its ID or its email address.
+    pub async fn get_sender(
+        &self,
+        sender: impl Into<ResourceId>,
+    ) -> Result<Response<Sender>, ClientError> {
+        self.execute(&GetSender(sender.into()), &NoParams::default())
+            .await
+    }
+
+    /// Create a new sender.
+    ///
+    /// # Description
+    ///
+    /// Mailjet sends a validation email to the new address, so the sender won't be active until that validation is
+    /// completed. See [MailjetClient::validate_sender].
+    pub async fn create_sender(
+        &self,
+        sender: &SenderCreate,
+    ) -> Result<Response<Sender>, ClientError> {
+        self.execute(&CreateSender, sender).await
+    }
+
+    /// Delete a sender. The returned [Response] carries no payload.
+    pub async fn delete_sender(
+        &self,
+        sender: impl Into<ResourceId>,
+    ) -> Result<Response<Sender>, ClientError> {
+        self.execute(&DeleteSender(sender.into()), &NoParams::default())
+            .await
+    }
+
+    /// Validate a sender.
+    ///
+    /// # Description
+    ///
+    /// The returned [SenderValidation] includes the errors found during the validation, if any.
+    pub async fn validate_sender(
+        &self,
+        sender: impl Into<ResourceId>,
+    ) -> Result<Response<SenderValidation>, ClientError> {
+        self.execute(&ValidateSender(sender.into()), &NoParams::default())
+            .await
+    }
+
+    /// Check whether an email address is an active sender of the account.
+    ///
+    /// # Description
+    ///
+    /// Use this method to verify that the address used as `From` in your messages is a validated and active sender
+    /// before sending messages. An address that is not registered as a sender is reported as not active.
+    pub async fn is_active_sender(&self, email: &str) -> Result<bool, ClientError> {
+        let query = SenderQuery {
+            email: Some(email.to_string()),
+            ..Default::default()
+        };
+
+        let senders = self.list_senders(&query).await?;
+
+        Ok(senders
+            .payload
+            .unwrap_or_default()
+            .iter()
+            .any(|s| s.email.eq_ignore_ascii_case(email) && s.status == Status::Active))
+    }
+
+    /// Retrieve the list of webhooks (event callback URLs) of the account.
+    pub async fn list_event_callback_urls(
+        &self,
+        filters: &EventCallbackUrlFilters,
+    ) -> Result<Response<EventCallbackUrl>, ClientError> {
+        self.execute(&ListEventCallbackUrls, filters).await
+    }
+
+    /// Retrieve a webhook using its ID.
+    pub async fn get_event_callback_url(
+        &self,
+        id: i64,
+    ) -> Result<Response<EventCallbackUrl>, ClientError> {
+        self.execute(&GetEventCallbackUrl(id), &NoParams::default())
+            .await
+    }
+
+    /// Create a new webhook.
+    ///
+    /// # Description
+    ///
+    /// The external API rejects the request when the account already has a webhook for the same event type and
+    /// backup flag. Use [MailjetClient::ensure_webhooks] to reconcile the configuration instead.
+    pub async fn create_event_callback_url(
+        &self,
+        webhook: &EventCallbackUrlQuery,
+    ) -> Result<Response<EventCallbackUrl>, ClientError> {
+        self.execute(&CreateEventCallbackUrl, webhook).await
+    }
+
+    /// Update a webhook.
+    pub async fn update_event_callback_url(
+        &self,
+        id: i64,
+        update: &EventCallbackUrlQuery,
+    ) -> Result<Response<EventCallbackUrl>, ClientError> {
+        self.execute(&UpdateEventCallbackUrl(id), update).await
+    }
+
+    /// Delete a webhook. The returned [Response] carries no payload.
+    pub async fn delete_event_callback_url(
+        &self,
+        id: i64,
+    ) -> Result<Response<EventCallbackUrl>, ClientError> {
+        self.execute(&DeleteEventCallbackUrl(id), &NoParams::default())
+            .await
+    }
+
+    /// Make the webhooks of the account match the given configuration.
+    ///
+    /// # Description
+    ///
+    /// The current webhooks are retrieved and compared with `desired`: missing webhooks are created, those whose
+    /// URL or grouping differ (or that Mailjet marked as dead) are updated, and those not included in `desired` are
+    /// deleted. Calling this method again with the same configuration issues no changes.
+    ///
+    /// The changes are applied one by one, so an error interrupts the reconciliation and the changes applied until
+    /// then are kept.
+    pub async fn ensure_webhooks(
+        &self,
+        desired: &[WebhookSpec],
+    ) -> Result<WebhookChanges, ClientError> {
+        let current = self
+            .paginate(ListEventCallbackUrls, EventCallbackUrlFilters::default())
+            .collect_all(MAX_PAGE_SIZE as usize)
+            .await?;
+        let plan = webhooks::plan(current, desired)?;
+        let mut changes = WebhookChanges::default();
+
+        for webhook in plan.delete {
+            info!("Deleting the webhook {} ({})", webhook.id, webhook.url);
+            self.delete_event_callback_url(webhook.id).await?;
+            changes.deleted.push(webhook);
+        }
+
+        for (id, spec) in plan.update {
+            info!("Updating the webhook {id} ({})", spec.url);
+            let response = self
+                .update_event_callback_url(id, &EventCallbackUrlQuery::from(&spec))
+                .await?;
+            changes.updated.extend(response.payload.unwrap_or_default());
+        }
+
+        for spec in plan.create {
+            info!(
+                "Creating a webhook for {:?} events ({})",
+                spec.event_type, spec.url
+            );
+            let response = self
+                .create_event_callback_url(&EventCallbackUrlQuery::from(&spec))
+                .await?;
+            changes.created.extend(response.payload.unwrap_or_default());
+        }
+
+        Ok(changes)
+    }
+
+    /// Retrieve a list of the messages sent that match the given filters.
+    pub async fn list_messages(
+        &self,
+        filters: &MessageFilters,
+    ) -> Result<Response<SentMessage>, ClientError> {
+        self.execute(&ListMessages, filters).await
+    }
+
+    /// Retrieve a message sent using its ID.
+    pub async fn get_message(&self, message_id: i64) -> Result<Response<SentMessage>, ClientError> {
+        self.execute(&GetMessage(message_id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve the events (opened, clicked, bounced...) of a message sent.
+    pub async fn get_message_history(
+        &self,
+        message_id: i64,
+    ) -> Result<Response<MessageEvent>, ClientError> {
+        self.execute(&GetMessageHistory(message_id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve the sending information of the messages that match the given filters.
+    pub async fn list_message_information(
+        &self,
+        filters: &MessageFilters,
+    ) -> Result<Response<MessageInformation>, ClientError> {
+        self.execute(&ListMessageInformation, filters).await
+    }
+
+    /// Retrieve the sending information of a message using its ID.
+    pub async fn get_message_information(
+        &self,
+        message_id: i64,
+    ) -> Result<Response<MessageInformation>, ClientError> {
+        self.execute(&GetMessageInformation(message_id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve the status and the events of a message, using the [MessageObject] returned when it was sent.
+    ///
+    /// # Description
+    ///
+    /// Mailjet takes a while to register a message after accepting it, so the external API might reply with a
+    /// [ClientError::NotFound] error when this method is called right after sending the message.
+    pub async fn track_message(
+        &self,
+        message: &MessageObject,
+    ) -> Result<MessageDelivery, ClientError> {
+        let id = message.id().ok_or_else(|| {
+            ClientError::ParseError("The message object doesn't include a message ID".into())
+        })?;
+
+        let message = self
+            .get_message(id)
+            .await?
+            .payload
+            .and_then(|p| p.into_iter().next())
+            .ok_or_else(|| {
+                ClientError::UnknownError("The external API returned no message".into())
+            })?;
+        let history = self
+            .get_message_history(id)
+            .await?
+            .payload
+            .unwrap_or_default();
+
+        Ok(MessageDelivery { message, history })
+    }
+
+    /// Retrieve a list of parse routes (Parse API) that match the given filters.
+    pub async fn list_parse_routes(
+        &self,
+        filters: &ParseRouteFilters,
+    ) -> Result<Response<ParseRoute>, ClientError> {
+        self.execute(&ListParseRoutes, filters).await
+    }
+
+    /// Retrieve a parse route using its ID.
+    pub async fn get_parse_route(&self, id: i64) -> Result<Response<ParseRoute>, ClientError> {
+        self.execute(&GetParseRoute(id), &NoParams::default()).await
+    }
+
+    /// Create a new parse route.
+    ///
+    /// # Description
+    ///
+    /// The emails received at the address of the route are forwarded to its URL. The payload of those requests
+    /// deserializes into a [crate::inbound::InboundEmail].
+    pub async fn create_parse_route(
+        &self,
+        route: &ParseRouteQuery,
+    ) -> Result<Response<ParseRoute>, ClientError> {
+        self.execute(&CreateParseRoute, route).await
+    }
+
+    /// Delete a parse route. The returned [Response] carries no payload.
+    pub async fn delete_parse_route(&self, id: i64) -> Result<Response<ParseRoute>, ClientError> {
+        self.execute(&DeleteParseRoute(id), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve a list of templates that match the given filters.
+    pub async fn list_templates(
+        &self,
+        filters: &TemplateFilters,
+    ) -> Result<Response<Template>, ClientError> {
+        self.execute(&ListTemplates, filters).await
+    }
+
+    /// Retrieve a template using its ID or its `apikey|name` key.
+    pub async fn get_template(
+        &self,
+        template: impl Into<ResourceId>,
+    ) -> Result<Response<Template>, ClientError> {
+        self.execute(&GetTemplate(template.into()), &NoParams::default())
+            .await
+    }
+
+    /// Create a new template.
+    ///
+    /// # Description
+    ///
+    /// The new template has no content. Use [MailjetClient::set_template_content] to set it.
+    pub async fn create_template(
+        &self,
+        template: &TemplateQuery,
+    ) -> Result<Response<Template>, ClientError> {
+        self.execute(&CreateTemplate, template).await
+    }
+
+    /// Update the attributes of a template.
+    pub async fn update_template(
+        &self,
+        template: impl Into<ResourceId>,
+        update: &TemplateQuery,
+    ) -> Result<Response<Template>, ClientError> {
+        self.execute(&UpdateTemplate(template.into()), update).await
+    }
+
+    /// Delete a template. The returned [Response] carries no payload.
+    pub async fn delete_template(
+        &self,
+        template: impl Into<ResourceId>,
+    ) -> Result<Response<Template>, ClientError> {
+        self.execute(&DeleteTemplate(template.into()), &NoParams::default())
+            .await
+    }
+
+    /// Retrieve the content of a template: the HTML and text parts, the MJML content and the headers.
+    pub async fn get_template_content(
+        &self,
+        template: impl Into<ResourceId>,
+    ) -> Result<Response<TemplateContent>, ClientError> {
+        self.execute(&GetTemplateContent(template.into()), &NoParams::default())
+            .await
+    }
+
+    /// Set the content of a template.
+    ///
+    /// # Description
+    ///
+    /// This call creates or overwrites the content of the template. The parts set to `None` are not sent.
+    pub async fn set_template_content(
+        &self,
+        template: impl Into<ResourceId>,
+        content: &TemplateContent,
+    ) -> Result<Response<TemplateContent>, ClientError> {
+        self.execute(&SetTemplateContent(template.into()), content)
+            .await
+    }
+
+    /// Build a [Paginator] to retrieve all the matches of a query to a list (GET) endpoint.
+    ///
+    /// # Description
+    ///
+    /// The list methods of the client (such as [MailjetClient::list_contacts]) return a single page of matches. Use
+    /// this method when the query might match more objects than those returned in a single response.
+    pub fn paginate<'a, E>(&'a self, endpoint: E, request: E::Request) -> Paginator<'a, E>
+    where
+        E: Endpoint<Payload = RestResponse<<E as Endpoint>::Response>> + 'a,
+        E::Request: PaginatedRequest,
+    {
+        Paginator::new(self, endpoint, request)
+    }
+
+    /// Build a [JobPoller] to wait until an asynchronous job of the external API finishes.
+    ///
+    /// # Description
+    ///
+    /// `endpoint` is the endpoint that retrieves the status of the job, e.g. [GetListContactsJob].
+    pub fn poll_job<'a, E>(&'a self, endpoint: E) -> JobPoller<'a, E>
+    where
+        E: Endpoint<Request = NoParams>,
+        E::Response: AsyncJob,
+    {
+        JobPoller::new(self, endpoint)
+    }
+
+    /// Issue a request to an endpoint of the external API.
+    ///
+    /// # Description
+    ///
+    /// This is the generic method used by the rest of the client's member functions. It builds the URL of the
+    /// endpoint, attaches the given parameters (as a query string for GET and DELETE requests, and as a JSON body
+    /// otherwise), sends the request and parses the payload of the response using the types declared by the
+    /// endpoint. See [Endpoint].
+    ///
+    /// Requests that fail due to transient errors are retried according to the client's [RetryPolicy].
+    #[instrument(skip(self))]
+    pub async fn execute<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        params: &E::Request,
+    ) -> Result<Response<E::Response>, ClientError> {
+        let (status_code, payload) = self.execute_raw(endpoint, params).await?;
+
+        Ok(Response {
+            status_code,
+            payload: payload.map(Into::into),
+        })
+    }
+
+    /// Issue a request to an endpoint of the external API, and return the raw payload of the response.
+    ///
+    /// # Description
+    ///
+    /// This is the same as [MailjetClient::execute], but the payload is returned as it is declared by
+    /// [Endpoint::Payload], i.e. without unwrapping the response objects. This is needed by clients of the REST API
+    /// that need the counters included in the response (see [crate::data_objects::RestResponse]).
+    pub(crate) async fn execute_raw<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        params: &E::Request,
+    ) -> Result<(u16, Option<E::Payload>), ClientError> {
+        debug!("Request parameters: {:#?}", params);
+
+        let idempotent = E::METHOD != Method::POST || params.is_deduplicable();
+        let mut attempt = 1;
+
+        loop {
+            match self.execute_once(endpoint, params).await {
+                Ok(response) => return Ok(response),
+                Err(e) => match self.retry_policy.next_delay(attempt, &e, idempotent) {
+                    Some(delay) => {
+                        warn!(
+                            "Attempt {attempt} failed ({e}), retrying in {} ms",
+                            delay.as_millis()
+                        );
+                        sleep(delay).await;
+                        attempt += 1;
+                    }
+                    None => return Err(e),
+                },
+            }
+        }
+    }
+
+    /// Send a request to the DATA API, without retries.
+    ///
+    /// # Description
+    ///
+    /// The DATA API exchanges raw content rather than JSON objects, so these requests don't fit [Endpoint]. The body
+    /// is given along its size, use 0 when the size is unknown.
+    async fn send_data(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<(Body, u64)>,
+    ) -> Result<reqwest::Response, ClientError> {
+        if let Some(limiter) = &self.rate_limiter {
+            let bucket = if method == Method::GET {
+                RateLimitBucket::Read
+            } else {
+                RateLimitBucket::Write
+            };
+            limiter.acquire(bucket).await;
+        }
+
+        let mut request = self
             .http_client
-            .post(format!(
-                "{}/{}",
-                self.api_url,
-                ApiUrl::send(&self.api_version)
-            ))
+            .request(method.clone(), format!("{}/{}", self.api_url, path))
             .basic_auth(
                 self.api_user.expose_secret(),
                 Some(&self.api_key.expose_secret()),
-            )
-            .json(&request_params)
-            .build()
-            .unwrap();
+            );
 
-        debug!("POST request: {:#?}", request);
+        if let Some((body, size)) = body {
+            request = request.header(CONTENT_TYPE, "text/plain").body(body);
+            if size > 0 {
+                request = request.header(reqwest::header::CONTENT_LENGTH, size);
+            }
+        }
 
-        // Send the prepared request to the external API.
-        let raw_response = self
-            .http_client
-            .execute(request)
-            .await
-            .map_err(|e| ClientError::ExternalError(e.to_string()))?;
+        let response = request.send().await.map_err(ClientError::from_transport)?;
+        info!("{method} request to the DATA API executed");
+        debug!("Received response: {:#?}", response);
 
-        debug!("Received response: {:#?}", raw_response);
-        let response_code = raw_response.status().as_u16();
-        let payload = raw_response
+        let response_code = response.status().as_u16();
+        if (200..300).contains(&response_code) {
+            Ok(response)
+        } else {
+            let headers = response.headers().clone();
+            let payload = response
+                .text()
+                .await
+                .map_err(|e| ClientError::from_transport(e.into()))?;
+            let error = ClientError::from_response(response_code, &headers, payload);
+            warn!("The external API returned an error: {error}");
+
+            Err(error)
+        }
+    }
+
+    /// Parse the response of an upload to the DATA API.
+    async fn parse_data_upload(response: reqwest::Response) -> Result<DataUpload, ClientError> {
+        let response_code = response.status().as_u16();
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+        let payload = response
             .text()
             .await
-            .map_err(|e| ClientError::UnknownError(e.to_string()))?;
+            .map_err(|e| ClientError::from_transport(e.into()))?;
         debug!("Response's payload: {:#?}", payload);
 
-        // The POST request was successfully executed.
-        if response_code == 200 {
-            // Temporal struct to implement a deserializer.
-            #[derive(Deserialize, Debug)]
-            #[serde(rename_all = "PascalCase")]
-            #[allow(dead_code)]
-            struct TempResponse {
-                pub messages: Vec<SendResponseObject>,
-            }
+        serde_json::from_str(&payload).map_err(|e| ClientError::Deserialization {
+            status_code: response_code,
+            request_id,
+            source: e,
+        })
+    }
 
-            let mut payload: TempResponse = serde_json::from_str(payload.as_str())
-                .map_err(|e| ClientError::UnknownError(e.to_string()))?;
-            // Cast the internal objects of the array as trait objects.
-            let response = payload
-                .messages
-                .drain(..)
-                .map(|e: SendResponseObject| Box::<dyn ResponseObject>::from(Box::new(e)))
-                .collect();
+    /// Write the body of a response to `writer` as it is received.
+    async fn stream_to_writer<W>(
+        response: reqwest::Response,
+        writer: &mut W,
+    ) -> Result<u64, ClientError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut stream = response.bytes_stream();
+        let mut written = 0;
 
-            Ok(Response {
-                status_code: response_code,
-                payload: Some(response),
-            })
-        } else if response_code == 400 {
-            Err(ClientError::BadRequest(format!(
-                "status_code: {}, payload: {:#?}",
-                response_code, payload
-            )))
-        } else {
-            Err(ClientError::UnknownError(format!(
-                "status_code: {}, payload: {:#?}",
-                response_code, payload
-            )))
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| ClientError::from_transport(e.into()))?;
+            writer.write_all(&chunk).await.map_err(ClientError::Io)?;
+            written += chunk.len() as u64;
         }
-    }
+        writer.flush().await.map_err(ClientError::Io)?;
+        debug!("Downloaded {written} bytes");
 
-    #[instrument]
-    async fn send_email_v3(&self, request: &impl RequestObject) -> Result<Response, ClientError> {
-        debug!("Request parameters: {:#?}", request);
+        Ok(written)
+    }
 
-        // Try to cast the trait object as the expected params object.
-        let request_params: &SimpleMessage = match request.as_any().downcast_ref::<SimpleMessage>()
-        {
-            Some(r) => r,
-            None => {
-                error!("Received wrong parameters for the selected request");
-                return Err(ClientError::BadRequest(
-                    "Wrong parameters for the request".into(),
-                ));
-            }
-        };
+    /// Send a single request to the external API, without retries.
+    async fn execute_once<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        params: &E::Request,
+    ) -> Result<(u16, Option<E::Payload>), ClientError> {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(endpoint.rate_limit_bucket()).await;
+        }
 
         // Build a new request using the HTTP client.
         let request = self
             .http_client
-            .post(format!(
-                "{}/{}",
-                self.api_url,
-                ApiUrl::send(&self.api_version)
-            ))
+            .request(E::METHOD, format!("{}/{}", self.api_url, endpoint.path()))
             .basic_auth(
                 self.api_user.expose_secret(),
                 Some(&self.api_key.expose_secret()),
-            )
-            .json(&request_params)
-            .build()
-            .unwrap();
+            );
+
+        let request = if E::METHOD == Method::GET || E::METHOD == Method::DELETE {
+            request.query(params)
+        } else {
+            request.json(params)
+        }
+        .build()
+        .map_err(|e| {
+            error!("Failed to build the request: {e}");
+            ClientError::HTTPClient(e)
+        })?;
 
-        trace!("POST request: {:#?}", request);
+        trace!("{} request: {:#?}", E::METHOD, request);
 
         // Send the prepared request to the external API.
         let raw_response = self
             .http_client
             .execute(request)
             .await
-            .map_err(|e| ClientError::ExternalError(e.to_string()))?;
-        info!("Send request executed");
+            .map_err(ClientError::from_transport)?;
+        info!("{} request executed", E::METHOD);
         // This would log the main part of the response, the payload needs another iteration.
         debug!("Received response: {:#?}", raw_response);
 
         let response_code = raw_response.status().as_u16();
+        let headers = raw_response.headers().clone();
         let response_payload = raw_response
             .text()
             .await
-            .map_err(|e| ClientError::UnknownError(e.to_string()))?;
+            .map_err(|e| ClientError::from_transport(e.into()))?;
         debug!("Response's payload: {:#?}", response_payload);
 
         // The API docs state that 201 shall be received after a successful POST, however,
-        // I only received 200. Both cases would be acceptable, though:
-        if response_code == 200 || response_code == 201 {
-            // Temporal struct to implement a deserializer using the particular type of
-            // response expected from this endpoint of the external API.
-            #[derive(Deserialize, Debug)]
-            #[serde(rename_all = "PascalCase")]
-            #[allow(dead_code)]
-            struct TempResponse {
-                pub sent: Vec<MessageObject>,
+        // I only received 200 for `/send`. Both cases would be acceptable, though:
+        if (200..300).contains(&response_code) {
+            // Some endpoints (DELETE) don't include a body in the response.
+            if response_payload.trim().is_empty() {
+                return Ok((response_code, None));
             }
 
-            let mut payload: TempResponse = serde_json::from_str(response_payload.as_str())
-                .map_err(|e| ClientError::ParseError(e.to_string()))?;
-            // Cast the internal objects of the array as trait objects.
-            let response = payload
-                .sent
-                .drain(..)
-                .map(|e: MessageObject| Box::<dyn ResponseObject>::from(Box::new(e)))
-                .collect();
+            let payload: E::Payload =
+                serde_json::from_str(response_payload.as_str()).map_err(|e| {
+                    ClientError::Deserialization {
+                        status_code: response_code,
+                        request_id: headers
+                            .get(REQUEST_ID_HEADER)
+                            .and_then(|v| v.to_str().ok())
+                            .map(String::from),
+                        source: e,
+                    }
+                })?;
 
-            // And wrap it as a generic response type.
-            Ok(Response {
-                status_code: response_code,
-                payload: Some(response),
-            })
-        } else if response_code == 400 {
-            Err(ClientError::BadRequest(format!(
-                "status_code: {}, payload: {:#?}",
-                response_code, response_payload
-            )))
+            Ok((response_code, Some(payload)))
         } else {
-            Err(ClientError::UnknownError(format!(
-                "status_code: {}, payload: {:#?}",
-                response_code, response_payload
-            )))
+            let error = ClientError::from_response(response_code, &headers, response_payload);
+            warn!("The external API returned an error: {error}");
+
+            Err(error)
         }
     }
 }
 
+/// Path of the DATA API for the CSV content of a contacts list.
+fn contacts_csv_path(list_id: i64) -> String {
+    ApiUrl::data(&format!("contactslist/{list_id}/CSVData/text:plain"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
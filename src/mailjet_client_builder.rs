@@ -5,7 +5,7 @@
 
 //! Client builder module.
 
-use crate::{ApiVersion, ClientError, MailjetClient};
+use crate::{ApiVersion, ClientError, MailjetClient, RateLimiter, RetryPolicy};
 use secrecy::SecretString;
 
 /// A builder object for [MailjetClient].
@@ -40,6 +40,9 @@ pub struct MailjetClientBuilder {
     api_url: Option<String>,
     api_version: Option<String>,
     force_https: Option<bool>,
+    validation: bool,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl Default for MailjetClientBuilder {
@@ -60,6 +63,9 @@ impl Default for MailjetClientBuilder {
             api_url: Some("https://api.mailjet.com".into()),
             api_version: Some(ApiVersion::default().to_string()),
             force_https: Some(true),
+            validation: false,
+            retry_policy: None,
+            rate_limiter: None,
         }
     }
 }
@@ -113,6 +119,27 @@ impl MailjetClientBuilder {
         self
     }
 
+    /// Validate the messages locally before sending them. See [crate::data_objects::Validate].
+    pub fn with_validation(mut self, validation: bool) -> MailjetClientBuilder {
+        self.validation = validation;
+
+        self
+    }
+
+    /// Retry requests that failed due to transient errors following the given policy. See [RetryPolicy].
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> MailjetClientBuilder {
+        self.retry_policy = Some(policy);
+
+        self
+    }
+
+    /// Limit the rate of requests sent by the client following the given limiter. See [RateLimiter].
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> MailjetClientBuilder {
+        self.rate_limiter = Some(limiter);
+
+        self
+    }
+
     pub fn new(api_user: SecretString, api_key: SecretString) -> MailjetClientBuilder {
         MailjetClientBuilder {
             api_user: Some(api_user),
@@ -123,11 +150,14 @@ impl MailjetClientBuilder {
             api_url: None,
             api_version: None,
             force_https: None,
+            validation: false,
+            retry_policy: None,
+            rate_limiter: None,
         }
     }
 
     pub fn build(self) -> Result<MailjetClient, ClientError> {
-        MailjetClient::new(
+        let mut client = MailjetClient::new(
             self.api_user.unwrap(),
             self.api_key.unwrap(),
             self.email_address.as_deref(),
@@ -136,7 +166,21 @@ impl MailjetClientBuilder {
             self.api_url.as_deref(),
             self.api_version.as_deref(),
             self.force_https,
-        )
+        )?;
+
+        if self.validation {
+            client.enable_validation();
+        }
+
+        if let Some(policy) = self.retry_policy {
+            client.use_retry_policy(policy);
+        }
+
+        if let Some(limiter) = self.rate_limiter {
+            client.use_rate_limiter(limiter);
+        }
+
+        Ok(client)
     }
 }
 
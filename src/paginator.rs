@@ -0,0 +1,161 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Pagination module.
+
+use crate::{
+    data_objects::{PaginatedRequest, RestResponse},
+    endpoints::Endpoint,
+    ClientError, MailjetClient,
+};
+use futures::{stream, Stream, TryStreamExt};
+use tracing::debug;
+
+/// Maximum amount of objects that the REST API returns in a single response.
+pub const MAX_PAGE_SIZE: u16 = 1000;
+
+/// Object that retrieves all the matches of a query to a list (GET) endpoint of the REST API.
+///
+/// # Description
+///
+/// The endpoints of the REST API return the matches of a query in pages (see
+/// [Mailjet REST API responses](crate::data_objects)). This object hides that from the user: it issues as many
+/// requests as needed, updating the `Limit` and `Offset` of the query.
+///
+/// Build a new object using [MailjetClient::paginate], and then:
+/// - Use [Paginator::stream] to get a [Stream] of objects. Pages are fetched lazily, when the items of the previous
+///   one were consumed.
+/// - Use [Paginator::collect_all] to get all the objects at once.
+/// - Use [Paginator::count] to get the amount of matches without retrieving them (`CountOnly=1`).
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # async fn example(client: mailjet_client::MailjetClient) -> Result<(), mailjet_client::ClientError> {
+/// use mailjet_client::{data_objects::ContactFilters, endpoints::ListContacts};
+///
+/// let filters = ContactFilters {
+///     contacts_list: Some(42),
+///     ..Default::default()
+/// };
+///
+/// let total = client.paginate(ListContacts, filters.clone()).count().await?;
+/// let contacts = client
+///     .paginate(ListContacts, filters)
+///     .collect_all(10_000)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Paginator<'a, E: Endpoint> {
+    client: &'a MailjetClient,
+    endpoint: E,
+    request: E::Request,
+    page_size: u16,
+}
+
+impl<'a, E> Paginator<'a, E>
+where
+    E: Endpoint<Payload = RestResponse<<E as Endpoint>::Response>> + 'a,
+    E::Request: PaginatedRequest,
+{
+    /// Constructor. The default page size is the maximum allowed by the external API.
+    pub fn new(client: &'a MailjetClient, endpoint: E, request: E::Request) -> Self {
+        Paginator {
+            client,
+            endpoint,
+            request,
+            page_size: MAX_PAGE_SIZE,
+        }
+    }
+
+    /// Change the amount of objects requested per page, up to [MAX_PAGE_SIZE].
+    pub fn with_page_size(mut self, page_size: u16) -> Self {
+        self.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+
+        self
+    }
+
+    /// Get a stream of the objects that match the query.
+    ///
+    /// # Description
+    ///
+    /// The stream starts at the offset given in the query, and ends when the external API returns a page that is not
+    /// full (or empty). The `Total` of the pages is ignored, as many resources of the REST API report the size of the
+    /// page there rather than the amount of matches. An error interrupts the stream.
+    ///
+    /// `CountOnly` is cleared from the query, as the pages would include no objects otherwise.
+    pub fn stream(mut self) -> impl Stream<Item = Result<E::Response, ClientError>> + 'a {
+        let offset = self.request.offset();
+        self.request.set_count_only(false);
+
+        stream::try_unfold(
+            (self, offset, false),
+            |(mut paginator, offset, done)| async move {
+                if done {
+                    return Ok(None);
+                }
+
+                paginator.request.set_limit(paginator.page_size);
+                paginator.request.set_offset(offset);
+                debug!("Requesting page at offset {offset}");
+
+                let (_, payload) = paginator
+                    .client
+                    .execute_raw(&paginator.endpoint, &paginator.request)
+                    .await?;
+
+                let data = payload.map(|page| page.data).unwrap_or_default();
+
+                let next_offset = offset + data.len() as u32;
+                let done = data.len() < paginator.page_size as usize;
+
+                Ok(Some((data, (paginator, next_offset, done))))
+            },
+        )
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Get all the objects that match the query.
+    ///
+    /// # Description
+    ///
+    /// `max_items` is a safety cap: if the query matches more objects than that, [ClientError::TooManyItems] is
+    /// returned instead of silently truncating the result.
+    pub async fn collect_all(self, max_items: usize) -> Result<Vec<E::Response>, ClientError> {
+        let stream = self.stream();
+        futures::pin_mut!(stream);
+
+        let mut items = Vec::new();
+
+        while let Some(item) = stream.try_next().await? {
+            if items.len() == max_items {
+                return Err(ClientError::TooManyItems(max_items));
+            }
+            items.push(item);
+        }
+
+        Ok(items)
+    }
+
+    /// Get the amount of objects that match the query, without retrieving them.
+    ///
+    /// # Description
+    ///
+    /// The request is sent with `CountOnly=1`, the only case in which the `Total` of the response is the amount of
+    /// matches of the query.
+    pub async fn count(mut self) -> Result<u64, ClientError> {
+        self.request.set_count_only(true);
+
+        let (_, payload) = self
+            .client
+            .execute_raw(&self.endpoint, &self.request)
+            .await?;
+
+        Ok(payload.map(|p| p.total).unwrap_or_default())
+    }
+}
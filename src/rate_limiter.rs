@@ -0,0 +1,312 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Client-side rate limiting module.
+
+use crate::{
+    data_objects::{ValidationErrors, Violation},
+    ClientError,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, PoisonError,
+    },
+    time::Duration,
+};
+use tokio::time::{sleep, Instant};
+use tracing::debug;
+
+/// Upper bound of a single wait for a token. The bucket is checked again after waiting, so very low rates are still
+/// honored.
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// Maximum rate of requests allowed by a token bucket.
+///
+/// # Description
+///
+/// The bucket is refilled at `requests_per_second`, and it holds up to `burst` tokens. Each request consumes a token,
+/// so up to `burst` requests can be sent at once after a quiet period.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    requests_per_second: f64,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Constructor.
+    ///
+    /// # Description
+    ///
+    /// `burst` is at least 1. [ClientError::Validation] is returned when `requests_per_second` is not a finite
+    /// number greater than 0.
+    pub fn new(requests_per_second: f64, burst: u32) -> Result<Self, ClientError> {
+        if !(requests_per_second.is_finite() && requests_per_second > 0.0) {
+            return Err(ClientError::Validation(ValidationErrors {
+                violations: vec![Violation {
+                    field: "RequestsPerSecond".into(),
+                    message: format!(
+                        "the rate of requests must be a finite number greater than 0 (got {requests_per_second})"
+                    ),
+                }],
+            }));
+        }
+
+        Ok(RateLimit {
+            requests_per_second,
+            burst: burst.max(1),
+        })
+    }
+}
+
+/// Groups of endpoints that can be rate limited separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitBucket {
+    /// Endpoints that send emails (`/send`).
+    Send,
+    /// REST endpoints that read data (GET).
+    Read,
+    /// REST endpoints that modify data (POST, PUT and DELETE).
+    Write,
+}
+
+/// Snapshot of the metrics collected by a [RateLimiter].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RateLimitMetrics {
+    /// Amount of requests that went through the limiter.
+    pub requests: u64,
+    /// Amount of requests that had to wait for a token.
+    pub throttled_requests: u64,
+    /// Accumulated time spent waiting for tokens.
+    pub total_wait: Duration,
+}
+
+/// Client-side rate limiter based on token buckets.
+///
+/// # Description
+///
+/// Mailjet throttles accounts that exceed their API quotas. This object keeps the requests issued by a client within
+/// the given limits: when no token is available, the request waits (asynchronously) until the bucket is refilled,
+/// rather than failing.
+///
+/// A default limit applies to all the requests. Groups of endpoints (see [RateLimitBucket]) can get their own limit,
+/// in which case their requests only consume tokens from that bucket. All the calls issued by a shared
+/// [crate::MailjetClient] respect the same buckets.
+///
+/// ## Example
+///
+/// ```rust
+/// use mailjet_client::{MailjetClientBuilder, RateLimit, RateLimitBucket, RateLimiter};
+///
+/// # fn main() -> Result<(), mailjet_client::ClientError> {
+/// let limiter = RateLimiter::new(RateLimit::new(10.0, 20)?)
+///     .with_bucket(RateLimitBucket::Send, RateLimit::new(2.0, 5)?);
+///
+/// let client = MailjetClientBuilder::default()
+///     .with_rate_limiter(limiter)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    default: Option<TokenBucket>,
+    buckets: HashMap<RateLimitBucket, TokenBucket>,
+    requests: AtomicU64,
+    throttled_requests: AtomicU64,
+    wait_nanos: AtomicU64,
+}
+
+impl RateLimiter {
+    /// Build a new limiter that applies `limit` to all the requests.
+    ///
+    /// Use [RateLimiter::default] to build a limiter with no default limit.
+    pub fn new(limit: RateLimit) -> Self {
+        RateLimiter {
+            default: Some(TokenBucket::new(limit)),
+            ..Default::default()
+        }
+    }
+
+    /// Use a separate bucket for a group of endpoints.
+    pub fn with_bucket(mut self, bucket: RateLimitBucket, limit: RateLimit) -> Self {
+        self.buckets.insert(bucket, TokenBucket::new(limit));
+
+        self
+    }
+
+    /// Wait until a request targeting the given bucket is allowed.
+    pub(crate) async fn acquire(&self, bucket: RateLimitBucket) {
+        let Some(token_bucket) = self.buckets.get(&bucket).or(self.default.as_ref()) else {
+            return;
+        };
+
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let start = Instant::now();
+        let mut throttled = false;
+
+        while let Some(wait) = token_bucket.try_acquire() {
+            throttled = true;
+            debug!(
+                "Rate limit reached for {bucket:?}, waiting {} ms",
+                wait.as_millis()
+            );
+            sleep(wait).await;
+        }
+
+        if throttled {
+            self.throttled_requests.fetch_add(1, Ordering::Relaxed);
+            self.wait_nanos
+                .fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+        }
+    }
+
+    /// Metrics collected since the limiter was built.
+    pub fn metrics(&self) -> RateLimitMetrics {
+        RateLimitMetrics {
+            requests: self.requests.load(Ordering::Relaxed),
+            throttled_requests: self.throttled_requests.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// A token bucket, refilled continuously.
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit) -> Self {
+        TokenBucket {
+            limit,
+            state: Mutex::new(BucketState {
+                tokens: limit.burst as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Take a token from the bucket.
+    ///
+    /// ## Returns
+    ///
+    /// - `None` when a token was taken.
+    /// - `Some(wait)` with the time until the next token is available otherwise.
+    fn try_acquire(&self) -> Option<Duration> {
+        // The state is updated at once, so it is consistent even if the lock was poisoned.
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens =
+            (state.tokens + elapsed * self.limit.requests_per_second).min(self.limit.burst as f64);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            None
+        } else {
+            let wait = (1.0 - state.tokens) / self.limit.requests_per_second;
+
+            Some(
+                Duration::try_from_secs_f64(wait)
+                    .unwrap_or(MAX_WAIT)
+                    .min(MAX_WAIT),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn burst_is_not_throttled() {
+        let limiter = RateLimiter::new(RateLimit::new(1.0, 3).unwrap());
+
+        for _ in 0..3 {
+            limiter.acquire(RateLimitBucket::Send).await;
+        }
+
+        let metrics = limiter.metrics();
+        assert_eq!(metrics.requests, 3);
+        assert_eq!(metrics.throttled_requests, 0);
+        assert_eq!(metrics.total_wait, Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_wait_for_tokens() {
+        let limiter = RateLimiter::new(RateLimit::new(2.0, 1).unwrap());
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            limiter.acquire(RateLimitBucket::Read).await;
+        }
+
+        // 1 token available at start, and 4 more refilled at 2 tokens per second.
+        assert!(start.elapsed() >= Duration::from_secs(2));
+        let metrics = limiter.metrics();
+        assert_eq!(metrics.requests, 5);
+        assert_eq!(metrics.throttled_requests, 4);
+        assert!(metrics.total_wait >= Duration::from_secs(2));
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(-1.0)]
+    #[case(f64::NAN)]
+    #[case(f64::INFINITY)]
+    fn invalid_rates_are_rejected(#[case] requests_per_second: f64) {
+        let result = RateLimit::new(requests_per_second, 1);
+
+        assert!(
+            matches!(result, Err(ClientError::Validation(e)) if e.contains("RequestsPerSecond"))
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tiny_rate_waits_are_capped() {
+        let limiter = RateLimiter::new(RateLimit::new(1e-300, 1).unwrap());
+
+        limiter.acquire(RateLimitBucket::Read).await;
+        // The next token takes ages to be refilled, so the request keeps waiting instead of panicking.
+        let second = tokio::time::timeout(
+            Duration::from_secs(3600),
+            limiter.acquire(RateLimitBucket::Read),
+        )
+        .await;
+
+        assert!(second.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn buckets_are_independent() {
+        let limiter = RateLimiter::default()
+            .with_bucket(RateLimitBucket::Send, RateLimit::new(1.0, 1).unwrap());
+
+        limiter.acquire(RateLimitBucket::Send).await;
+        // Requests out of the configured bucket are not limited.
+        for _ in 0..10 {
+            limiter.acquire(RateLimitBucket::Read).await;
+        }
+        assert_eq!(limiter.metrics().requests, 1);
+
+        limiter.acquire(RateLimitBucket::Send).await;
+        assert_eq!(limiter.metrics().throttled_requests, 1);
+    }
+}
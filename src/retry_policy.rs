@@ -0,0 +1,323 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Retry policy module.
+
+use crate::ClientError;
+use rand::Rng;
+use std::time::Duration;
+
+/// Policy that controls how the client retries requests that failed due to transient errors.
+///
+/// # Description
+///
+/// Mailjet's API might reject a request because too many requests were sent (status code 429), or because of a
+/// transient issue in their servers (5xx status codes). This object describes how many times and how often the client
+/// shall retry such requests. The delay between attempts grows exponentially, and some random jitter is added to it
+/// to avoid many clients retrying at the same time. When the external API includes the header `Retry-After` in the
+/// response, that value is honored instead.
+///
+/// ## Safety of the retries
+///
+/// Sending an email twice is worse than not sending it at all, so the client only retries a request when it is safe
+/// to do it:
+/// - The request demonstrably didn't reach Mailjet: the connection couldn't be established, or Mailjet rejected it
+///   due to rate limiting (status code 429).
+/// - The request is idempotent: GET, PUT and DELETE requests, and POST requests whose request object allows
+///   deduplication on Mailjet's side (see [crate::data_objects::RequestObject::is_deduplicable]). For example, a
+///   [crate::data_objects::SendEmailParams] whose messages include a `CustomID`.
+///
+/// Any other failure is returned straight away to the caller.
+///
+/// ## Example
+///
+/// ```rust
+/// use mailjet_client::{MailjetClientBuilder, RetryPolicy};
+/// use secrecy::SecretString;
+/// use std::time::Duration;
+///
+/// let client = MailjetClientBuilder::default()
+///     .with_api_user(SecretString::from("A hash".to_string()))
+///     .with_api_key(SecretString::from("A hash".to_string()))
+///     .with_retry_policy(
+///         RetryPolicy::default()
+///             .with_max_attempts(5)
+///             .with_backoff(Duration::from_millis(200), Duration::from_secs(10)),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    honor_retry_after: bool,
+    retryable_status_codes: Vec<u16>,
+    retry_on_timeout: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Default retry policy.
+    ///
+    /// # Description
+    ///
+    /// Up to 3 attempts, with an initial delay of 500 ms that doubles on every attempt up to 30 s. Jitter is applied,
+    /// `Retry-After` is honored, and the status codes 429, 500, 502, 503 and 504 are considered transient.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            honor_retry_after: true,
+            retryable_status_codes: Vec::from([429, 500, 502, 503, 504]),
+            retry_on_timeout: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries a request. This is the policy used by a client unless other is given.
+    pub fn disabled() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Maximum amount of attempts for a request, including the first one.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+
+        self
+    }
+
+    /// Initial delay between attempts and the upper bound for it.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+
+        self
+    }
+
+    /// Enable or disable the random jitter applied to the delay between attempts.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Enable or disable honoring the header `Retry-After`.
+    ///
+    /// # Description
+    ///
+    /// When enabled, the delay given by the external API replaces the computed backoff. If that delay exceeds the
+    /// maximum backoff of the policy, the request is not retried.
+    pub fn with_retry_after(mut self, honor: bool) -> Self {
+        self.honor_retry_after = honor;
+
+        self
+    }
+
+    /// Status codes that are considered transient errors.
+    pub fn with_retryable_status_codes(mut self, codes: &[u16]) -> Self {
+        self.retryable_status_codes = codes.to_vec();
+
+        self
+    }
+
+    /// Enable or disable retrying idempotent requests that timed out.
+    pub fn with_retry_on_timeout(mut self, retry: bool) -> Self {
+        self.retry_on_timeout = retry;
+
+        self
+    }
+
+    /// Maximum amount of attempts for a request.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Compute the delay before the next attempt.
+    ///
+    /// # Description
+    ///
+    /// `attempt` is the number of the attempt that failed (starting at 1) and `idempotent` tells whether the request
+    /// can be sent again without side effects.
+    ///
+    /// ## Returns
+    ///
+    /// - `Some(delay)` when the request shall be retried after waiting for `delay`.
+    /// - `None` when the error shall be returned to the caller.
+    pub(crate) fn next_delay(
+        &self,
+        attempt: u32,
+        error: &ClientError,
+        idempotent: bool,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error, idempotent) {
+            return None;
+        }
+
+        if let ClientError::RateLimited {
+            retry_after: Some(delay),
+            ..
+        } = error
+        {
+            if self.honor_retry_after {
+                return (*delay <= self.max_backoff).then_some(*delay);
+            }
+        }
+
+        Some(self.backoff(attempt))
+    }
+
+    /// Check whether an error is transient, and the request can be sent again.
+    fn is_retryable(&self, error: &ClientError, idempotent: bool) -> bool {
+        match error {
+            // The request didn't reach the external API.
+            ClientError::Transport(e) if e.is_connect() => true,
+            // The external API rejected the request without processing it.
+            ClientError::RateLimited { .. } => self.retryable_status_codes.contains(&429),
+            ClientError::Timeout(_) => idempotent && self.retry_on_timeout,
+            ClientError::Transport(_) => idempotent,
+            e => {
+                idempotent
+                    && e.details()
+                        .is_some_and(|d| self.retryable_status_codes.contains(&d.status_code))
+            }
+        }
+    }
+
+    /// Exponential backoff for the given attempt, with equal jitter if enabled.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let half = delay / 2;
+            half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+        } else {
+            delay
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApiErrorDetails;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    fn server_error(status_code: u16) -> ClientError {
+        ClientError::ServerError(Box::new(ApiErrorDetails {
+            status_code,
+            ..Default::default()
+        }))
+    }
+
+    fn rate_limited(retry_after: Option<Duration>) -> ClientError {
+        ClientError::RateLimited {
+            retry_after,
+            details: Box::new(ApiErrorDetails {
+                status_code: 429,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[fixture]
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .with_max_attempts(4)
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(350))
+            .with_jitter(false)
+    }
+
+    #[rstest]
+    fn backoff_grows_exponentially(policy: RetryPolicy) {
+        let error = server_error(503);
+
+        assert_eq!(
+            policy.next_delay(1, &error, true),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.next_delay(2, &error, true),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.next_delay(3, &error, true),
+            Some(Duration::from_millis(350))
+        );
+        // The maximum amount of attempts was reached.
+        assert_eq!(policy.next_delay(4, &error, true), None);
+    }
+
+    #[rstest]
+    fn jitter_stays_within_bounds(policy: RetryPolicy) {
+        let policy = policy.with_jitter(true);
+
+        for _ in 0..50 {
+            let delay = policy.next_delay(2, &server_error(500), true).unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[rstest]
+    fn non_idempotent_requests_are_not_retried(policy: RetryPolicy) {
+        assert_eq!(policy.next_delay(1, &server_error(500), false), None);
+        // Rate limited requests never reached Mailjet's processing logic.
+        assert_eq!(
+            policy.next_delay(1, &rate_limited(None), false),
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    #[rstest]
+    fn retry_after_is_honored(policy: RetryPolicy) {
+        let delay = Duration::from_millis(300);
+        assert_eq!(
+            policy.next_delay(1, &rate_limited(Some(delay)), true),
+            Some(delay)
+        );
+        // Too long to wait.
+        assert_eq!(
+            policy.next_delay(1, &rate_limited(Some(Duration::from_secs(5))), true),
+            None
+        );
+        // Ignore the header.
+        let policy = policy.with_retry_after(false);
+        assert_eq!(
+            policy.next_delay(1, &rate_limited(Some(Duration::from_secs(5))), true),
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    #[rstest]
+    fn only_configured_status_codes_are_retried(policy: RetryPolicy) {
+        let policy = policy.with_retryable_status_codes(&[503]);
+
+        assert!(policy.next_delay(1, &server_error(503), true).is_some());
+        assert!(policy.next_delay(1, &server_error(500), true).is_none());
+        assert!(policy.next_delay(1, &rate_limited(None), true).is_none());
+        assert!(policy
+            .next_delay(1, &ClientError::BadRequest(Default::default()), true)
+            .is_none());
+    }
+
+    #[rstest]
+    fn disabled_policy_never_retries() {
+        let policy = RetryPolicy::disabled();
+
+        assert_eq!(policy.max_attempts(), 1);
+        assert_eq!(policy.next_delay(1, &rate_limited(None), true), None);
+    }
+}
@@ -0,0 +1,733 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Builder and validator for the expressions of the segmentation filters (`/contactfilter`).
+//!
+//! # Description
+//!
+//! A segmentation filter selects the contacts whose properties match an expression, e.g.
+//! `(age<35) AND (gender="female")`. The expressions combine comparisons of contact properties with literals,
+//! function calls (such as the date functions `IsInPreviousDays` and `IsInPreviousHours`), and the logical
+//! operators `AND`, `OR` and `NOT`.
+//!
+//! [Expression] builds such strings from typed values, so literals are always well formed. The expressions, whether
+//! built or written by hand, are checked using [validate_expression], which the client calls before creating or
+//! updating a filter (see [crate::MailjetClient::create_contact_filter]). Use [try_property] to check the name of a
+//! property when it is referenced instead.
+//! See [Segmentation](https://dev.mailjet.com/email/guides/contact-management/#segmentation).
+//!
+//! ## Example
+//!
+//! ```
+//! use mailjet_client::segmentation::{property, validate_expression};
+//!
+//! let expression = property("age")
+//!     .lt(35)
+//!     .and(property("gender").eq("female"))
+//!     .and(property("last_purchase").in_previous_days(30).or(property("vip").eq(true)));
+//!
+//! assert_eq!(
+//!     expression.to_string(),
+//!     r#"(age<35) AND (gender="female") AND (IsInPreviousDays(last_purchase,30) OR (vip=true))"#
+//! );
+//! assert!(validate_expression(&expression.to_string()).is_ok());
+//! assert!(validate_expression("(age<35) AND").is_err());
+//! ```
+
+use crate::{
+    data_objects::{ContactFilterQuery, Validate, ValidationErrors, Violation},
+    ClientError,
+};
+use std::{fmt, ops::Not};
+
+/// Comparison operators of the segmentation syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let op = match self {
+            Operator::Eq => "=",
+            Operator::Ne => "<>",
+            Operator::Lt => "<",
+            Operator::Le => "<=",
+            Operator::Gt => ">",
+            Operator::Ge => ">=",
+        };
+
+        write!(f, "{op}")
+    }
+}
+
+/// Operand of an expression: a contact property or a literal value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Property(String),
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Property(name) => write!(f, "{name}"),
+            Operand::Str(value) => write!(
+                f,
+                "\"{}\"",
+                value.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+            Operand::Int(value) => write!(f, "{value}"),
+            Operand::Float(value) => write!(f, "{value}"),
+            Operand::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for Operand {
+    fn from(value: &str) -> Self {
+        Operand::Str(value.into())
+    }
+}
+
+impl From<String> for Operand {
+    fn from(value: String) -> Self {
+        Operand::Str(value)
+    }
+}
+
+impl From<i64> for Operand {
+    fn from(value: i64) -> Self {
+        Operand::Int(value)
+    }
+}
+
+impl From<i32> for Operand {
+    fn from(value: i32) -> Self {
+        Operand::Int(value.into())
+    }
+}
+
+impl From<u32> for Operand {
+    fn from(value: u32) -> Self {
+        Operand::Int(value.into())
+    }
+}
+
+/// Floats are checked, as the segmentation syntax has no literal for NaN or infinity.
+impl TryFrom<f64> for Operand {
+    type Error = ClientError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_finite() {
+            Ok(Operand::Float(value))
+        } else {
+            Err(validation_error(
+                "Expression",
+                format!("the value {value} is not a finite number"),
+            ))
+        }
+    }
+}
+
+impl From<bool> for Operand {
+    fn from(value: bool) -> Self {
+        Operand::Bool(value)
+    }
+}
+
+impl From<Property> for Operand {
+    fn from(value: Property) -> Self {
+        Operand::Property(value.0)
+    }
+}
+
+/// A contact property, used to start a comparison. Build it using [property].
+#[derive(Debug, Clone)]
+pub struct Property(String);
+
+/// Reference a contact property by its name.
+///
+/// # Description
+///
+/// The name is not checked: an invalid one makes [validate_expression] reject the expression. Use [try_property] to
+/// check it straight away.
+pub fn property(name: &str) -> Property {
+    Property(name.into())
+}
+
+/// Reference a contact property by its name, checking that it can be written in an expression.
+///
+/// # Description
+///
+/// [ClientError::Validation] is returned when the name is not an identifier: letters, digits and `_`, not starting
+/// with a digit, other than the keywords `AND`, `OR` and `NOT`.
+pub fn try_property(name: &str) -> Result<Property, ClientError> {
+    match tokenize(name).as_deref() {
+        Ok([(_, Token::Ident(ident))]) if ident == name => Ok(property(name)),
+        _ => Err(validation_error(
+            "Expression",
+            format!("\"{name}\" is not a valid property name"),
+        )),
+    }
+}
+
+impl Property {
+    fn compare(self, op: Operator, value: impl Into<Operand>) -> Expression {
+        Expression::Compare {
+            property: self.0,
+            op,
+            value: value.into(),
+        }
+    }
+
+    pub fn eq(self, value: impl Into<Operand>) -> Expression {
+        self.compare(Operator::Eq, value)
+    }
+
+    pub fn ne(self, value: impl Into<Operand>) -> Expression {
+        self.compare(Operator::Ne, value)
+    }
+
+    pub fn lt(self, value: impl Into<Operand>) -> Expression {
+        self.compare(Operator::Lt, value)
+    }
+
+    pub fn le(self, value: impl Into<Operand>) -> Expression {
+        self.compare(Operator::Le, value)
+    }
+
+    pub fn gt(self, value: impl Into<Operand>) -> Expression {
+        self.compare(Operator::Gt, value)
+    }
+
+    pub fn ge(self, value: impl Into<Operand>) -> Expression {
+        self.compare(Operator::Ge, value)
+    }
+
+    /// The date of the property is within the last `days` days.
+    pub fn in_previous_days(self, days: u32) -> Expression {
+        Expression::call("IsInPreviousDays", [Operand::Property(self.0), days.into()])
+    }
+
+    /// The date of the property is within the last `hours` hours.
+    pub fn in_previous_hours(self, hours: u32) -> Expression {
+        Expression::call(
+            "IsInPreviousHours",
+            [Operand::Property(self.0), hours.into()],
+        )
+    }
+}
+
+/// Expression of a segmentation filter.
+///
+/// # Description
+///
+/// The [fmt::Display] implementation renders the expression using the segmentation syntax. Comparisons are enclosed
+/// in parentheses, as are the nested groups of `AND` and `OR`, so the precedence of the operators never matters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Compare {
+        property: String,
+        op: Operator,
+        value: Operand,
+    },
+    Call {
+        function: String,
+        args: Vec<Operand>,
+    },
+    And(Vec<Expression>),
+    Or(Vec<Expression>),
+    Not(Box<Expression>),
+}
+
+impl Expression {
+    /// Call a function of the segmentation syntax, e.g. a date function not covered by [Property].
+    pub fn call(function: &str, args: impl IntoIterator<Item = Operand>) -> Self {
+        Expression::Call {
+            function: function.into(),
+            args: args.into_iter().collect(),
+        }
+    }
+
+    /// Both expressions match.
+    pub fn and(self, other: Expression) -> Self {
+        match self {
+            Expression::And(mut all) => {
+                all.push(other);
+                Expression::And(all)
+            }
+            this => Expression::And(vec![this, other]),
+        }
+    }
+
+    /// Any of the expressions match.
+    pub fn or(self, other: Expression) -> Self {
+        match self {
+            Expression::Or(mut any) => {
+                any.push(other);
+                Expression::Or(any)
+            }
+            this => Expression::Or(vec![this, other]),
+        }
+    }
+
+    /// Render the expression, enclosing it in parentheses when it is a group.
+    fn fmt_operand(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expression::And(_) | Expression::Or(_) => write!(f, "({self})"),
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+impl Not for Expression {
+    type Output = Expression;
+
+    fn not(self) -> Self::Output {
+        Expression::Not(Box::new(self))
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (separator, expressions) = match self {
+            Expression::Compare {
+                property,
+                op,
+                value,
+            } => return write!(f, "({property}{op}{value})"),
+            Expression::Call { function, args } => {
+                let args: Vec<_> = args.iter().map(Operand::to_string).collect();
+                return write!(f, "{function}({})", args.join(","));
+            }
+            Expression::Not(expression) => {
+                write!(f, "NOT ")?;
+                return expression.fmt_operand(f);
+            }
+            Expression::And(expressions) => (" AND ", expressions),
+            Expression::Or(expressions) => (" OR ", expressions),
+        };
+
+        for (i, expression) in expressions.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{separator}")?;
+            }
+            expression.fmt_operand(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Check the syntax of an expression of a segmentation filter.
+///
+/// # Description
+///
+/// The grammar accepted is:
+/// - Comparisons of two operands using `=`, `<>`, `!=`, `<`, `<=`, `>` or `>=`.
+/// - Operands: property names, numbers, strings enclosed in double quotes and function calls.
+/// - Function calls on their own, e.g. `IsInPreviousDays(last_purchase,30)`.
+/// - Groups enclosed in parentheses, combined using `AND`, `OR` and `NOT` (in any case).
+///
+/// Only the syntax is checked: whether the properties and functions exist is checked by the external API. The
+/// violation reports the position (in characters) where the first error was found.
+pub fn validate_expression(expression: &str) -> Result<(), ValidationErrors> {
+    let tokens = tokenize(expression).map_err(syntax_error)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: expression.chars().count(),
+    };
+
+    parser.expression().map_err(syntax_error)?;
+    match parser.peek() {
+        None => Ok(()),
+        Some((pos, token)) => Err(syntax_error((*pos, format!("unexpected {token}")))),
+    }
+}
+
+impl Validate for ContactFilterQuery {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut violations = Vec::new();
+
+        if self.name.trim().is_empty() {
+            violations.push(Violation {
+                field: "Name".into(),
+                message: "the name of the filter can't be empty".into(),
+            });
+        }
+
+        if let Err(errors) = validate_expression(&self.expression) {
+            violations.extend(errors.violations);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors { violations })
+        }
+    }
+}
+
+fn validation_error(field: &str, message: String) -> ClientError {
+    ClientError::Validation(ValidationErrors {
+        violations: vec![Violation {
+            field: field.into(),
+            message,
+        }],
+    })
+}
+
+fn syntax_error((pos, message): (usize, String)) -> ValidationErrors {
+    ValidationErrors {
+        violations: vec![Violation {
+            field: "Expression".into(),
+            message: format!("syntax error at position {pos}: {message}"),
+        }],
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number,
+    Str,
+    Op,
+    LParen,
+    RParen,
+    Comma,
+    And,
+    Or,
+    Not,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "'{name}'"),
+            Token::Number => write!(f, "number"),
+            Token::Str => write!(f, "string"),
+            Token::Op => write!(f, "operator"),
+            Token::LParen => write!(f, "'('"),
+            Token::RParen => write!(f, "')'"),
+            Token::Comma => write!(f, "','"),
+            Token::And => write!(f, "AND"),
+            Token::Or => write!(f, "OR"),
+            Token::Not => write!(f, "NOT"),
+        }
+    }
+}
+
+type SyntaxError = (usize, String);
+
+fn tokenize(expression: &str) -> Result<Vec<(usize, Token)>, SyntaxError> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+
+        let token = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '=' => Token::Op,
+            '<' | '>' | '!' => {
+                if chars.get(i + 1) == Some(&'=') || (c == '<' && chars.get(i + 1) == Some(&'>')) {
+                    i += 1;
+                } else if c == '!' {
+                    return Err((start, "expected '!='".into()));
+                }
+                Token::Op
+            }
+            '"' => {
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                if i >= chars.len() {
+                    return Err((start, "unterminated string".into()));
+                }
+                Token::Str
+            }
+            c if c.is_ascii_digit()
+                || (c == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit)) =>
+            {
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push((start, Token::Number));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let token = match word.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Ident(word),
+                };
+                tokens.push((start, token));
+                continue;
+            }
+            c => return Err((start, format!("unexpected character '{c}'"))),
+        };
+
+        tokens.push((start, token));
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser of the segmentation syntax. It only checks the syntax, so nothing is built.
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    /// Position of the end of the expression.
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next_is(&self, token: &Token) -> bool {
+        self.peek().is_some_and(|(_, t)| t == token)
+    }
+
+    fn error(&self, expected: &str) -> SyntaxError {
+        match self.peek() {
+            Some((pos, token)) => (*pos, format!("expected {expected}, found {token}")),
+            None => (
+                self.end,
+                format!("expected {expected}, found the end of the expression"),
+            ),
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<(), SyntaxError> {
+        if self.next_is(token) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&token.to_string()))
+        }
+    }
+
+    /// expression := term (OR term)*
+    fn expression(&mut self) -> Result<(), SyntaxError> {
+        self.term()?;
+        while self.next_is(&Token::Or) {
+            self.pos += 1;
+            self.term()?;
+        }
+
+        Ok(())
+    }
+
+    /// term := factor (AND factor)*
+    fn term(&mut self) -> Result<(), SyntaxError> {
+        self.factor()?;
+        while self.next_is(&Token::And) {
+            self.pos += 1;
+            self.factor()?;
+        }
+
+        Ok(())
+    }
+
+    /// factor := NOT factor | '(' expression ')' | comparison | call
+    fn factor(&mut self) -> Result<(), SyntaxError> {
+        if self.next_is(&Token::Not) {
+            self.pos += 1;
+            return self.factor();
+        }
+
+        if self.next_is(&Token::LParen) {
+            self.pos += 1;
+            self.expression()?;
+            return self.expect(&Token::RParen);
+        }
+
+        let is_call = self.operand()?;
+        if self.next_is(&Token::Op) {
+            self.pos += 1;
+            self.operand()?;
+        } else if !is_call {
+            return Err(self.error("a comparison operator"));
+        }
+
+        Ok(())
+    }
+
+    /// operand := identifier | identifier '(' [operand (',' operand)*] ')' | number | string
+    ///
+    /// Returns whether the operand is a function call.
+    fn operand(&mut self) -> Result<bool, SyntaxError> {
+        match self.peek() {
+            Some((_, Token::Number | Token::Str)) => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some((_, Token::Ident(_))) => {
+                self.pos += 1;
+                if !self.next_is(&Token::LParen) {
+                    return Ok(false);
+                }
+
+                self.pos += 1;
+                if !self.next_is(&Token::RParen) {
+                    self.operand()?;
+                    while self.next_is(&Token::Comma) {
+                        self.pos += 1;
+                        self.operand()?;
+                    }
+                }
+                self.expect(&Token::RParen)?;
+
+                Ok(true)
+            }
+            _ => Err(self.error("a property, a value or a function")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn build_expressions() {
+        let expression = property("age")
+            .ge(18)
+            .and(property("age").lt(Operand::try_from(35.5).unwrap()));
+        assert_eq!(expression.to_string(), "(age>=18) AND (age<35.5)");
+
+        let expression = !property("country")
+            .eq("FR")
+            .or(property("country").ne("ES"));
+        assert_eq!(
+            expression.to_string(),
+            r#"NOT ((country="FR") OR (country<>"ES"))"#
+        );
+
+        let expression = property("nickname").eq(r#"The "Ace""#);
+        assert_eq!(expression.to_string(), r#"(nickname="The \"Ace\"")"#);
+
+        let expression = property("last_login")
+            .in_previous_hours(12)
+            .and(Expression::call(
+                "IsInPreviousDays",
+                [property("signup").into(), 7.into()],
+            ));
+        assert_eq!(
+            expression.to_string(),
+            "IsInPreviousHours(last_login,12) AND IsInPreviousDays(signup,7)"
+        );
+    }
+
+    #[rstest]
+    #[case("first name")]
+    #[case("age)")]
+    #[case("1st")]
+    #[case("")]
+    #[case("or")]
+    fn invalid_property_names(#[case] name: &str) {
+        assert!(matches!(
+            try_property(name),
+            Err(ClientError::Validation(e)) if e.contains("Expression")
+        ));
+        // Names that aren't checked make the expression invalid.
+        assert!(validate_expression(&property(name).eq(1).to_string()).is_err());
+    }
+
+    #[rstest]
+    #[case(f64::NAN)]
+    #[case(f64::INFINITY)]
+    #[case(f64::NEG_INFINITY)]
+    fn non_finite_floats(#[case] value: f64) {
+        assert!(matches!(
+            Operand::try_from(value),
+            Err(ClientError::Validation(_))
+        ));
+    }
+
+    #[rstest]
+    fn valid_property_names() {
+        assert!(try_property("last_purchase").is_ok());
+        // The blank space around a name is not part of it.
+        assert!(try_property(" last_purchase").is_err());
+    }
+
+    #[rstest]
+    #[case(r#"(age<35) AND (gender="female")"#)]
+    #[case(r#"age >= 18 and not (country != "FR" OR vip=true)"#)]
+    #[case("IsInPreviousDays(last_purchase,30)")]
+    #[case("(balance>-10.5)")]
+    #[case(r#"(name="say \"hi\"")"#)]
+    fn valid_expressions(#[case] expression: &str) {
+        assert_eq!(validate_expression(expression), Ok(()));
+    }
+
+    #[rstest]
+    #[case("", 0)]
+    #[case("(age<35) AND", 12)]
+    #[case("(age<35", 7)]
+    #[case("(age<35))", 8)]
+    #[case("age", 3)]
+    #[case(r#"(name="pilot)"#, 6)]
+    #[case("age ! 3", 4)]
+    #[case("age < 3 # 4", 8)]
+    #[case("IsInPreviousDays(last_purchase,", 31)]
+    fn invalid_expressions(#[case] expression: &str, #[case] position: usize) {
+        let errors =
+            validate_expression(expression).expect_err("The expression should be rejected");
+
+        assert!(errors.contains("Expression"));
+        assert!(
+            errors.violations[0]
+                .message
+                .starts_with(&format!("syntax error at position {position}:")),
+            "{}",
+            errors.violations[0].message
+        );
+    }
+
+    #[rstest]
+    fn validate_query() {
+        let query = ContactFilterQuery::new("", "(age<35");
+
+        let errors = query.validate().expect_err("The query should be rejected");
+
+        assert!(errors.contains("Name"));
+        assert!(errors.contains("Expression"));
+        assert!(ContactFilterQuery::new("Young", property("age").lt(35))
+            .validate()
+            .is_ok());
+    }
+}
@@ -0,0 +1,1160 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Offline renderer for Mailjet's template language.
+
+use crate::data_objects::{Message, NameAndEmail, Variables};
+use serde_json::Value;
+use std::{cmp::Ordering, fmt};
+
+/// Kind of the errors found when rendering a template.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateErrorKind {
+    /// A variable without default value is not defined, e.g. `{{var:name}}`.
+    UndefinedVariable(String),
+    /// The template is malformed, e.g. a `{% if %}` without `{% endif %}`.
+    Syntax(String),
+    /// A value can't be used as requested, e.g. a loop over a string, or an unknown filter.
+    InvalidValue(String),
+}
+
+impl fmt::Display for TemplateErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TemplateErrorKind::UndefinedVariable(name) => {
+                write!(f, "undefined variable \"{name}\"")
+            }
+            TemplateErrorKind::Syntax(message) => write!(f, "syntax error: {message}"),
+            TemplateErrorKind::InvalidValue(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// Error found when rendering a template.
+///
+/// # Description
+///
+/// Errors are reported like the external API does when `TemplateErrorReporting` is enabled: the part of the message
+/// (`Subject`, `TextPart` or `HTMLPart`) and the line of the template where the error was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateError {
+    /// Part of the message that includes the template, if any.
+    pub part: Option<&'static str>,
+    /// Line of the template (starting at 1).
+    pub line: usize,
+    pub kind: TemplateErrorKind,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.part {
+            Some(part) => write!(f, "{part}, line {}: {}", self.line, self.kind),
+            None => write!(f, "line {}: {}", self.line, self.kind),
+        }
+    }
+}
+
+/// Result of rendering a single template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rendered {
+    /// Rendered text. It is empty when the template has syntax errors.
+    pub output: String,
+    pub errors: Vec<TemplateError>,
+}
+
+/// Result of rendering the parts of a [Message].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedMessage {
+    pub subject: Option<String>,
+    pub text_part: Option<String>,
+    pub html_part: Option<String>,
+    pub errors: Vec<TemplateError>,
+}
+
+impl RenderedMessage {
+    /// Whether the templates of the message were rendered without errors.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Renderer for [Mailjet's template language][tl], used to preview and test templates without calling the external
+/// API.
+///
+/// # Description
+///
+/// The following subset of the language is supported:
+/// - Variables of the message: `{{var:name}}`, with a default value: `{{var:name:"default"}}`. Nested values are
+///   accessed using dots: `{{var:order.total}}`.
+/// - Contact properties: `{{data:firstname:"customer"}}`. Use [TemplateRenderer::with_contact_data] to set them.
+/// - Conditions: `{% if var:count > 1 %}`, `{% elseif ... %}`, `{% else %}` and `{% endif %}`. Comparison
+///   operators (`==`, `!=`, `<`, `<=`, `>`, `>=`), logical operators (`and`, `or`, `not`) and parentheses are allowed.
+/// - Loops over arrays: `{% for item in var:items %}{{item.name}}{% endfor %}`.
+/// - Filters: `{{var:name|upper}}`. Supported filters are `upper`, `lower`, `capitalize`, `title`, `trim`, `length`
+///   and `escape`.
+///
+/// Undefined variables without a default value are rendered as an empty string and reported as errors, as the
+/// external API does.
+///
+/// ## Example
+///
+/// ```
+/// use mailjet_client::{data_objects::MessageBuilder, TemplateRenderer};
+///
+/// let message = MessageBuilder::default()
+///     .with_from("pilot@mailjet.com", None)
+///     .with_subject("Welcome {{var:name:\"passenger\"}}!")
+///     .with_text_body("{% for stop in var:stops %}{{stop|upper}} {% endfor %}")
+///     .with_variable("stops", vec!["cph", "mad"])
+///     .build()
+///     .unwrap();
+///
+/// let rendered = TemplateRenderer::default().render_message(&message);
+///
+/// assert!(rendered.is_valid());
+/// assert_eq!(rendered.subject.as_deref(), Some("Welcome passenger!"));
+/// assert_eq!(rendered.text_part.as_deref(), Some("CPH MAD "));
+/// ```
+///
+/// [tl]: https://documentation.mailjet.com/hc/en-us/articles/360042138873-Template-Language
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRenderer {
+    contact_data: Variables,
+}
+
+impl TemplateRenderer {
+    /// Set the properties of the contact, used by `{{data:...}}`.
+    pub fn with_contact_data(mut self, contact_data: Variables) -> Self {
+        self.contact_data = contact_data;
+
+        self
+    }
+
+    /// Render a template using the given variables (`{{var:...}}`).
+    pub fn render(&self, template: &str, variables: &Variables) -> Rendered {
+        self.render_part(template, variables, None)
+    }
+
+    /// Render the subject, text and HTML parts of a message using its variables.
+    ///
+    /// # Description
+    ///
+    /// The `Globals` of a request are not considered, use
+    /// [SendEmailParams::effective_messages](crate::data_objects::SendEmailParams::effective_messages) to merge them
+    /// into the messages first.
+    pub fn render_message(&self, message: &Message) -> RenderedMessage {
+        let variables = message.variables.clone().unwrap_or_default();
+
+        self.render_message_with(message, &variables)
+    }
+
+    /// Render a message for a recipient. The variables of the recipient (`Vars`) override those of the message.
+    pub fn render_message_for(
+        &self,
+        message: &Message,
+        recipient: &NameAndEmail,
+    ) -> RenderedMessage {
+        let mut variables = message.variables.clone().unwrap_or_default();
+        variables.extend(recipient.vars.clone().unwrap_or_default());
+
+        self.render_message_with(message, &variables)
+    }
+
+    fn render_message_with(&self, message: &Message, variables: &Variables) -> RenderedMessage {
+        let mut errors = Vec::new();
+        let mut render = |part: &Option<String>, name| {
+            part.as_deref().map(|template| {
+                let rendered = self.render_part(template, variables, Some(name));
+                errors.extend(rendered.errors);
+                rendered.output
+            })
+        };
+
+        RenderedMessage {
+            subject: render(&message.subject, "Subject"),
+            text_part: render(&message.text_part, "TextPart"),
+            html_part: render(&message.html_part, "HTMLPart"),
+            errors,
+        }
+    }
+
+    fn render_part(
+        &self,
+        template: &str,
+        variables: &Variables,
+        part: Option<&'static str>,
+    ) -> Rendered {
+        let nodes = match parse(template) {
+            Ok(nodes) => nodes,
+            Err((line, message)) => {
+                return Rendered {
+                    output: String::new(),
+                    errors: vec![TemplateError {
+                        part,
+                        line,
+                        kind: TemplateErrorKind::Syntax(message),
+                    }],
+                }
+            }
+        };
+
+        let mut context = Context {
+            variables,
+            contact_data: &self.contact_data,
+            locals: Vec::new(),
+            part,
+            errors: Vec::new(),
+        };
+        let mut output = String::new();
+        context.render(&nodes, &mut output);
+
+        Rendered {
+            output,
+            errors: context.errors,
+        }
+    }
+}
+
+/// Source of the value of a variable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scope {
+    /// `var:`: variables of the message.
+    Var,
+    /// `data:`: properties of the contact.
+    Data,
+    /// Variables defined by a loop.
+    Local,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Literal(Value),
+    Var {
+        scope: Scope,
+        path: Vec<String>,
+        default: Option<Value>,
+    },
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Cmp(CmpOp, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Output {
+        expr: Expr,
+        filters: Vec<String>,
+        line: usize,
+    },
+    If {
+        branches: Vec<(Expr, Vec<Node>, usize)>,
+        otherwise: Vec<Node>,
+    },
+    For {
+        name: String,
+        iterable: Expr,
+        body: Vec<Node>,
+        line: usize,
+    },
+}
+
+/// Syntax error: line and description.
+type SyntaxError = (usize, String);
+
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Output(String),
+    Tag(String),
+}
+
+/// Split a template into text, outputs (`{{ }}`) and tags (`{% %}`), with the line where they start.
+fn tokenize(template: &str) -> Result<Vec<(Token, usize)>, SyntaxError> {
+    let mut tokens = Vec::new();
+    let mut rest = template;
+    let mut line = 1;
+
+    loop {
+        let start = match (rest.find("{{"), rest.find("{%")) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => {
+                if !rest.is_empty() {
+                    tokens.push((Token::Text(rest.into()), line));
+                }
+                return Ok(tokens);
+            }
+        };
+
+        if start > 0 {
+            tokens.push((Token::Text(rest[..start].into()), line));
+            line += rest[..start].matches('\n').count();
+        }
+
+        let is_output = rest[start..].starts_with("{{");
+        let (open, close) = if is_output {
+            ("{{", "}}")
+        } else {
+            ("{%", "%}")
+        };
+        let content_start = start + open.len();
+        let Some(length) = rest[content_start..].find(close) else {
+            return Err((line, format!("unclosed \"{open}\"")));
+        };
+        let content = rest[content_start..content_start + length].trim();
+
+        tokens.push((
+            if is_output {
+                Token::Output(content.into())
+            } else {
+                Token::Tag(content.into())
+            },
+            line,
+        ));
+        let end = content_start + length + close.len();
+        line += rest[start..end].matches('\n').count();
+        rest = &rest[end..];
+    }
+}
+
+fn parse(template: &str) -> Result<Vec<Node>, SyntaxError> {
+    let mut parser = Parser {
+        tokens: tokenize(template)?.into_iter(),
+    };
+
+    match parser.nodes(&[])? {
+        (nodes, None) => Ok(nodes),
+        (_, Some((keyword, _, line))) => Err((line, format!("unexpected \"{{% {keyword} %}}\""))),
+    }
+}
+
+struct Parser {
+    tokens: std::vec::IntoIter<(Token, usize)>,
+}
+
+/// Tag that ends a block: keyword, rest of the tag and line.
+type EndTag = (String, String, usize);
+
+impl Parser {
+    /// Parse nodes until one of the given tags (or the end of the template) is found.
+    fn nodes(&mut self, end: &[&str]) -> Result<(Vec<Node>, Option<EndTag>), SyntaxError> {
+        let mut nodes = Vec::new();
+
+        while let Some((token, line)) = self.tokens.next() {
+            match token {
+                Token::Text(text) => nodes.push(Node::Text(text)),
+                Token::Output(content) => {
+                    let (expr, filters) = parse_output(&content).map_err(|e| (line, e))?;
+                    nodes.push(Node::Output {
+                        expr,
+                        filters,
+                        line,
+                    });
+                }
+                Token::Tag(content) => {
+                    let (keyword, rest) = content
+                        .split_once(char::is_whitespace)
+                        .map(|(k, r)| (k.to_string(), r.trim().to_string()))
+                        .unwrap_or((content.clone(), String::new()));
+
+                    if end.contains(&keyword.as_str()) {
+                        return Ok((nodes, Some((keyword, rest, line))));
+                    }
+
+                    match keyword.as_str() {
+                        "if" => nodes.push(self.if_block(&rest, line)?),
+                        "for" => nodes.push(self.for_block(&rest, line)?),
+                        _ => return Err((line, format!("unexpected \"{{% {keyword} %}}\""))),
+                    }
+                }
+            }
+        }
+
+        Ok((nodes, None))
+    }
+
+    fn if_block(&mut self, condition: &str, line: usize) -> Result<Node, SyntaxError> {
+        let mut branches = Vec::new();
+        let mut condition = (parse_condition(condition).map_err(|e| (line, e))?, line);
+
+        loop {
+            let (body, end) = self.nodes(&["elseif", "elsif", "else", "endif"])?;
+            branches.push((condition.0, body, condition.1));
+
+            match end {
+                None => return Err((line, "missing \"{% endif %}\"".into())),
+                Some((keyword, _, _)) if keyword == "endif" => {
+                    return Ok(Node::If {
+                        branches,
+                        otherwise: Vec::new(),
+                    })
+                }
+                Some((keyword, _, else_line)) if keyword == "else" => {
+                    return match self.nodes(&["endif"])? {
+                        (otherwise, Some(_)) => Ok(Node::If {
+                            branches,
+                            otherwise,
+                        }),
+                        (_, None) => Err((else_line, "missing \"{% endif %}\"".into())),
+                    };
+                }
+                Some((_, rest, elseif_line)) => {
+                    condition = (
+                        parse_condition(&rest).map_err(|e| (elseif_line, e))?,
+                        elseif_line,
+                    );
+                }
+            }
+        }
+    }
+
+    fn for_block(&mut self, content: &str, line: usize) -> Result<Node, SyntaxError> {
+        let mut parts = content.splitn(3, char::is_whitespace);
+        let (Some(name), Some("in"), Some(iterable)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err((line, "expected \"{% for <name> in <variable> %}\"".into()));
+        };
+        let iterable = parse_condition(iterable).map_err(|e| (line, e))?;
+
+        match self.nodes(&["endfor"])? {
+            (body, Some(_)) => Ok(Node::For {
+                name: name.into(),
+                iterable,
+                body,
+                line,
+            }),
+            (_, None) => Err((line, "missing \"{% endfor %}\"".into())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ExprToken {
+    Word(String),
+    Literal(Value),
+    Var {
+        scope: Scope,
+        path: String,
+        default: Option<Value>,
+    },
+    Op(CmpOp),
+    LParen,
+    RParen,
+    Pipe,
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || c == '-'
+}
+
+fn lex_string(chars: &[char], i: &mut usize) -> Result<String, String> {
+    let quote = chars[*i];
+    let mut value = String::new();
+    *i += 1;
+
+    while *i < chars.len() {
+        match chars[*i] {
+            '\\' if *i + 1 < chars.len() => {
+                value.push(chars[*i + 1]);
+                *i += 2;
+            }
+            c if c == quote => {
+                *i += 1;
+                return Ok(value);
+            }
+            c => {
+                value.push(c);
+                *i += 1;
+            }
+        }
+    }
+
+    Err("unclosed string".into())
+}
+
+fn parse_number(text: &str) -> Option<Value> {
+    text.parse::<i64>()
+        .map(Value::from)
+        .ok()
+        .or_else(|| text.parse::<f64>().ok().map(Value::from))
+}
+
+fn lex(expression: &str) -> Result<Vec<ExprToken>, String> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    let read_while = |i: &mut usize, f: &dyn Fn(char) -> bool| -> String {
+        let start = *i;
+        while *i < chars.len() && f(chars[*i]) {
+            *i += 1;
+        }
+        chars[start..*i].iter().collect()
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(ExprToken::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(ExprToken::RParen);
+                i += 1;
+            }
+            '|' => {
+                tokens.push(ExprToken::Pipe);
+                i += 1;
+            }
+            '"' | '\'' => tokens.push(ExprToken::Literal(Value::String(lex_string(
+                &chars, &mut i,
+            )?))),
+            '=' | '!' | '<' | '>' => {
+                let op = match (c, next) {
+                    ('=', Some('=')) => CmpOp::Eq,
+                    ('!', Some('=')) => CmpOp::Ne,
+                    ('<', Some('=')) => CmpOp::Le,
+                    ('>', Some('=')) => CmpOp::Ge,
+                    ('<', _) => CmpOp::Lt,
+                    ('>', _) => CmpOp::Gt,
+                    _ => return Err(format!("unknown operator \"{c}\"")),
+                };
+                i += if matches!(op, CmpOp::Lt | CmpOp::Gt) {
+                    1
+                } else {
+                    2
+                };
+                tokens.push(ExprToken::Op(op));
+            }
+            c if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                i += 1;
+                let number = format!(
+                    "{c}{}",
+                    read_while(&mut i, &|c| c.is_ascii_digit() || c == '.')
+                );
+                tokens.push(ExprToken::Literal(
+                    parse_number(&number).ok_or(format!("invalid number \"{number}\""))?,
+                ));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let word = read_while(&mut i, &is_name_char);
+                let scope = match word.as_str() {
+                    "var" if next_is(&chars, i, ':') => Scope::Var,
+                    "data" if next_is(&chars, i, ':') => Scope::Data,
+                    _ => {
+                        tokens.push(ExprToken::Word(word));
+                        continue;
+                    }
+                };
+
+                i += 1;
+                let path = read_while(&mut i, &is_name_char);
+                if path.is_empty() {
+                    return Err(format!(
+                        "missing the name of the variable after \"{word}:\""
+                    ));
+                }
+
+                let default = if next_is(&chars, i, ':') {
+                    i += 1;
+                    Some(match chars.get(i) {
+                        Some('"' | '\'') => Value::String(lex_string(&chars, &mut i)?),
+                        _ => {
+                            let text =
+                                read_while(&mut i, &|c| !c.is_whitespace() && c != '|' && c != ')');
+                            match text.as_str() {
+                                "true" => Value::Bool(true),
+                                "false" => Value::Bool(false),
+                                "null" => Value::Null,
+                                _ => parse_number(&text).unwrap_or(Value::String(text)),
+                            }
+                        }
+                    })
+                } else {
+                    None
+                };
+
+                tokens.push(ExprToken::Var {
+                    scope,
+                    path,
+                    default,
+                });
+            }
+            c => return Err(format!("unexpected character '{c}'")),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn next_is(chars: &[char], i: usize, c: char) -> bool {
+    chars.get(i) == Some(&c)
+}
+
+/// Parser of expressions, using the following grammar:
+///
+/// ```text
+/// or      := and ("or" and)*
+/// and     := not ("and" not)*
+/// not     := "not" not | cmp
+/// cmp     := primary (op primary)?
+/// primary := literal | variable | "(" or ")"
+/// ```
+struct ExprParser {
+    tokens: Vec<ExprToken>,
+    pos: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> Option<&ExprToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<ExprToken> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(ExprToken::Word(w)) if w == word)
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.and()?;
+        while self.is_word("or") {
+            self.pos += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.not()?;
+        while self.is_word("and") {
+            self.pos += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr, String> {
+        if self.is_word("not") {
+            self.pos += 1;
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.cmp()
+    }
+
+    fn cmp(&mut self) -> Result<Expr, String> {
+        let left = self.primary()?;
+
+        match self.peek() {
+            Some(ExprToken::Op(op)) => {
+                let op = *op;
+                self.pos += 1;
+                Ok(Expr::Cmp(op, Box::new(left), Box::new(self.primary()?)))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(ExprToken::Literal(value)) => Ok(Expr::Literal(value)),
+            Some(ExprToken::Var {
+                scope,
+                path,
+                default,
+            }) => Ok(Expr::Var {
+                scope,
+                path: path.split('.').map(String::from).collect(),
+                default,
+            }),
+            Some(ExprToken::Word(word)) => match word.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                "null" => Ok(Expr::Literal(Value::Null)),
+                "and" | "or" | "not" | "in" => Err(format!("unexpected \"{word}\"")),
+                _ => Ok(Expr::Var {
+                    scope: Scope::Local,
+                    path: word.split('.').map(String::from).collect(),
+                    default: None,
+                }),
+            },
+            Some(ExprToken::LParen) => {
+                let expr = self.or()?;
+                match self.next() {
+                    Some(ExprToken::RParen) => Ok(expr),
+                    _ => Err("missing \")\"".into()),
+                }
+            }
+            Some(token) => Err(format!("unexpected {token:?}")),
+            None => Err("missing expression".into()),
+        }
+    }
+}
+
+fn parse_condition(expression: &str) -> Result<Expr, String> {
+    let mut parser = ExprParser {
+        tokens: lex(expression)?,
+        pos: 0,
+    };
+    let expr = parser.or()?;
+
+    match parser.peek() {
+        None => Ok(expr),
+        Some(token) => Err(format!("unexpected {token:?}")),
+    }
+}
+
+fn parse_output(expression: &str) -> Result<(Expr, Vec<String>), String> {
+    let mut parser = ExprParser {
+        tokens: lex(expression)?,
+        pos: 0,
+    };
+    let expr = parser.or()?;
+    let mut filters = Vec::new();
+
+    while let Some(token) = parser.next() {
+        match (token, parser.next()) {
+            (ExprToken::Pipe, Some(ExprToken::Word(filter))) => filters.push(filter),
+            (ExprToken::Pipe, _) => return Err("missing the name of the filter".into()),
+            (token, _) => return Err(format!("unexpected {token:?}")),
+        }
+    }
+
+    Ok((expr, filters))
+}
+
+struct Context<'a> {
+    variables: &'a Variables,
+    contact_data: &'a Variables,
+    locals: Vec<(String, Value)>,
+    part: Option<&'static str>,
+    errors: Vec<TemplateError>,
+}
+
+impl Context<'_> {
+    fn error(&mut self, line: usize, kind: TemplateErrorKind) {
+        self.errors.push(TemplateError {
+            part: self.part,
+            line,
+            kind,
+        });
+    }
+
+    fn render(&mut self, nodes: &[Node], output: &mut String) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => output.push_str(text),
+                Node::Output {
+                    expr,
+                    filters,
+                    line,
+                } => {
+                    let mut value = self.eval(expr, *line);
+                    for filter in filters {
+                        value = match apply_filter(filter, value) {
+                            Ok(value) => value,
+                            Err(e) => {
+                                self.error(*line, TemplateErrorKind::InvalidValue(e));
+                                Value::Null
+                            }
+                        };
+                    }
+                    output.push_str(&display(&value));
+                }
+                Node::If {
+                    branches,
+                    otherwise,
+                } => {
+                    let branch = branches
+                        .iter()
+                        .find(|(condition, _, line)| is_truthy(&self.eval(condition, *line)))
+                        .map(|(_, body, _)| body)
+                        .unwrap_or(otherwise);
+                    self.render(branch, output);
+                }
+                Node::For {
+                    name,
+                    iterable,
+                    body,
+                    line,
+                } => match self.eval(iterable, *line) {
+                    Value::Array(items) => {
+                        for item in items {
+                            self.locals.push((name.clone(), item));
+                            self.render(body, output);
+                            self.locals.pop();
+                        }
+                    }
+                    // Undefined variables were already reported.
+                    Value::Null => (),
+                    other => self.error(
+                        *line,
+                        TemplateErrorKind::InvalidValue(format!("can't loop over {other}")),
+                    ),
+                },
+            }
+        }
+    }
+
+    fn eval(&mut self, expr: &Expr, line: usize) -> Value {
+        match expr {
+            Expr::Literal(value) => value.clone(),
+            Expr::Var {
+                scope,
+                path,
+                default,
+            } => {
+                let root = match scope {
+                    Scope::Var => self.variables.get(&path[0]),
+                    Scope::Data => self.contact_data.get(&path[0]),
+                    Scope::Local => self
+                        .locals
+                        .iter()
+                        .rev()
+                        .find(|(name, _)| *name == path[0])
+                        .map(|(_, value)| value),
+                };
+                let value = root.and_then(|root| {
+                    path[1..].iter().try_fold(root, |value, key| match value {
+                        Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+                        value => value.get(key),
+                    })
+                });
+
+                match (value, default) {
+                    (Some(value), _) => value.clone(),
+                    (None, Some(default)) => default.clone(),
+                    (None, None) => {
+                        let prefix = match scope {
+                            Scope::Var => "var:",
+                            Scope::Data => "data:",
+                            Scope::Local => "",
+                        };
+                        self.error(
+                            line,
+                            TemplateErrorKind::UndefinedVariable(format!(
+                                "{prefix}{}",
+                                path.join(".")
+                            )),
+                        );
+                        Value::Null
+                    }
+                }
+            }
+            Expr::Not(expr) => Value::Bool(!is_truthy(&self.eval(expr, line))),
+            Expr::And(left, right) => {
+                Value::Bool(is_truthy(&self.eval(left, line)) && is_truthy(&self.eval(right, line)))
+            }
+            Expr::Or(left, right) => {
+                Value::Bool(is_truthy(&self.eval(left, line)) || is_truthy(&self.eval(right, line)))
+            }
+            Expr::Cmp(op, left, right) => {
+                let (left, right) = (self.eval(left, line), self.eval(right, line));
+
+                Value::Bool(match op {
+                    CmpOp::Eq => equals(&left, &right),
+                    CmpOp::Ne => !equals(&left, &right),
+                    CmpOp::Lt => compare(&left, &right) == Some(Ordering::Less),
+                    CmpOp::Le => matches!(
+                        compare(&left, &right),
+                        Some(Ordering::Less | Ordering::Equal)
+                    ),
+                    CmpOp::Gt => compare(&left, &right) == Some(Ordering::Greater),
+                    CmpOp::Ge => {
+                        matches!(
+                            compare(&left, &right),
+                            Some(Ordering::Greater | Ordering::Equal)
+                        )
+                    }
+                })
+            }
+        }
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Numbers (and strings holding numbers) are compared by value, the rest by their JSON representation.
+fn equals(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(_), _) | (_, Value::Number(_)) => {
+            match (as_number(left), as_number(right)) {
+                (Some(l), Some(r)) => l == r,
+                _ => false,
+            }
+        }
+        _ => left == right,
+    }
+}
+
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+        _ => as_number(left)?.partial_cmp(&as_number(right)?),
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn apply_filter(filter: &str, value: Value) -> Result<Value, String> {
+    let text = display(&value);
+
+    Ok(Value::from(match filter {
+        "upper" => text.to_uppercase(),
+        "lower" => text.to_lowercase(),
+        "capitalize" => capitalize(&text),
+        "title" => text
+            .split(' ')
+            .map(capitalize)
+            .collect::<Vec<_>>()
+            .join(" "),
+        "trim" => text.trim().to_string(),
+        "length" => {
+            return Ok(Value::from(match &value {
+                Value::Array(a) => a.len(),
+                Value::Object(o) => o.len(),
+                _ => text.chars().count(),
+            }))
+        }
+        "escape" => text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;"),
+        _ => return Err(format!("unknown filter \"{filter}\"")),
+    }))
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_objects::{to_variables, MessageBuilder};
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+    use serde_json::json;
+
+    #[fixture]
+    fn variables() -> Variables {
+        to_variables(&json!({
+            "name": "Jane",
+            "count": 3,
+            "vip": true,
+            "order": {"total": 42.5, "items": [{"name": "seat"}, {"name": "meal"}]},
+            "stops": ["cph", "mad"],
+        }))
+        .unwrap()
+    }
+
+    fn render(template: &str, variables: &Variables) -> Rendered {
+        TemplateRenderer::default()
+            .with_contact_data(to_variables(&json!({"firstname": "Janet"})).unwrap())
+            .render(template, variables)
+    }
+
+    #[rstest]
+    #[case("Hello {{var:name}}!", "Hello Jane!")]
+    #[case("Hello {{ var:name }}!", "Hello Jane!")]
+    #[case("Hello {{var:nickname:\"friend\"}}!", "Hello friend!")]
+    #[case("Hello {{var:nickname:friend}}!", "Hello friend!")]
+    #[case("Hello {{data:firstname}}!", "Hello Janet!")]
+    #[case("Total: {{var:order.total}}", "Total: 42.5")]
+    #[case("First: {{var:order.items.0.name}}", "First: seat")]
+    #[case("{{var:name|upper}} {{var:name|lower}}", "JANE jane")]
+    #[case("{{var:stops|length}} {{var:name|length}}", "2 4")]
+    #[case("{{'fly me away'|title}}", "Fly Me Away")]
+    #[case("{{'<b>'|escape}}", "&lt;b&gt;")]
+    #[case("{% if var:vip %}VIP{% endif %}", "VIP")]
+    #[case(
+        "{% if var:count > 5 %}many{% elseif var:count >= 3 %}some{% else %}few{% endif %}",
+        "some"
+    )]
+    #[case("{% if var:count == 1 %}one{% else %}other{% endif %}", "other")]
+    #[case(
+        "{% if var:name == \"Jane\" and not var:missing:false %}yes{% endif %}",
+        "yes"
+    )]
+    #[case(
+        "{% if (var:count < 1 or var:vip) and var:name != 'Bob' %}yes{% endif %}",
+        "yes"
+    )]
+    #[case("{% for stop in var:stops %}{{stop|upper}} {% endfor %}", "CPH MAD ")]
+    #[case(
+        "{% for item in var:order.items %}{% if item.name == 'meal' %}[{{item.name}}]{% endif %}{% endfor %}",
+        "[meal]"
+    )]
+    fn templates_are_rendered(
+        variables: Variables,
+        #[case] template: &str,
+        #[case] expected: &str,
+    ) {
+        let rendered = render(template, &variables);
+
+        assert_eq!(rendered.errors, Vec::new());
+        assert_eq!(rendered.output, expected);
+    }
+
+    #[rstest]
+    fn undefined_variables_are_reported(variables: Variables) {
+        let rendered = render(
+            "Hello {{var:name}},\nyour seat: {{var:seat}}\n{{data:city}}",
+            &variables,
+        );
+
+        assert_eq!(rendered.output, "Hello Jane,\nyour seat: \n");
+        assert_eq!(
+            rendered.errors,
+            vec![
+                TemplateError {
+                    part: None,
+                    line: 2,
+                    kind: TemplateErrorKind::UndefinedVariable("var:seat".into()),
+                },
+                TemplateError {
+                    part: None,
+                    line: 3,
+                    kind: TemplateErrorKind::UndefinedVariable("data:city".into()),
+                },
+            ]
+        );
+        assert_eq!(
+            rendered.errors[0].to_string(),
+            "line 2: undefined variable \"var:seat\""
+        );
+    }
+
+    #[rstest]
+    fn lines_after_multiline_tokens(variables: Variables) {
+        // The blank space around the content of a token counts too.
+        let rendered = render("{{\nvar:a\n}}\n{{var:missing}}", &variables);
+
+        let lines: Vec<_> = rendered.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![1, 4]);
+    }
+
+    #[rstest]
+    #[case("Hello {{var:name", 1)]
+    #[case("line\n{% if var:vip %}VIP", 2)]
+    #[case("{% endif %}", 1)]
+    #[case("{% for var:stops %}{% endfor %}", 1)]
+    #[case("{% if var:count > %}{% endif %}", 1)]
+    #[case("{{var:name|}}", 1)]
+    #[case("{% if var:vip %}\n{% else %}\n{% endfor %}", 3)]
+    fn syntax_errors_are_reported(
+        variables: Variables,
+        #[case] template: &str,
+        #[case] line: usize,
+    ) {
+        let rendered = render(template, &variables);
+
+        assert_eq!(rendered.output, "");
+        assert_eq!(rendered.errors.len(), 1);
+        assert_eq!(rendered.errors[0].line, line);
+        assert!(matches!(
+            rendered.errors[0].kind,
+            TemplateErrorKind::Syntax(_)
+        ));
+    }
+
+    #[rstest]
+    fn invalid_values_are_reported(variables: Variables) {
+        let rendered = render(
+            "{% for c in var:name %}{% endfor %}{{var:name|reverse}}",
+            &variables,
+        );
+
+        assert_eq!(rendered.errors.len(), 2);
+        assert!(rendered
+            .errors
+            .iter()
+            .all(|e| matches!(e.kind, TemplateErrorKind::InvalidValue(_))));
+    }
+
+    #[rstest]
+    fn message_is_rendered() {
+        let message = MessageBuilder::default()
+            .with_from("pilot@mailjet.com", None)
+            .add_to("passenger@mailjet.com", None)
+            .with_subject("Welcome {{var:name}}")
+            .with_text_body("Your seat: {{var:seat}}")
+            .with_html_body("<p>{{var:name}}</p>")
+            .with_variable("name", "Jane")
+            .build()
+            .unwrap();
+
+        let rendered = TemplateRenderer::default().render_message(&message);
+
+        assert_eq!(rendered.subject.as_deref(), Some("Welcome Jane"));
+        assert_eq!(rendered.html_part.as_deref(), Some("<p>Jane</p>"));
+        assert!(!rendered.is_valid());
+        assert_eq!(
+            rendered.errors[0].to_string(),
+            "TextPart, line 1: undefined variable \"var:seat\""
+        );
+
+        let mut recipient = NameAndEmail::new("passenger@mailjet.com", None);
+        recipient.vars = Some(to_variables(&json!({"name": "John", "seat": "12A"})).unwrap());
+        let rendered = TemplateRenderer::default().render_message_for(&message, &recipient);
+
+        assert!(rendered.is_valid());
+        assert_eq!(rendered.subject.as_deref(), Some("Welcome John"));
+        assert_eq!(rendered.text_part.as_deref(), Some("Your seat: 12A"));
+    }
+}
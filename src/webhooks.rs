@@ -0,0 +1,570 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+//! Types for the events that Mailjet sends to the webhooks of an account (Event API).
+//!
+//! # Description
+//!
+//! Mailjet notifies the events of the messages (sent, opened, clicked, bounced...) issuing a POST request to the
+//! URL registered for each event type. The body of the request includes a single event, or an array of events when
+//! the grouping option is enabled for the URL. [parse_events] handles both formats.
+//!
+//! Unknown fields are ignored, and events whose type is not known by this crate are kept as [Event::Unknown], so
+//! new event types added by Mailjet don't break the parsing of a batch.
+//! See [Event API](https://dev.mailjet.com/email/guides/webhooks/).
+//!
+//! The webhooks of an account can be configured from code declaring a [WebhookSpec] per webhook and calling
+//! [crate::MailjetClient::ensure_webhooks].
+//!
+//! ## Example
+//!
+//! ```
+//! use mailjet_client::webhooks::{parse_events, Event};
+//!
+//! let body = r#"[
+//!     {"event": "open", "time": 1433103519, "MessageID": 19421777396190490, "email": "api@mailjet.com",
+//!      "ip": "127.0.0.1", "geo": "US", "agent": "Mozilla/5.0"},
+//!     {"event": "click", "time": 1433334653, "MessageID": 19421777396190490, "email": "api@mailjet.com",
+//!      "url": "https://mailjet.com"}
+//! ]"#;
+//!
+//! let events = parse_events(body.as_bytes()).expect("Failed to parse the events");
+//!
+//! assert!(matches!(&events[1], Event::Click(click) if click.url == "https://mailjet.com"));
+//! assert_eq!(events[0].message_id(), Some(19421777396190490));
+//! ```
+
+use crate::{
+    data_objects::{
+        CallbackStatus, EventCallbackUrl, EventCallbackUrlQuery, EventType, ValidationErrors,
+        Violation,
+    },
+    ClientError,
+};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Fields shared by all the event types.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct EventMessage {
+    /// Unix timestamp of the event.
+    pub time: i64,
+    #[serde(rename = "MessageID")]
+    pub message_id: i64,
+    #[serde(rename = "Message_GUID")]
+    pub message_guid: String,
+    /// Recipient of the message.
+    pub email: String,
+    pub mj_campaign_id: i64,
+    pub mj_contact_id: i64,
+    pub customcampaign: String,
+    /// `CustomID` given to the message when it was sent.
+    #[serde(rename = "CustomID")]
+    pub custom_id: String,
+    /// `EventPayload` given to the message when it was sent.
+    #[serde(rename = "Payload")]
+    pub payload: String,
+}
+
+/// Fields that describe the client used by the recipient.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct UserAgent {
+    pub ip: String,
+    /// Country code of the IP address.
+    pub geo: String,
+    /// User agent of the client.
+    pub agent: String,
+}
+
+/// The message was sent.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct SentEvent {
+    #[serde(flatten)]
+    pub message: EventMessage,
+    /// Internal ID of the message.
+    pub mj_message_id: String,
+    /// Reply of the SMTP server of the recipient.
+    pub smtp_reply: String,
+}
+
+/// The message was opened by the recipient.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct OpenEvent {
+    #[serde(flatten)]
+    pub message: EventMessage,
+    #[serde(flatten)]
+    pub user_agent: UserAgent,
+}
+
+/// The recipient clicked a link of the message.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ClickEvent {
+    #[serde(flatten)]
+    pub message: EventMessage,
+    /// URL of the clicked link.
+    pub url: String,
+    #[serde(flatten)]
+    pub user_agent: UserAgent,
+}
+
+/// The message bounced.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct BounceEvent {
+    #[serde(flatten)]
+    pub message: EventMessage,
+    /// Whether the contact was blocked after this bounce.
+    pub blocked: bool,
+    /// Whether the bounce is permanent.
+    pub hard_bounce: bool,
+    /// Cause of the error: `recipient`, `domain`, `content`, `spam reporting`, `system` or `mailjet`.
+    pub error_related_to: String,
+    pub error: String,
+    /// Details of the error, as reported by the SMTP server of the recipient.
+    pub comment: String,
+}
+
+/// The message was blocked by Mailjet.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct BlockedEvent {
+    #[serde(flatten)]
+    pub message: EventMessage,
+    /// Cause of the error: `recipient`, `domain`, `content`, `spam reporting`, `system` or `mailjet`.
+    pub error_related_to: String,
+    pub error: String,
+}
+
+/// The recipient marked the message as spam.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct SpamEvent {
+    #[serde(flatten)]
+    pub message: EventMessage,
+    /// Tool that reported the message as spam.
+    pub source: String,
+}
+
+/// The recipient unsubscribed.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct UnsubEvent {
+    #[serde(flatten)]
+    pub message: EventMessage,
+    /// ID of the contact list the recipient unsubscribed from.
+    pub mj_list_id: i64,
+    #[serde(flatten)]
+    pub user_agent: UserAgent,
+}
+
+/// An event sent by Mailjet to a webhook.
+///
+/// # Description
+///
+/// The variant is selected by the field `event` of the payload. Events of a type not listed here are kept as
+/// [Event::Unknown] with the raw payload.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Sent(SentEvent),
+    Open(OpenEvent),
+    Click(ClickEvent),
+    Bounce(BounceEvent),
+    Blocked(BlockedEvent),
+    Spam(SpamEvent),
+    Unsub(UnsubEvent),
+    Unknown { event: String, data: Value },
+}
+
+impl Event {
+    /// Type of the event, as given by the field `event` of the payload.
+    pub fn event_type(&self) -> &str {
+        match self {
+            Event::Sent(_) => "sent",
+            Event::Open(_) => "open",
+            Event::Click(_) => "click",
+            Event::Bounce(_) => "bounce",
+            Event::Blocked(_) => "blocked",
+            Event::Spam(_) => "spam",
+            Event::Unsub(_) => "unsub",
+            Event::Unknown { event, .. } => event,
+        }
+    }
+
+    /// Fields shared by all the event types. Not available for unknown events.
+    pub fn message(&self) -> Option<&EventMessage> {
+        match self {
+            Event::Sent(e) => Some(&e.message),
+            Event::Open(e) => Some(&e.message),
+            Event::Click(e) => Some(&e.message),
+            Event::Bounce(e) => Some(&e.message),
+            Event::Blocked(e) => Some(&e.message),
+            Event::Spam(e) => Some(&e.message),
+            Event::Unsub(e) => Some(&e.message),
+            Event::Unknown { .. } => None,
+        }
+    }
+
+    /// ID of the message of the event.
+    pub fn message_id(&self) -> Option<i64> {
+        match self {
+            Event::Unknown { data, .. } => data.get("MessageID").and_then(Value::as_i64),
+            _ => self.message().map(|m| m.message_id),
+        }
+    }
+
+    /// Unix timestamp of the event.
+    pub fn time(&self) -> Option<i64> {
+        match self {
+            Event::Unknown { data, .. } => data.get("time").and_then(Value::as_i64),
+            _ => self.message().map(|m| m.time),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let data = Value::deserialize(deserializer)?;
+        let event = data
+            .get("event")
+            .and_then(Value::as_str)
+            .ok_or_else(|| D::Error::missing_field("event"))?
+            .to_string();
+
+        fn parse<T: for<'a> Deserialize<'a>, E: Error>(data: Value) -> Result<T, E> {
+            T::deserialize(data).map_err(E::custom)
+        }
+
+        Ok(match event.as_str() {
+            "sent" => Event::Sent(parse(data)?),
+            "open" => Event::Open(parse(data)?),
+            "click" => Event::Click(parse(data)?),
+            "bounce" => Event::Bounce(parse(data)?),
+            "blocked" => Event::Blocked(parse(data)?),
+            "spam" => Event::Spam(parse(data)?),
+            "unsub" => Event::Unsub(parse(data)?),
+            _ => Event::Unknown { event, data },
+        })
+    }
+}
+
+/// Parse the body of a request sent by Mailjet to a webhook.
+///
+/// # Description
+///
+/// The body might include a single event or an array of events (grouped delivery). Either way, a list of events is
+/// returned. A [ClientError::ParseError] is returned when the body is not a valid event payload.
+pub fn parse_events(body: &[u8]) -> Result<Vec<Event>, ClientError> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Payload {
+        Batch(Vec<Event>),
+        Single(Box<Event>),
+    }
+
+    let value: Value =
+        serde_json::from_slice(body).map_err(|e| ClientError::ParseError(e.to_string()))?;
+
+    match Payload::deserialize(value) {
+        Ok(Payload::Batch(events)) => Ok(events),
+        Ok(Payload::Single(event)) => Ok(vec![*event]),
+        Err(e) => Err(ClientError::ParseError(e.to_string())),
+    }
+}
+
+/// Desired configuration of a webhook. See [crate::MailjetClient::ensure_webhooks].
+///
+/// # Description
+///
+/// An account has, at most, a webhook and a backup webhook per event type, so that pair identifies a webhook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookSpec {
+    pub event_type: EventType,
+    pub url: String,
+    pub is_backup: bool,
+    /// Whether the events are grouped in arrays (version 2 of the Event API).
+    pub grouped: bool,
+}
+
+impl WebhookSpec {
+    /// Build a spec for the main webhook of an event type, sending an event per request.
+    pub fn new(event_type: EventType, url: &str) -> Self {
+        WebhookSpec {
+            event_type,
+            url: url.to_string(),
+            is_backup: false,
+            grouped: false,
+        }
+    }
+
+    pub fn with_backup(mut self, is_backup: bool) -> Self {
+        self.is_backup = is_backup;
+
+        self
+    }
+
+    pub fn with_grouped(mut self, grouped: bool) -> Self {
+        self.grouped = grouped;
+
+        self
+    }
+
+    fn version(&self) -> u8 {
+        if self.grouped {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn matches(&self, current: &EventCallbackUrl) -> bool {
+        self.url == current.url
+            && self.version() == current.version
+            && current.status == CallbackStatus::Alive
+    }
+}
+
+impl From<&WebhookSpec> for EventCallbackUrlQuery {
+    fn from(value: &WebhookSpec) -> Self {
+        EventCallbackUrlQuery {
+            event_type: Some(value.event_type),
+            url: Some(value.url.clone()),
+            status: Some(CallbackStatus::Alive),
+            version: Some(value.version()),
+            is_backup: Some(value.is_backup),
+        }
+    }
+}
+
+/// Changes applied by [crate::MailjetClient::ensure_webhooks].
+#[derive(Debug, Clone, Default)]
+pub struct WebhookChanges {
+    pub created: Vec<EventCallbackUrl>,
+    pub updated: Vec<EventCallbackUrl>,
+    pub deleted: Vec<EventCallbackUrl>,
+}
+
+impl WebhookChanges {
+    /// Whether the configuration of the account already matched the desired one.
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.updated.is_empty() && self.deleted.is_empty()
+    }
+}
+
+/// Requests needed to turn the current webhooks of an account into the desired ones.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct WebhookPlan {
+    pub create: Vec<WebhookSpec>,
+    pub update: Vec<(i64, WebhookSpec)>,
+    pub delete: Vec<EventCallbackUrl>,
+}
+
+/// Compute the diff between the current webhooks of an account and the desired ones.
+///
+/// # Description
+///
+/// Webhooks are matched by their event type and backup flag. Matching webhooks whose URL or version differ, or that
+/// were marked as dead, are updated. Current webhooks that are not desired are deleted.
+pub(crate) fn plan(
+    current: Vec<EventCallbackUrl>,
+    desired: &[WebhookSpec],
+) -> Result<WebhookPlan, ClientError> {
+    let mut wanted = HashMap::new();
+    let mut violations = Vec::new();
+
+    for (i, spec) in desired.iter().enumerate() {
+        if wanted
+            .insert((spec.event_type, spec.is_backup), spec)
+            .is_some()
+        {
+            violations.push(Violation {
+                field: format!("[{i}]"),
+                message: "Duplicated webhook for the same event type and backup flag".into(),
+            });
+        }
+    }
+
+    if !violations.is_empty() {
+        return Err(ClientError::Validation(ValidationErrors { violations }));
+    }
+
+    let mut plan = WebhookPlan::default();
+
+    for webhook in current {
+        match wanted.remove(&(webhook.event_type, webhook.is_backup)) {
+            Some(spec) if spec.matches(&webhook) => (),
+            Some(spec) => plan.update.push((webhook.id, spec.clone())),
+            None => plan.delete.push(webhook),
+        }
+    }
+
+    // Keep the order given by the caller for the new webhooks.
+    plan.create = desired
+        .iter()
+        .filter(|s| wanted.contains_key(&(s.event_type, s.is_backup)))
+        .cloned()
+        .collect();
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::*;
+
+    #[rstest]
+    fn sent_event() {
+        let body = r#"{
+            "event": "sent", "time": 1433333949, "MessageID": 19421777835146490,
+            "Message_GUID": "1ab23cd4-e567-8901-2345-6789f0gh1i2j", "email": "api@mailjet.com",
+            "mj_campaign_id": 7257, "mj_contact_id": 4, "customcampaign": "", "mj_message_id": "19421777835146490",
+            "smtp_reply": "sent (250 2.0.0 OK 1433333948 fa5si855896wjc.199 - gsmtp)",
+            "CustomID": "helloworld", "Payload": ""
+        }"#;
+
+        let events = parse_events(body.as_bytes()).expect("Failed to parse the event");
+
+        assert_eq!(events.len(), 1);
+        let Event::Sent(sent) = &events[0] else {
+            panic!("Wrong event type: {:?}", events[0]);
+        };
+        assert_eq!(sent.message.message_id, 19421777835146490);
+        assert_eq!(sent.message.custom_id, "helloworld");
+        assert!(sent.smtp_reply.starts_with("sent (250"));
+        assert_eq!(events[0].time(), Some(1433333949));
+    }
+
+    #[rstest]
+    fn bounce_event() {
+        let body = r#"{
+            "event": "bounce", "time": 1430812195, "MessageID": 13792286917004336, "email": "bounce@mailjet.com",
+            "mj_campaign_id": 0, "mj_contact_id": 0, "customcampaign": "", "CustomID": "", "Payload": "",
+            "blocked": true, "hard_bounce": true, "error_related_to": "recipient", "error": "user unknown"
+        }"#;
+
+        let events = parse_events(body.as_bytes()).expect("Failed to parse the event");
+
+        let Event::Bounce(bounce) = &events[0] else {
+            panic!("Wrong event type: {:?}", events[0]);
+        };
+        assert!(bounce.hard_bounce);
+        assert!(bounce.blocked);
+        assert_eq!(bounce.error_related_to, "recipient");
+        assert_eq!(bounce.error, "user unknown");
+    }
+
+    #[rstest]
+    fn grouped_events() {
+        let body = r#"[
+            {"event": "open", "time": 1433103519, "MessageID": 1, "email": "api@mailjet.com",
+             "ip": "127.0.0.1", "geo": "US", "agent": "Mozilla/5.0"},
+            {"event": "click", "time": 1433334653, "MessageID": 1, "email": "api@mailjet.com",
+             "url": "https://mailjet.com", "ip": "127.0.0.1", "geo": "FR", "agent": "Mozilla/5.0"},
+            {"event": "blocked", "time": 1430812195, "MessageID": 2, "email": "blocked@mailjet.com",
+             "error_related_to": "recipient", "error": "user unknown"},
+            {"event": "spam", "time": 1430812195, "MessageID": 3, "email": "spam@mailjet.com", "source": "JMRPP"},
+            {"event": "unsub", "time": 1433334941, "MessageID": 4, "email": "unsub@mailjet.com",
+             "mj_list_id": 1, "ip": "127.0.0.1", "geo": "FR", "agent": "Mozilla/5.0"}
+        ]"#;
+
+        let events = parse_events(body.as_bytes()).expect("Failed to parse the events");
+
+        let types: Vec<_> = events.iter().map(Event::event_type).collect();
+        assert_eq!(types, ["open", "click", "blocked", "spam", "unsub"]);
+        assert!(matches!(&events[0], Event::Open(open) if open.user_agent.geo == "US"));
+        assert!(matches!(&events[1], Event::Click(click) if click.url == "https://mailjet.com"));
+        assert!(matches!(&events[3], Event::Spam(spam) if spam.source == "JMRPP"));
+        assert!(matches!(&events[4], Event::Unsub(unsub) if unsub.mj_list_id == 1));
+    }
+
+    #[rstest]
+    fn unknown_fields_and_events() {
+        let body = r#"[
+            {"event": "open", "time": 1433103519, "MessageID": 1, "email": "api@mailjet.com", "new_field": [1, 2]},
+            {"event": "forward", "time": 1433103600, "MessageID": 2, "email": "api@mailjet.com"}
+        ]"#;
+
+        let events = parse_events(body.as_bytes()).expect("Failed to parse the events");
+
+        assert!(matches!(&events[0], Event::Open(_)));
+        assert_eq!(events[1].event_type(), "forward");
+        assert_eq!(events[1].message_id(), Some(2));
+        assert_eq!(events[1].time(), Some(1433103600));
+        assert!(events[1].message().is_none());
+    }
+
+    fn webhook(id: i64, event_type: EventType, url: &str, version: u8) -> EventCallbackUrl {
+        EventCallbackUrl {
+            id,
+            api_key_id: 1,
+            event_type,
+            is_backup: false,
+            status: CallbackStatus::Alive,
+            url: url.into(),
+            version,
+        }
+    }
+
+    #[rstest]
+    fn webhooks_plan() {
+        let mut dead = webhook(3, EventType::Click, "https://hooks.test/click", 1);
+        dead.status = CallbackStatus::Dead;
+        let current = vec![
+            webhook(1, EventType::Open, "https://hooks.test/open", 1),
+            webhook(2, EventType::Bounce, "https://old.test/bounce", 1),
+            dead,
+            webhook(4, EventType::Spam, "https://hooks.test/spam", 1),
+        ];
+        let desired = [
+            WebhookSpec::new(EventType::Open, "https://hooks.test/open"),
+            WebhookSpec::new(EventType::Bounce, "https://hooks.test/bounce"),
+            WebhookSpec::new(EventType::Click, "https://hooks.test/click"),
+            WebhookSpec::new(EventType::Sent, "https://hooks.test/sent").with_grouped(true),
+            WebhookSpec::new(EventType::Sent, "https://backup.test/sent").with_backup(true),
+        ];
+
+        let plan = plan(current, &desired).expect("Failed to plan the changes");
+
+        assert_eq!(plan.create, desired[3..]);
+        assert_eq!(
+            plan.update,
+            vec![(2, desired[1].clone()), (3, desired[2].clone())]
+        );
+        assert_eq!(plan.delete.len(), 1);
+        assert_eq!(plan.delete[0].id, 4);
+    }
+
+    #[rstest]
+    fn webhooks_plan_duplicated() {
+        let desired = [
+            WebhookSpec::new(EventType::Open, "https://hooks.test/open"),
+            WebhookSpec::new(EventType::Open, "https://hooks.test/other"),
+        ];
+
+        let result = plan(Vec::new(), &desired);
+
+        assert!(matches!(result, Err(ClientError::Validation(e)) if e.contains("[1]")));
+    }
+
+    #[rstest]
+    #[case("not json")]
+    #[case(r#"{"time": 1433103519}"#)]
+    #[case(r#"[{"event": "open", "time": "yesterday"}]"#)]
+    fn invalid_payloads(#[case] body: &str) {
+        assert!(matches!(
+            parse_events(body.as_bytes()),
+            Err(ClientError::ParseError(_))
+        ));
+    }
+}
@@ -7,11 +7,12 @@ use crate::helper::TestApp;
 use async_std::fs::read_to_string;
 use mailjet_client::{
     data_objects::{MessageBuilder, MessageObject, SendEmailParams, SimpleMessage},
-    ClientError,
+    ApiVersion, ClientError, RetryPolicy,
 };
 use rstest::*;
 use serde::{Deserialize, Serialize};
-use std::mem::discriminant;
+use serde_json::json;
+use std::{mem::discriminant, time::Duration};
 use tracing::{debug, info};
 use wiremock::{
     matchers::{method, path},
@@ -20,7 +21,10 @@ use wiremock::{
 
 #[fixture]
 fn empty_email_request_v3_1() -> SendEmailParams {
-    let message = MessageBuilder::default().build();
+    let message = MessageBuilder::default()
+        .with_from("pilot@mailjet.com", None)
+        .build()
+        .expect("Failed to build the message");
 
     SendEmailParams {
         sandbox_mode: Some(true),
@@ -95,7 +99,7 @@ async fn test_send_empty_email_v3_1(empty_email_request_v3_1: SendEmailParams) {
     info!("Errors: {:#?}", errors);
     assert_eq!(
         discriminant(&errors),
-        discriminant(&ClientError::BadRequest("".to_string()))
+        discriminant(&ClientError::BadRequest(Default::default()))
     );
 }
 
@@ -151,7 +155,8 @@ async fn test2_send_email_v3_1() {
         .with_from(&from_addr, Some("Mailjet Rust Client"))
         .with_to(&test_recipient, None)
         .with_text_body("Test message sent from the Mailjet Rust Client")
-        .build();
+        .build()
+        .expect("Failed to build the message");
 
     let request = SendEmailParams {
         sandbox_mode: Some(false),
@@ -205,3 +210,216 @@ async fn mocktest_send_email_v3(
     debug!("Response: {:#?}", result);
     assert!(result.is_ok());
 }
+
+#[rstest]
+async fn mocktest_send_email_v3_1(#[future] valid_email_request_v3_1: SendEmailParams) {
+    let mut test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    let response: serde_json::Value = serde_json::from_str(
+        &read_to_string("tests/api/data/response_send_v3_1.json")
+            .await
+            .expect("Failed to read the response template from a file"),
+    )
+    .expect("Failed to parse the response template");
+
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .mount(
+            test_client
+                .email_server
+                .as_deref()
+                .expect("Failed to get a reference to the mock server"),
+        )
+        .await;
+
+    let result = test_client
+        .send_email_v3_1(&valid_email_request_v3_1.await)
+        .await;
+
+    debug!("Response: {:#?}", result);
+    let payload = result
+        .expect("Failed to send the email")
+        .payload
+        .expect("Empty payload");
+    assert_eq!(payload.len(), 1);
+    assert_eq!(
+        payload[0].to.as_ref().unwrap()[0].message_id,
+        Some(456789123)
+    );
+}
+
+#[rstest]
+async fn mocktest_error_details(#[future] valid_email_request_v3_1: SendEmailParams) {
+    let mut test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(401)
+                .insert_header("X-MJ-Request-GUID", "5e2b1f0c-request-guid")
+                .set_body_json(serde_json::json!({
+                    "ErrorIdentifier": "f987008f-251a-4dff-8ffc-40f1583ad7bc",
+                    "ErrorCode": "mj-0002",
+                    "StatusCode": 401,
+                    "ErrorMessage": "API key authentication/authorization failure."
+                })),
+        )
+        .mount(
+            test_client
+                .email_server
+                .as_deref()
+                .expect("Failed to get a reference to the mock server"),
+        )
+        .await;
+
+    let result = test_client
+        .send_email_v3_1(&valid_email_request_v3_1.await)
+        .await;
+
+    let error = result.expect_err("The request shall fail");
+    debug!("Error: {:#?}", error);
+    assert!(matches!(error, ClientError::Unauthorized(_)));
+    assert_eq!(error.status_code(), Some(401));
+    assert_eq!(error.request_id(), Some("5e2b1f0c-request-guid"));
+    assert_eq!(error.details().unwrap().errors[0].error_code, "mj-0002");
+}
+
+/// Test case to check that only requests that can be deduplicated are retried after a server error.
+#[rstest]
+async fn mocktest_retry_transient_errors(#[future] valid_email_request_v3_1: SendEmailParams) {
+    let mut test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    test_client.api_client.use_retry_policy(
+        RetryPolicy::default()
+            .with_max_attempts(3)
+            .with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+    );
+    let server = test_client
+        .email_server
+        .clone()
+        .expect("Failed to get a reference to the mock server");
+
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"Messages": []})))
+        .mount(&server)
+        .await;
+
+    let mut request = valid_email_request_v3_1.await;
+
+    // No CustomID: sending the message twice is not safe.
+    let result = test_client.send_email_v3_1(&request).await;
+    debug!("Result: {:#?}", result);
+    assert!(matches!(result, Err(ClientError::ServerError(_))));
+
+    // Reset the mock, and try again using a CustomID.
+    server.reset().await;
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"Messages": []})))
+        .mount(&server)
+        .await;
+
+    request.messages[0].custom_id = Some("test-message-1".into());
+    let result = test_client.send_email_v3_1(&request).await;
+    debug!("Result: {:#?}", result);
+    assert!(result.is_ok());
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[rstest]
+async fn mocktest_local_validation(empty_email_request_v3_1: SendEmailParams) {
+    let mut test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(
+            test_client
+                .email_server
+                .as_deref()
+                .expect("Failed to get a reference to the mock server"),
+        )
+        .await;
+
+    test_client.api_client.enable_validation();
+    let result = test_client.send_email_v3_1(&empty_email_request_v3_1).await;
+
+    match result {
+        Err(ClientError::Validation(errors)) => {
+            debug!("Violations: {errors}");
+            assert!(errors.contains("Messages[0].To"));
+            assert!(errors.contains("Messages[0].TextPart"));
+        }
+        other => panic!("Unexpected result: {other:?}"),
+    }
+}
+
+#[rstest]
+#[case(ApiVersion::V3, "/v3/send", json!({"Sent": [{"Email": "passenger@mailjet.com", "MessageID": 42}]}))]
+#[case(ApiVersion::V3_1, "/v3.1/send", json!({"Messages": [{
+    "Status": "success",
+    "To": [{"Email": "passenger@mailjet.com", "MessageID": 42}]
+}]}))]
+async fn mocktest_send_message_routing(
+    #[case] version: ApiVersion,
+    #[case] endpoint: &str,
+    #[case] response: serde_json::Value,
+) {
+    let mut test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path(endpoint))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .expect(1)
+        .mount(
+            test_client
+                .email_server
+                .as_deref()
+                .expect("Failed to get a reference to the mock server"),
+        )
+        .await;
+
+    test_client.api_client.use_api_version(version);
+    let message = MessageBuilder::default()
+        .with_from("pilot@mailjet.com", None)
+        .with_to("passenger@mailjet.com", None)
+        .with_text_body("Dear passenger, welcome to Mailjet!")
+        .build()
+        .expect("Failed to build the message");
+
+    let response = test_client
+        .api_client
+        .send_message(message)
+        .await
+        .expect("Failed to send the message");
+
+    let payload = response.payload.expect("Empty payload");
+    assert_eq!(payload.len(), 1);
+    assert_eq!(payload[0].message_id, Some(42));
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::TestApp;
+use mailjet_client::{
+    data_objects::{Message, MessageBuilder, MessageProperty},
+    SendOutcome,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path},
+    Mock, Request, ResponseTemplate,
+};
+
+/// Mock of `/send` (v3.1): messages whose subject is "reject" are rejected, the rest get the subject as ID.
+fn send_responder(request: &Request) -> ResponseTemplate {
+    let body: Value = serde_json::from_slice(&request.body).expect("Failed to parse the request");
+    let mut rejected = false;
+
+    let messages: Vec<_> = body["Messages"]
+        .as_array()
+        .expect("Missing messages")
+        .iter()
+        .map(|m| match m["Subject"].as_str().unwrap() {
+            "reject" => {
+                rejected = true;
+                json!({"Status": "error", "Errors": [{
+                    "ErrorIdentifier": "f987008f-251a-4dff-8ffc-40f1583ad7bc",
+                    "ErrorCode": "send-0003",
+                    "StatusCode": 400,
+                    "ErrorMessage": "At least \"HTMLPart\", \"TextPart\" or \"TemplateID\" must be provided.",
+                    "ErrorRelatedTo": ["HTMLPart", "TextPart"]
+                }]})
+            }
+            id => json!({"Status": "success", "To": [{
+                "Email": "passenger@mailjet.com",
+                "MessageID": id.parse::<i64>().unwrap()
+            }]}),
+        })
+        .collect();
+
+    ResponseTemplate::new(if rejected { 400 } else { 200 })
+        .set_body_json(json!({ "Messages": messages }))
+}
+
+fn message(subject: &str) -> Message {
+    MessageBuilder::default()
+        .with_from("pilot@mailjet.com", None)
+        .with_subject(subject)
+        .build()
+        .expect("Failed to build the message")
+}
+
+#[rstest]
+async fn mocktest_send_bulk() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(send_responder)
+        .expect(3)
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let mut messages: Vec<_> = (0..120).map(|i| message(&i.to_string())).collect();
+    messages[75] = message("reject");
+    let globals = MessageProperty {
+        text_part: Some("Dear passenger, welcome to Mailjet!".into()),
+        ..Default::default()
+    };
+
+    let outcomes = test_client
+        .api_client
+        .send_bulk(messages, Some(globals), 2)
+        .await;
+
+    assert_eq!(outcomes.len(), 120);
+    assert_eq!(outcomes.iter().filter(|o| o.is_sent()).count(), 119);
+    for (i, outcome) in outcomes.iter().enumerate() {
+        match outcome {
+            SendOutcome::Sent(ids) => assert_eq!(ids[0].message_id, Some(i as i64)),
+            SendOutcome::Rejected(errors) => {
+                assert_eq!(i, 75);
+                assert_eq!(errors[0].error_code, "send-0003");
+            }
+            SendOutcome::Failed(e) => panic!("Unexpected error: {e}"),
+        }
+    }
+}
+
+#[rstest]
+async fn mocktest_send_bulk_failed_request() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3.1/send"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "ErrorIdentifier": "06df1144-c6f3-4ca7-8885-7ec5d4344113",
+            "ErrorCode": "mj-0002",
+            "StatusCode": 401,
+            "ErrorMessage": "API key authentication/authorization failure."
+        })))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let outcomes = test_client
+        .api_client
+        .send_bulk(vec![message("1"), message("2")], None, 4)
+        .await;
+
+    assert_eq!(outcomes.len(), 2);
+    assert!(outcomes.iter().all(|o| matches!(o, SendOutcome::Failed(_))));
+}
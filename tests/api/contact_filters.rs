@@ -0,0 +1,80 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::{
+    data_objects::{ContactFilterQuery, ContactFilterStatus},
+    segmentation::property,
+    ClientError,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, ResponseTemplate,
+};
+
+#[rstest]
+async fn mocktest_create_contact_filter() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contactfilter"))
+        .and(method("POST"))
+        .and(body_json(json!({
+            "Name": "Young pilots",
+            "Expression": "(age<35) AND (rank=\"pilot\")",
+            "Description": "Pilots under 35"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([{
+            "Description": "Pilots under 35",
+            "Expression": "(age<35) AND (rank=\"pilot\")",
+            "ID": 5,
+            "Name": "Young pilots",
+            "Status": "unused"
+        }]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let query = ContactFilterQuery {
+        description: Some("Pilots under 35".into()),
+        ..ContactFilterQuery::new(
+            "Young pilots",
+            property("age").lt(35).and(property("rank").eq("pilot")),
+        )
+    };
+
+    let result = test_client.api_client.create_contact_filter(&query).await;
+
+    debug!("Result: {:#?}", result);
+    let filter = &result.expect("Failed to create a filter").payload.unwrap()[0];
+    assert_eq!(filter.id, 5);
+    assert_eq!(filter.status, ContactFilterStatus::Unused);
+}
+
+#[rstest]
+async fn mocktest_invalid_contact_filter() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contactfilter/5"))
+        .and(method("PUT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .update_contact_filter(5, &ContactFilterQuery::new("Young pilots", "(age<35"))
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert!(matches!(result, Err(ClientError::Validation(e)) if e.contains("Expression")));
+}
@@ -0,0 +1,217 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::{
+    data_objects::{
+        ContactAction, ContactsListAction, JobStatus, ManageContactsQuery, ManageListContactsQuery,
+        ManagedContact,
+    },
+    endpoints::{GetListContactsJob, GetManyContactsJob},
+    ClientError,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+fn job(#[default("Completed")] status: &str, #[default(2)] count: i64) -> serde_json::Value {
+    rest_page(json!([{
+        "Count": count,
+        "Error": "",
+        "ErrorFile": "",
+        "JobEnd": "",
+        "JobStart": "2024-09-20T10:00:00Z",
+        "Status": status
+    }]))
+}
+
+#[rstest]
+async fn mocktest_manage_list_contacts(
+    job: serde_json::Value,
+    #[from(job)]
+    #[with("In Progress", 1)]
+    in_progress_job: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/REST/contactslist/7/managemanycontacts"))
+        .and(method("POST"))
+        .and(body_json(json!({
+            "Action": "addnoforce",
+            "Contacts": [
+                {"Email": "passenger@mailjet.com", "Name": "Passenger", "Properties": {"seat": "12A"}},
+                {"Email": "copilot@mailjet.com"}
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([{"JobID": 3}]))))
+        .mount(server)
+        .await;
+
+    // The job is in progress the first time it is polled.
+    Mock::given(path("/v3/REST/contactslist/7/managemanycontacts/3"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(in_progress_job))
+        .up_to_n_times(1)
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/contactslist/7/managemanycontacts/3"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job))
+        .mount(server)
+        .await;
+
+    let query = ManageListContactsQuery {
+        action: ContactAction::AddNoForce,
+        contacts: vec![
+            ManagedContact {
+                name: Some("Passenger".into()),
+                properties: Some(HashMap::from([("seat".to_string(), json!("12A"))])),
+                ..ManagedContact::new("passenger@mailjet.com")
+            },
+            ManagedContact::new("copilot@mailjet.com"),
+        ],
+    };
+
+    let handle = test_client
+        .api_client
+        .manage_list_contacts(7, &query)
+        .await
+        .expect("Failed to start the job")
+        .payload
+        .unwrap();
+    assert_eq!(handle[0].job_id, 3);
+
+    let result = test_client
+        .api_client
+        .poll_job(GetListContactsJob {
+            list_id: 7,
+            job_id: handle[0].job_id,
+        })
+        .with_backoff(Duration::from_millis(10), Duration::from_millis(20))
+        .wait()
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let job = result.expect("Failed to poll the job");
+    assert_eq!(job.status, JobStatus::Completed);
+    assert_eq!(job.count, 2);
+}
+
+#[rstest]
+async fn mocktest_manage_many_contacts_timeout(#[with("Pending", 0)] job: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/REST/contact/managemanycontacts"))
+        .and(method("POST"))
+        .and(body_json(json!({
+            "ContactsLists": [{"ListID": 7, "Action": "unsub"}],
+            "Contacts": [{"Email": "passenger@mailjet.com"}]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([{"JobID": 4}]))))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/contact/managemanycontacts/4"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job))
+        .mount(server)
+        .await;
+
+    let query = ManageContactsQuery {
+        contacts_lists: vec![ContactsListAction {
+            list_id: 7,
+            action: ContactAction::Unsub,
+        }],
+        contacts: vec![ManagedContact::new("passenger@mailjet.com")],
+    };
+
+    let handle = test_client
+        .api_client
+        .manage_many_contacts(&query)
+        .await
+        .expect("Failed to start the job")
+        .payload
+        .unwrap();
+
+    let result = test_client
+        .api_client
+        .poll_job(GetManyContactsJob(handle[0].job_id))
+        .with_backoff(Duration::from_millis(10), Duration::from_millis(20))
+        .with_timeout(Duration::from_millis(50))
+        .wait()
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert!(matches!(result, Err(ClientError::JobTimeout(_))));
+}
+
+#[rstest]
+async fn mocktest_poll_job_timeout_includes_requests(#[with("Pending", 0)] job: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    // Each request takes longer than the pauses between them, so the timeout is reached after a couple of polls.
+    Mock::given(path("/v3/REST/contact/managemanycontacts/4"))
+        .and(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(job)
+                .set_delay(Duration::from_millis(100)),
+        )
+        .expect(1..=2)
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .poll_job(GetManyContactsJob(4))
+        .with_backoff(Duration::from_millis(10), Duration::from_millis(10))
+        .with_timeout(Duration::from_millis(150))
+        .wait()
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert!(matches!(result, Err(ClientError::JobTimeout(_))));
+}
+
+#[rstest]
+async fn mocktest_poll_job_unknown_status(#[with("Exploded", 0)] job: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contact/managemanycontacts/4"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job))
+        .expect(1)
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .poll_job(GetManyContactsJob(4))
+        .with_backoff(Duration::from_millis(10), Duration::from_millis(10))
+        .with_timeout(Duration::from_secs(5))
+        .wait()
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert!(matches!(result, Err(ClientError::UnknownJobStatus(status)) if status == "Exploded"));
+}
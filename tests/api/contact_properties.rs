@@ -0,0 +1,190 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::{
+    contact_properties::{ContactProperties, PropertyDefinition},
+    data_objects::{PropertyDataType, PropertyNamespace},
+    ClientError,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, ResponseTemplate,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Passenger {
+    first_name: String,
+    miles: Option<i64>,
+}
+
+impl ContactProperties for Passenger {
+    fn properties() -> Vec<PropertyDefinition> {
+        vec![
+            PropertyDefinition::new("first_name", PropertyDataType::Str),
+            PropertyDefinition::new("miles", PropertyDataType::Int).historic(),
+        ]
+    }
+}
+
+#[fixture]
+fn metadata(
+    #[default(1)] id: i64,
+    #[default("first_name")] name: &str,
+    #[default("str")] datatype: &str,
+    #[default("static")] namespace: &str,
+) -> serde_json::Value {
+    json!({"ID": id, "Datatype": datatype, "Name": name, "NameSpace": namespace})
+}
+
+async fn mount_metadata(server: &wiremock::MockServer, data: Vec<serde_json::Value>) {
+    Mock::given(path("/v3/REST/contactmetadata"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(data.into())))
+        .mount(server)
+        .await;
+}
+
+#[rstest]
+async fn mocktest_declare_contact_properties(
+    metadata: serde_json::Value,
+    #[from(metadata)]
+    #[with(2, "miles", "int", "historic")]
+    miles: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    mount_metadata(server, vec![metadata]).await;
+
+    Mock::given(path("/v3/REST/contactmetadata"))
+        .and(method("POST"))
+        .and(body_json(
+            json!({"Datatype": "int", "Name": "miles", "NameSpace": "historic"}),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([miles]))))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    let result = test_client
+        .api_client
+        .declare_contact_properties::<Passenger>()
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let created = result.expect("Failed to declare the properties");
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].name, "miles");
+    assert_eq!(created[0].namespace, PropertyNamespace::Historic);
+}
+
+#[rstest]
+async fn mocktest_contact_properties(
+    metadata: serde_json::Value,
+    #[from(metadata)]
+    #[with(2, "miles", "int", "historic")]
+    miles: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    mount_metadata(server, vec![metadata, miles]).await;
+
+    Mock::given(path("/v3/REST/contactdata/passenger@mailjet.com"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([{
+            "ContactID": 7,
+            "Data": [
+                {"Name": "first_name", "Value": "Amelia"},
+                {"Name": "miles", "Value": "1200"}
+            ],
+            "ID": 7
+        }]))))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/contactdata/7"))
+        .and(method("PUT"))
+        .and(body_json(json!({"Data": [
+            {"Name": "first_name", "Value": "Amelia"},
+            {"Name": "miles", "Value": 1500}
+        ]})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(rest_page(json!([{"ContactID": 7, "Data": [], "ID": 7}]))),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+
+    let mut passenger: Passenger = test_client
+        .api_client
+        .get_contact_properties("passenger@mailjet.com")
+        .await
+        .expect("Failed to retrieve the properties");
+    assert_eq!(
+        passenger,
+        Passenger {
+            first_name: "Amelia".into(),
+            miles: Some(1200),
+        }
+    );
+
+    passenger.miles = Some(1500);
+    let result = test_client
+        .api_client
+        .update_contact_properties(7, &passenger)
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert_eq!(
+        result.expect("Failed to update the properties").status_code,
+        200
+    );
+}
+
+#[rstest]
+async fn mocktest_contact_properties_mismatch(
+    metadata: serde_json::Value,
+    #[from(metadata)]
+    #[with(2, "miles", "float", "historic")]
+    miles: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    mount_metadata(server, vec![metadata, miles]).await;
+
+    Mock::given(path("/v3/REST/contactdata/7"))
+        .and(method("PUT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(server)
+        .await;
+
+    let passenger = Passenger {
+        first_name: "Amelia".into(),
+        miles: Some(1500),
+    };
+    let result = test_client
+        .api_client
+        .update_contact_properties(7, &passenger)
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert!(matches!(result, Err(ClientError::Validation(e)) if e.contains("miles")));
+}
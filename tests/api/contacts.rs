@@ -0,0 +1,182 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::TestApp;
+use async_std::fs::read_to_string;
+use mailjet_client::data_objects::{ContactFilters, ContactQuery, ContactUpdate};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+async fn contact_response() -> serde_json::Value {
+    serde_json::from_str(
+        &read_to_string("tests/api/data/response_contact.json")
+            .await
+            .expect("Failed to read the response template from a file"),
+    )
+    .expect("Failed to parse the response template")
+}
+
+#[rstest]
+async fn mocktest_create_contact(#[future] contact_response: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contact"))
+        .and(method("POST"))
+        .and(body_json(serde_json::json!({
+            "IsExcludedFromCampaigns": null,
+            "Name": "Passenger 1",
+            "Email": "passenger@mailjet.com"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(contact_response.await))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .create_contact(&ContactQuery {
+            is_excluded_from_campaigns: None,
+            name: Some("Passenger 1".into()),
+            email: "passenger@mailjet.com".into(),
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let response = result.expect("Failed to create the contact");
+    assert_eq!(response.status_code, 201);
+    let contact = &response.payload.unwrap()[0];
+    assert_eq!(contact.id, 1234567);
+    assert_eq!(contact.delivered_count, 3);
+}
+
+#[rstest]
+async fn mocktest_get_contact(#[future] contact_response: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contact/passenger@mailjet.com"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(contact_response.await))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .get_contact("passenger@mailjet.com")
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let contact = &result.expect("Failed to get the contact").payload.unwrap()[0];
+    assert_eq!(contact.email, "passenger@mailjet.com");
+    assert_eq!(
+        contact.last_activity_at.as_deref(),
+        Some("2024-09-21T12:30:00Z")
+    );
+}
+
+#[rstest]
+async fn mocktest_get_contact_escaped_email(#[future] contact_response: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    // A raw `+` in the path would be read as a space by the external API.
+    Mock::given(path("/v3/REST/contact/a%2Bb@x.com"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(contact_response.await))
+        .expect(1)
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client.api_client.get_contact("a+b@x.com").await;
+
+    debug!("Result: {:#?}", result);
+    assert!(result.is_ok());
+}
+
+#[rstest]
+async fn mocktest_list_contacts(#[future] contact_response: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contact"))
+        .and(method("GET"))
+        .and(query_param("ContactsList", "42"))
+        .and(query_param("Limit", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(contact_response.await))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .list_contacts(&ContactFilters {
+            contacts_list: Some(42),
+            limit: Some(10),
+            ..Default::default()
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert_eq!(result.unwrap().payload.unwrap().len(), 1);
+}
+
+#[rstest]
+async fn mocktest_update_contact(#[future] contact_response: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contact/1234567"))
+        .and(method("PUT"))
+        .and(body_json(
+            serde_json::json!({"IsExcludedFromCampaigns": true}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(contact_response.await))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .update_contact(
+            1234567,
+            &ContactUpdate {
+                is_excluded_from_campaigns: Some(true),
+                name: None,
+            },
+        )
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert!(result.is_ok());
+}
+
+#[rstest]
+async fn mocktest_delete_contact() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v4/contacts/1234567"))
+        .and(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client.api_client.delete_contact(1234567).await;
+
+    debug!("Result: {:#?}", result);
+    let response = result.expect("Failed to delete the contact");
+    assert_eq!(response.status_code, 200);
+    assert!(response.payload.is_none());
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::data_objects::{ContactsListFilters, ContactsListQuery};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+fn list(
+    #[default(1)] id: i64,
+    #[default("newsletter")] name: &str,
+    #[default(false)] is_deleted: bool,
+) -> serde_json::Value {
+    json!({
+        "Address": "xyz123",
+        "CreatedAt": "2024-09-20T10:00:00Z",
+        "ID": id,
+        "IsDeleted": is_deleted,
+        "Name": name,
+        "SubscriberCount": 12
+    })
+}
+
+#[fixture]
+fn recipient(#[default(5)] id: i64, #[default(false)] is_unsubscribed: bool) -> serde_json::Value {
+    json!({
+        "ContactID": 9,
+        "ID": id,
+        "IsActive": true,
+        "IsUnsubscribed": is_unsubscribed,
+        "ListID": 1,
+        "ListName": "newsletter",
+        "SubscribedAt": "2024-09-20T10:00:00Z",
+        "UnsubscribedAt": null
+    })
+}
+
+#[rstest]
+async fn mocktest_contacts_lists(
+    list: serde_json::Value,
+    #[from(list)]
+    #[with(1, "newsletter", true)]
+    trashed_list: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/REST/contactslist"))
+        .and(method("POST"))
+        .and(body_json(json!({"Name": "newsletter"})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([list]))))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/contactslist"))
+        .and(method("GET"))
+        .and(query_param("IsDeleted", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([list]))))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/contactslist/1"))
+        .and(method("PUT"))
+        .and(body_json(json!({"IsDeleted": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([trashed_list]))))
+        .mount(server)
+        .await;
+
+    let created = test_client
+        .api_client
+        .create_contacts_list(&ContactsListQuery {
+            name: Some("newsletter".into()),
+            ..Default::default()
+        })
+        .await;
+    debug!("Result: {:#?}", created);
+    let created = &created.expect("Failed to create a list").payload.unwrap()[0];
+    assert_eq!(created.subscriber_count, 12);
+
+    let lists = test_client
+        .api_client
+        .list_contacts_lists(&ContactsListFilters {
+            is_deleted: Some(false),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list the lists")
+        .payload
+        .unwrap();
+    assert_eq!(lists.len(), 1);
+
+    let trashed = test_client
+        .api_client
+        .update_contacts_list(
+            1,
+            &ContactsListQuery {
+                is_deleted: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("Failed to update a list")
+        .payload
+        .unwrap();
+    assert!(trashed[0].is_deleted);
+}
+
+#[rstest]
+async fn mocktest_subscribe_contact(recipient: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/listrecipient"))
+        .and(method("POST"))
+        .and(body_json(json!({
+            "ContactAlt": "passenger@mailjet.com",
+            "ListID": 1,
+            "IsUnsubscribed": false
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([recipient]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .subscribe_contact("passenger@mailjet.com", 1)
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let subscription = &result.expect("Failed to subscribe").payload.unwrap()[0];
+    assert_eq!(subscription.list_id, 1);
+    assert!(!subscription.is_unsubscribed);
+}
+
+#[rstest]
+async fn mocktest_unsubscribe_contact(
+    recipient: serde_json::Value,
+    #[from(recipient)]
+    #[with(5, true)]
+    unsubscribed: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/REST/listrecipient"))
+        .and(method("GET"))
+        .and(query_param("Contact", "9"))
+        .and(query_param("ContactsList", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([recipient]))))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/listrecipient/5"))
+        .and(method("PUT"))
+        .and(body_json(json!({"IsUnsubscribed": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([unsubscribed]))))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    let result = test_client.api_client.unsubscribe_contact(9, 1).await;
+
+    debug!("Result: {:#?}", result);
+    let updated = result.expect("Failed to unsubscribe");
+    assert_eq!(updated.len(), 1);
+    assert!(updated[0].is_unsubscribed);
+}
+
+#[rstest]
+async fn mocktest_get_contact_lists() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contact/9/getcontactslists"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([
+                {"IsActive": true, "IsUnsub": false, "ListID": 1, "SubscribedAt": "2024-09-20T10:00:00Z"},
+                {"IsActive": true, "IsUnsub": true, "ListID": 2, "SubscribedAt": "2024-09-21T10:00:00Z"}
+            ]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client.api_client.get_contact_lists(9).await;
+
+    debug!("Result: {:#?}", result);
+    let memberships = result.expect("Failed to get the lists").payload.unwrap();
+    assert_eq!(memberships.len(), 2);
+    assert!(memberships[1].is_unsub);
+}
@@ -0,0 +1,198 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::{
+    data_objects::{ContactAction, CsvImportQuery, ImportOptions, JobStatus},
+    endpoints::GetCsvImport,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use std::time::Duration;
+use tracing::debug;
+use uuid::Uuid;
+use wiremock::{
+    matchers::{body_json, body_string, header, method, path},
+    Mock, ResponseTemplate,
+};
+
+const CSV: &str = "email,name\npassenger@mailjet.com,Passenger\ncopilot@mailjet.com,Copilot\n";
+
+#[fixture]
+fn csv_import(#[default("Completed")] status: &str) -> serde_json::Value {
+    rest_page(json!([{
+        "ContactsListID": 7,
+        "Count": 2,
+        "Current": 0,
+        "DataID": 11,
+        "Errcount": 0,
+        "ErrTreshold": 0,
+        "ID": 5,
+        "ImportOptions": "",
+        "JobEnd": "",
+        "JobStart": "2024-09-20T10:00:00Z",
+        "Method": "addnoforce",
+        "RequestAt": "2024-09-20T10:00:00Z",
+        "Status": status
+    }]))
+}
+
+#[rstest]
+async fn mocktest_import_contacts_csv(
+    csv_import: serde_json::Value,
+    #[from(csv_import)]
+    #[with("Upload")]
+    uploaded_import: serde_json::Value,
+    #[from(csv_import)]
+    #[with("In Progress")]
+    running_import: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/DATA/contactslist/7/CSVData/text:plain"))
+        .and(method("POST"))
+        .and(header("Content-Type", "text/plain"))
+        .and(body_string(CSV))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ID": 11})))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/csvimport"))
+        .and(method("POST"))
+        .and(body_json(json!({
+            "ContactsListID": 7,
+            "DataID": 11,
+            "Method": "addnoforce",
+            "ImportOptions": "{\"FieldNames\":[\"email\",\"name\"]}"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(uploaded_import))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/csvimport/5"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(running_import))
+        .up_to_n_times(1)
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/csvimport/5"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(csv_import))
+        .mount(server)
+        .await;
+
+    let file = std::env::temp_dir().join(format!("{}.csv", Uuid::new_v4()));
+    std::fs::write(&file, CSV).expect("Failed to write the CSV file");
+
+    let upload = test_client.api_client.upload_contacts_csv(7, &file).await;
+    std::fs::remove_file(&file).expect("Failed to remove the CSV file");
+
+    debug!("Upload: {:#?}", upload);
+    let upload = upload.expect("Failed to upload the CSV file");
+    assert_eq!(upload.id, 11);
+
+    let query = CsvImportQuery {
+        contacts_list_id: 7,
+        data_id: upload.id,
+        method: ContactAction::AddNoForce,
+        import_options: Some(ImportOptions {
+            field_names: vec!["email".into(), "name".into()],
+            ..Default::default()
+        }),
+    };
+
+    let import = test_client
+        .api_client
+        .create_csv_import(&query)
+        .await
+        .expect("Failed to start the import")
+        .payload
+        .unwrap();
+    assert_eq!(import[0].id, 5);
+
+    let job = test_client
+        .api_client
+        .poll_job(GetCsvImport(import[0].id))
+        .with_backoff(Duration::from_millis(10), Duration::from_millis(20))
+        .wait()
+        .await
+        .expect("Failed to poll the import");
+
+    assert_eq!(job.status, JobStatus::Completed);
+    assert_eq!(job.count, 2);
+    assert_eq!(job.errcount, 0);
+}
+
+#[rstest]
+async fn mocktest_upload_contacts_csv_from_reader() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/DATA/contactslist/7/CSVData/text:plain"))
+        .and(method("POST"))
+        .and(body_string(CSV))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"ID": 12})))
+        .mount(server)
+        .await;
+
+    let upload = test_client
+        .api_client
+        .upload_contacts_csv_from_reader(7, CSV.as_bytes())
+        .await
+        .expect("Failed to upload the CSV content");
+
+    assert_eq!(upload.id, 12);
+}
+
+#[rstest]
+async fn mocktest_download_contacts_csv() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/DATA/contactslist/7/CSVData/text:plain"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(CSV))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/DATA/BatchJob/5/CSVError/text:csv"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("\"broken\",Invalid email\n"))
+        .mount(server)
+        .await;
+
+    let file = std::env::temp_dir().join(format!("{}.csv", Uuid::new_v4()));
+    let written = test_client
+        .api_client
+        .download_contacts_csv(7, &file)
+        .await
+        .expect("Failed to download the contacts");
+    let content = std::fs::read_to_string(&file).expect("Failed to read the CSV file");
+    std::fs::remove_file(&file).expect("Failed to remove the CSV file");
+
+    assert_eq!(written, CSV.len() as u64);
+    assert_eq!(content, CSV);
+
+    let mut errors = Vec::new();
+    test_client
+        .api_client
+        .download_csv_import_errors(5, &mut errors)
+        .await
+        .expect("Failed to download the errors");
+
+    assert_eq!(
+        String::from_utf8(errors).unwrap(),
+        "\"broken\",Invalid email\n"
+    );
+}
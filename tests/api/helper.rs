@@ -5,11 +5,12 @@
 
 use anyhow::{anyhow, Result};
 use mailjet_client::{
-    data_objects::{RequestObject, Response},
+    data_objects::{MessageObject, Response, SendEmailParams, SendResponseObject, SimpleMessage},
     ClientError, MailjetClient,
 };
 use once_cell::sync::Lazy;
 use secrecy::SecretString;
+use serde_json::json;
 use std::sync::Arc;
 use tracing::{info, level_filters::LevelFilter};
 use tracing_subscriber::{fmt, layer::SubscriberExt, registry, util::SubscriberInitExt, Layer};
@@ -40,6 +41,17 @@ static TRACING: Lazy<()> = Lazy::new(|| {
     }
 });
 
+/// Wrap an array of objects using the envelope of the responses of the REST API.
+pub fn rest_page(data: serde_json::Value) -> serde_json::Value {
+    let count = data.as_array().map_or(0, Vec::len);
+
+    json!({
+        "Count": count,
+        "Data": data,
+        "Total": count
+    })
+}
+
 pub struct TestApp {
     pub api_client: MailjetClient,
     pub email_server: Option<Arc<MockServer>>,
@@ -106,8 +118,8 @@ impl TestApp {
 
     pub async fn send_email_v3_1(
         &mut self,
-        request: &impl RequestObject,
-    ) -> Result<Response, ClientError> {
+        request: &SendEmailParams,
+    ) -> Result<Response<SendResponseObject>, ClientError> {
         info!("Test email using API v3.1");
         self.api_client
             .use_api_version(mailjet_client::ApiVersion::V3_1);
@@ -116,8 +128,8 @@ impl TestApp {
 
     pub async fn send_email_v3(
         &mut self,
-        request: &impl RequestObject,
-    ) -> Result<Response, ClientError> {
+        request: &SimpleMessage,
+    ) -> Result<Response<MessageObject>, ClientError> {
         info!("Test email using API v3");
         self.api_client
             .use_api_version(mailjet_client::ApiVersion::V3);
@@ -4,4 +4,17 @@
 // For a copy, see <https://opensource.org/licenses/MIT>.
 
 mod api_client;
+mod bulk_send;
+mod contact_filters;
+mod contact_jobs;
+mod contact_properties;
+mod contacts;
+mod contacts_lists;
+mod csv;
 mod helper;
+mod messages;
+mod pagination;
+mod parse_routes;
+mod senders;
+mod templates;
+mod webhooks;
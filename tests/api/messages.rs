@@ -0,0 +1,182 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::{
+    data_objects::{MessageFilters, MessageObject, MessageStatus},
+    ClientError,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+fn message(#[default(1)] id: i64, #[default("sent")] status: &str) -> serde_json::Value {
+    json!({
+        "ArrivedAt": "2024-09-20T10:00:00Z",
+        "AttachmentCount": 0,
+        "AttemptCount": 1,
+        "CampaignID": 5,
+        "ContactAlt": "",
+        "ContactID": 9,
+        "Delay": 0.0,
+        "DestinationID": 14,
+        "FilterTime": 0,
+        "ID": id,
+        "IsClickTracked": true,
+        "IsHTMLPartIncluded": true,
+        "IsOpenTracked": true,
+        "IsTextPartIncluded": false,
+        "IsUnsubTracked": false,
+        "MessageSize": 1024,
+        "SenderID": 3,
+        "SpamassassinScore": 0.1,
+        "SpamassRules": "",
+        "StatePermanent": false,
+        "Status": status,
+        "Subject": "Your email flight plan!",
+        "UUID": "1ab23cd4-e567-8901-2345-6789f0gh1i2j"
+    })
+}
+
+#[rstest]
+async fn mocktest_list_messages(message: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/message"))
+        .and(method("GET"))
+        .and(query_param("CustomID", "order-42"))
+        .and(query_param("ShowSubject", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(rest_page(json!([message, message::get(2, "bounce")]))),
+        )
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .list_messages(&MessageFilters {
+            custom_id: Some("order-42".into()),
+            show_subject: Some(true),
+            ..Default::default()
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let messages = result.expect("Failed to list messages").payload.unwrap();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].status, MessageStatus::Sent);
+    assert_eq!(messages[1].status, MessageStatus::Bounce);
+    assert_eq!(messages[1].subject, "Your email flight plan!");
+}
+
+#[rstest]
+async fn mocktest_message_information() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/messageinformation/1"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([{
+            "CampaignID": 5,
+            "ClickTrackedCount": 2,
+            "ContactID": 9,
+            "CreatedAt": "2024-09-20T10:00:00Z",
+            "ID": 1,
+            "MessageSize": 1024,
+            "OpenTrackedCount": 3,
+            "QueuedCount": 0,
+            "SendEndAt": "2024-09-20T10:00:01Z",
+            "SentCount": 1,
+            "SpamAssassinRules": {"ALT": "", "ID": 0},
+            "SpamAssassinScore": 0.1
+        }]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client.api_client.get_message_information(1).await;
+
+    debug!("Result: {:#?}", result);
+    let information = &result
+        .expect("Failed to get the information")
+        .payload
+        .unwrap()[0];
+    assert_eq!(information.open_tracked_count, 3);
+    assert_eq!(information.click_tracked_count, 2);
+}
+
+#[rstest]
+#[case(Some(1), None)]
+#[case(None, Some("https://api.mailjet.com/v3/REST/message/1"))]
+async fn mocktest_track_message(
+    #[case] id: Option<i64>,
+    #[case] href: Option<&str>,
+    #[with(1, "clicked")] message: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/message/1"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([message]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    Mock::given(path("/v3/REST/messagehistory/1"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([
+                {"Comment": "", "EventAt": 1726826400, "EventType": "sent", "State": "", "Useragent": "", "UseragentID": 0},
+                {"Comment": "", "EventAt": 1726826500, "EventType": "opened", "State": "", "Useragent": "Firefox", "UseragentID": 1},
+                {"Comment": "", "EventAt": 1726826600, "EventType": "clicked", "State": "", "Useragent": "Firefox", "UseragentID": 1}
+            ]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let sent = MessageObject {
+        email: Some("passenger@mailjet.com".into()),
+        message_uuid: None,
+        message_id: id,
+        message_href: href.map(String::from),
+    };
+
+    let result = test_client.api_client.track_message(&sent).await;
+
+    debug!("Result: {:#?}", result);
+    let delivery = result.expect("Failed to track the message");
+    assert_eq!(delivery.status(), MessageStatus::Clicked);
+    assert_eq!(delivery.history.len(), 3);
+    assert_eq!(
+        delivery.last_event().unwrap().event_type,
+        MessageStatus::Clicked
+    );
+}
+
+#[rstest]
+async fn mocktest_track_message_without_id() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    let sent = MessageObject {
+        email: Some("passenger@mailjet.com".into()),
+        message_uuid: None,
+        message_id: None,
+        message_href: None,
+    };
+
+    let result = test_client.api_client.track_message(&sent).await;
+
+    assert!(matches!(result, Err(ClientError::ParseError(_))));
+}
@@ -0,0 +1,174 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use futures::TryStreamExt;
+use mailjet_client::{data_objects::ContactFilters, endpoints::ListContacts, ClientError};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn page(ids: &[i64], total: u64) -> ResponseTemplate {
+    let data: Vec<_> = ids
+        .iter()
+        .map(|id| json!({"ID": id, "Email": format!("contact{id}@mail.com")}))
+        .collect();
+    let mut body = rest_page(data.into());
+    body["Total"] = total.into();
+
+    ResponseTemplate::new(200).set_body_json(body)
+}
+
+async fn mount_pages(server: &MockServer) {
+    Mock::given(path("/v3/REST/contact"))
+        .and(method("GET"))
+        .and(query_param("Limit", "2"))
+        .and(query_param("Offset", "0"))
+        .respond_with(page(&[1, 2], 3))
+        .mount(server)
+        .await;
+    Mock::given(path("/v3/REST/contact"))
+        .and(method("GET"))
+        .and(query_param("Limit", "2"))
+        .and(query_param("Offset", "2"))
+        .respond_with(page(&[3], 3))
+        .mount(server)
+        .await;
+}
+
+#[rstest]
+async fn mocktest_paginator_stream() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    mount_pages(test_client.email_server.as_deref().unwrap()).await;
+
+    let contacts: Vec<_> = test_client
+        .api_client
+        .paginate(ListContacts, ContactFilters::default())
+        .with_page_size(2)
+        .stream()
+        .try_collect()
+        .await
+        .expect("Failed to retrieve all the pages");
+
+    debug!("Contacts: {:#?}", contacts);
+    let ids: Vec<_> = contacts.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+#[rstest]
+async fn mocktest_paginator_total_is_page_count() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    // Some resources report the size of the page as `Total`, so it can't be used to stop.
+    for (offset, ids) in [("0", vec![1, 2]), ("2", vec![3, 4]), ("4", vec![5])] {
+        Mock::given(path("/v3/REST/contact"))
+            .and(method("GET"))
+            .and(query_param("Limit", "2"))
+            .and(query_param("Offset", offset))
+            .respond_with(page(&ids, ids.len() as u64))
+            .mount(server)
+            .await;
+    }
+
+    let contacts = test_client
+        .api_client
+        .paginate(ListContacts, ContactFilters::default())
+        .with_page_size(2)
+        .collect_all(10)
+        .await
+        .expect("Failed to retrieve all the pages");
+
+    let ids: Vec<_> = contacts.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+}
+
+#[rstest]
+async fn mocktest_paginator_clears_count_only() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    // As the external API does, no objects are returned when only the amount of matches is requested.
+    Mock::given(path("/v3/REST/contact"))
+        .and(method("GET"))
+        .and(query_param("CountOnly", "1"))
+        .respond_with(page(&[], 3))
+        .mount(server)
+        .await;
+    mount_pages(server).await;
+
+    let filters = ContactFilters {
+        count_only: Some(true),
+        ..Default::default()
+    };
+    let contacts = test_client
+        .api_client
+        .paginate(ListContacts, filters)
+        .with_page_size(2)
+        .collect_all(10)
+        .await
+        .expect("Failed to retrieve all the pages");
+
+    let ids: Vec<_> = contacts.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+#[rstest]
+async fn mocktest_paginator_collect_all() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    mount_pages(test_client.email_server.as_deref().unwrap()).await;
+
+    let contacts = test_client
+        .api_client
+        .paginate(ListContacts, ContactFilters::default())
+        .with_page_size(2)
+        .collect_all(3)
+        .await
+        .expect("Failed to retrieve all the pages");
+    assert_eq!(contacts.len(), 3);
+
+    let result = test_client
+        .api_client
+        .paginate(ListContacts, ContactFilters::default())
+        .with_page_size(2)
+        .collect_all(2)
+        .await;
+    assert!(matches!(result, Err(ClientError::TooManyItems(2))));
+}
+
+#[rstest]
+async fn mocktest_paginator_count() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/contact"))
+        .and(method("GET"))
+        .and(query_param("CountOnly", "1"))
+        .respond_with(page(&[], 1234))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let count = test_client
+        .api_client
+        .paginate(ListContacts, ContactFilters::default())
+        .count()
+        .await;
+
+    assert_eq!(count.unwrap(), 1234);
+}
@@ -0,0 +1,97 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::data_objects::{ParseRouteFilters, ParseRouteQuery};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+fn route(
+    #[default(1)] id: i64,
+    #[default("abc@parse-in1.mailjet.com")] email: &str,
+) -> serde_json::Value {
+    json!({
+        "APIKeyID": 1,
+        "Email": email,
+        "ID": id,
+        "Url": "https://hooks.test/inbound"
+    })
+}
+
+#[rstest]
+async fn mocktest_create_parse_route(route: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/parseroute"))
+        .and(method("POST"))
+        .and(body_json(json!({"Url": "https://hooks.test/inbound"})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([route]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .create_parse_route(&ParseRouteQuery {
+            url: "https://hooks.test/inbound".into(),
+            email: None,
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let route = &result.expect("Failed to create a route").payload.unwrap()[0];
+    assert_eq!(route.id, 1);
+    assert_eq!(route.email, "abc@parse-in1.mailjet.com");
+}
+
+#[rstest]
+async fn mocktest_list_and_delete_parse_routes(
+    #[with(2, "replies@mailjet.com")] route: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/parseroute"))
+        .and(method("GET"))
+        .and(query_param("Email", "replies@mailjet.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([route]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    Mock::given(path("/v3/REST/parseroute/2"))
+        .and(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let routes = test_client
+        .api_client
+        .list_parse_routes(&ParseRouteFilters {
+            email: Some("replies@mailjet.com".into()),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list the routes")
+        .payload
+        .unwrap();
+    assert_eq!(routes.len(), 1);
+
+    let result = test_client
+        .api_client
+        .delete_parse_route(routes[0].id)
+        .await;
+    debug!("Result: {:#?}", result);
+    assert!(result.expect("Failed to delete a route").payload.is_none());
+}
@@ -0,0 +1,142 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::data_objects::{EmailType, SenderCreate, SenderQuery, Status};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+fn sender(
+    #[default(1)] id: i64,
+    #[default("pilot@mailjet.com")] email: &str,
+    #[default("Active")] status: &str,
+) -> serde_json::Value {
+    json!({
+        "CreatedAt": "2024-09-20T10:00:00Z",
+        "DNSID": 123,
+        "Email": email,
+        "EmailType": "transactional",
+        "Filename": "",
+        "ID": id,
+        "IsDefaultSender": false,
+        "Name": "Mailjet Pilot",
+        "Status": status
+    })
+}
+
+#[rstest]
+async fn mocktest_list_senders(sender: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/sender"))
+        .and(method("GET"))
+        .and(query_param("DnsID", "123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([
+            sender,
+            sender::get(2, "copilot@mailjet.com", "Inactive")
+        ]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .list_senders(&SenderQuery {
+            dns_id: Some(123),
+            ..Default::default()
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let senders = result.expect("Failed to list senders").payload.unwrap();
+    assert_eq!(senders.len(), 2);
+    assert_eq!(senders[0].status, Status::Active);
+    assert_eq!(senders[1].email_type, EmailType::Transactional);
+}
+
+#[rstest]
+#[case("Active", true)]
+#[case("Inactive", false)]
+async fn mocktest_is_active_sender(#[case] status: &str, #[case] expected: bool) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/sender"))
+        .and(method("GET"))
+        .and(query_param("Email", "pilot@mailjet.com"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(rest_page(json!([sender::get(
+                1,
+                "pilot@mailjet.com",
+                status
+            )]))),
+        )
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .is_active_sender("pilot@mailjet.com")
+        .await;
+
+    debug!("Result: {:#?}", result);
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[rstest]
+async fn mocktest_create_and_validate_sender(
+    #[with(1, "pilot@mailjet.com", "Inactive")] sender: serde_json::Value,
+) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/REST/sender"))
+        .and(method("POST"))
+        .and(body_json(
+            json!({"Name": "Mailjet Pilot", "Email": "pilot@mailjet.com"}),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([sender]))))
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/sender/1/validate"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(
+            json!([{"Errors": {}, "ValidationMethod": "ActivationEmail", "GlobalError": ""}]),
+        )))
+        .mount(server)
+        .await;
+
+    let sender = test_client
+        .api_client
+        .create_sender(&SenderCreate {
+            name: Some("Mailjet Pilot".into()),
+            email: "pilot@mailjet.com".into(),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create the sender")
+        .payload
+        .unwrap()
+        .remove(0);
+
+    let result = test_client.api_client.validate_sender(sender.id).await;
+
+    debug!("Result: {:#?}", result);
+    let validation = result.unwrap().payload.unwrap().remove(0);
+    assert!(validation.errors.is_empty());
+    assert_eq!(validation.validation_method, "ActivationEmail");
+}
@@ -0,0 +1,196 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::data_objects::{
+    TemplateContent, TemplateFilters, TemplateOwnerType, TemplatePurpose, TemplateQuery,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use std::collections::HashMap;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+fn template(#[default(1)] id: i64, #[default("welcome")] name: &str) -> serde_json::Value {
+    json!({
+        "Author": "Mailjet Pilot",
+        "Categories": ["onboarding"],
+        "Copyright": "",
+        "Description": "",
+        "EditMode": 2,
+        "ID": id,
+        "IsStarred": false,
+        "IsTextPartGenerationEnabled": true,
+        "Locale": "en_US",
+        "Name": name,
+        "OwnerId": 42,
+        "OwnerType": "apikey",
+        "Purposes": ["transactional"],
+        "CreatedAt": "2024-09-20T10:00:00Z",
+        "LastUpdatedAt": "2024-09-21T10:00:00Z"
+    })
+}
+
+#[rstest]
+async fn mocktest_list_templates(template: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/template"))
+        .and(method("GET"))
+        .and(query_param("OwnerType", "apikey"))
+        .and(query_param("Purposes", "transactional"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(rest_page(json!([template, template::get(2, "farewell")]))),
+        )
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .list_templates(&TemplateFilters {
+            owner_type: Some(TemplateOwnerType::Apikey),
+            purposes: Some(TemplatePurpose::Transactional),
+            ..Default::default()
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let templates = result.expect("Failed to list templates").payload.unwrap();
+    assert_eq!(templates.len(), 2);
+    assert_eq!(templates[0].name, "welcome");
+    assert_eq!(templates[1].purposes, vec![TemplatePurpose::Transactional]);
+}
+
+#[rstest]
+async fn mocktest_create_template(template: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/template"))
+        .and(method("POST"))
+        .and(body_json(json!({
+            "Name": "welcome",
+            "OwnerType": "apikey",
+            "Purposes": ["transactional"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([template]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .create_template(&TemplateQuery {
+            name: Some("welcome".into()),
+            owner_type: Some(TemplateOwnerType::Apikey),
+            purposes: Some(vec![TemplatePurpose::Transactional]),
+            ..Default::default()
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let template = &result
+        .expect("Failed to create a template")
+        .payload
+        .unwrap()[0];
+    assert_eq!(template.id, 1);
+    assert_eq!(template.owner_type, TemplateOwnerType::Apikey);
+}
+
+#[rstest]
+async fn mocktest_update_and_delete_template(template: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/template/1"))
+        .and(method("PUT"))
+        .and(body_json(json!({"IsStarred": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([template]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    Mock::given(path("/v3/REST/template/1"))
+        .and(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .update_template(
+            1,
+            &TemplateQuery {
+                is_starred: Some(true),
+                ..Default::default()
+            },
+        )
+        .await;
+    debug!("Result: {:#?}", result);
+    assert!(result.is_ok());
+
+    let result = test_client.api_client.delete_template(1).await;
+    debug!("Result: {:#?}", result);
+    assert!(result
+        .expect("Failed to delete a template")
+        .payload
+        .is_none());
+}
+
+#[rstest]
+async fn mocktest_template_content() {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    let body = json!({
+        "Html-part": "<h3>Hello {{var:name}}</h3>",
+        "Text-part": "Hello {{var:name}}",
+        "Headers": {"Subject": "Welcome aboard!"}
+    });
+
+    Mock::given(path("/v3/REST/template/1/detailcontent"))
+        .and(method("POST"))
+        .and(body_json(body.clone()))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([body.clone()]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    Mock::given(path("/v3/REST/template/1/detailcontent"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([body]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let content = TemplateContent {
+        html_part: Some("<h3>Hello {{var:name}}</h3>".into()),
+        text_part: Some("Hello {{var:name}}".into()),
+        mjml_content: None,
+        headers: Some(HashMap::from([(
+            "Subject".to_string(),
+            "Welcome aboard!".to_string(),
+        )])),
+    };
+
+    let result = test_client
+        .api_client
+        .set_template_content(1, &content)
+        .await;
+    debug!("Result: {:#?}", result);
+    assert!(result.is_ok());
+
+    let result = test_client.api_client.get_template_content(1).await;
+    debug!("Result: {:#?}", result);
+    let stored = &result.expect("Failed to get the content").payload.unwrap()[0];
+    assert_eq!(stored, &content);
+}
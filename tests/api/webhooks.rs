@@ -0,0 +1,149 @@
+// Copyright (c) 2024 Felipe Torres González. All rights reserved.
+//
+// This work is licensed under the terms of the MIT license.
+// For a copy, see <https://opensource.org/licenses/MIT>.
+
+use crate::helper::{rest_page, TestApp};
+use mailjet_client::{
+    data_objects::{CallbackStatus, EventCallbackUrlQuery, EventType},
+    webhooks::WebhookSpec,
+};
+use pretty_assertions::assert_eq;
+use rstest::*;
+use serde_json::json;
+use tracing::debug;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, ResponseTemplate,
+};
+
+#[fixture]
+fn webhook(
+    #[default(1)] id: i64,
+    #[default("open")] event_type: &str,
+    #[default("https://hooks.test/open")] url: &str,
+    #[default(1)] version: u8,
+) -> serde_json::Value {
+    json!({
+        "APIKeyID": 1,
+        "EventType": event_type,
+        "ID": id,
+        "IsBackup": false,
+        "Status": "alive",
+        "Url": url,
+        "Version": version
+    })
+}
+
+#[rstest]
+async fn mocktest_create_event_callback_url(webhook: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+
+    Mock::given(path("/v3/REST/eventcallbackurl"))
+        .and(method("POST"))
+        .and(body_json(
+            json!({"EventType": "open", "Url": "https://hooks.test/open"}),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(rest_page(json!([webhook]))))
+        .mount(test_client.email_server.as_deref().unwrap())
+        .await;
+
+    let result = test_client
+        .api_client
+        .create_event_callback_url(&EventCallbackUrlQuery {
+            event_type: Some(EventType::Open),
+            url: Some("https://hooks.test/open".into()),
+            ..Default::default()
+        })
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let webhook = &result.expect("Failed to create a webhook").payload.unwrap()[0];
+    assert_eq!(webhook.event_type, EventType::Open);
+    assert_eq!(webhook.status, CallbackStatus::Alive);
+}
+
+#[rstest]
+async fn mocktest_ensure_webhooks(webhook: serde_json::Value) {
+    let test_client = TestApp::spawn_app()
+        .await
+        .expect("Failed to build a mock test client");
+    let server = test_client.email_server.as_deref().unwrap();
+
+    Mock::given(path("/v3/REST/eventcallbackurl"))
+        .and(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(rest_page(json!([
+            webhook,
+            webhook::get(2, "bounce", "https://old.test/bounce", 1),
+            webhook::get(3, "spam", "https://hooks.test/spam", 1)
+        ]))))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/eventcallbackurl/3"))
+        .and(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/eventcallbackurl/2"))
+        .and(method("PUT"))
+        .and(body_json(json!({
+            "EventType": "bounce",
+            "Url": "https://hooks.test/bounce",
+            "Status": "alive",
+            "Version": 1,
+            "IsBackup": false
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(rest_page(json!([webhook::get(
+                2,
+                "bounce",
+                "https://hooks.test/bounce",
+                1
+            )]))),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(path("/v3/REST/eventcallbackurl"))
+        .and(method("POST"))
+        .and(body_json(json!({
+            "EventType": "sent",
+            "Url": "https://hooks.test/sent",
+            "Status": "alive",
+            "Version": 2,
+            "IsBackup": false
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(rest_page(json!([webhook::get(
+                4,
+                "sent",
+                "https://hooks.test/sent",
+                2
+            )]))),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+
+    let result = test_client
+        .api_client
+        .ensure_webhooks(&[
+            WebhookSpec::new(EventType::Open, "https://hooks.test/open"),
+            WebhookSpec::new(EventType::Bounce, "https://hooks.test/bounce"),
+            WebhookSpec::new(EventType::Sent, "https://hooks.test/sent").with_grouped(true),
+        ])
+        .await;
+
+    debug!("Result: {:#?}", result);
+    let changes = result.expect("Failed to reconcile the webhooks");
+    assert_eq!(changes.created[0].id, 4);
+    assert_eq!(changes.updated[0].id, 2);
+    assert_eq!(changes.deleted[0].id, 3);
+}